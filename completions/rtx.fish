@@ -6,19 +6,22 @@ complete -xc rtx -l log-level -a "error warn info debug trace" -d 'Set the log o
 complete -xc rtx -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.'
 complete -xc rtx -s v -l verbose -d 'Show installation output'
 complete -xc rtx -s y -l yes -d 'Answer yes to all prompts'
-set -l others activate alias bin-paths cache completion current deactivate direnv doctor env env-vars exec implode install latest link ls ls-remote outdated plugins prune reshim self-update settings shell sync trust uninstall upgrade use version where which
+set -l others activate alias bin-paths bundle cache completion current deactivate direnv doctor du env env-vars exec generate implode install latest link ls ls-remote outdated plugins prune reshim self-update settings shell shim-cache sync toolset-hash trust uninstall upgrade use version where where-used which
 complete -xc rtx -n "not $fssf $others" -a activate -d 'Initializes rtx in the current shell'
 complete -xc rtx -n "not $fssf $others" -a alias -d 'Manage aliases'
 complete -xc rtx -n "not $fssf $others" -a bin-paths -d 'List all the active runtime bin paths'
+complete -xc rtx -n "not $fssf $others" -a bundle -d 'Create or install offline bundles of tools'
 complete -xc rtx -n "not $fssf $others" -a cache -d 'Manage the rtx cache'
 complete -xc rtx -n "not $fssf $others" -a completion -d 'Generate shell completions'
 complete -xc rtx -n "not $fssf $others" -a current -d 'Shows current active and installed runtime versions'
 complete -xc rtx -n "not $fssf $others" -a deactivate -d 'Disable rtx for current shell session'
 complete -xc rtx -n "not $fssf $others" -a direnv -d 'Output direnv function to use rtx inside direnv'
 complete -xc rtx -n "not $fssf $others" -a doctor -d 'Check rtx installation for possible problems.'
+complete -xc rtx -n "not $fssf $others" -a du -d 'Show disk usage of installed tools'
 complete -xc rtx -n "not $fssf $others" -a env -d 'Exports env vars to activate rtx a single time'
 complete -xc rtx -n "not $fssf $others" -a env-vars -d 'Manage environment variables'
 complete -xc rtx -n "not $fssf $others" -a exec -d 'Execute a command with tool(s) set'
+complete -xc rtx -n "not $fssf $others" -a generate -d 'Generate files from the current toolset'
 complete -xc rtx -n "not $fssf $others" -a implode -d 'Removes rtx CLI and all related data'
 complete -xc rtx -n "not $fssf $others" -a install -d 'Install a tool version'
 complete -xc rtx -n "not $fssf $others" -a latest -d 'Gets the latest available version for a plugin'
@@ -32,17 +35,21 @@ complete -xc rtx -n "not $fssf $others" -a reshim -d 'rebuilds the shim farm'
 complete -xc rtx -n "not $fssf $others" -a self-update -d 'Updates rtx itself'
 complete -xc rtx -n "not $fssf $others" -a settings -d 'Manage settings'
 complete -xc rtx -n "not $fssf $others" -a shell -d 'Sets a tool version for the current shell session'
+complete -xc rtx -n "not $fssf $others" -a shim-cache -d 'Manage the cache shims use to skip loading config'
 complete -xc rtx -n "not $fssf $others" -a sync -d 'Add tool versions from external tools to rtx'
+complete -xc rtx -n "not $fssf $others" -a toolset-hash -d 'Print a hash of the current toolset'
 complete -xc rtx -n "not $fssf $others" -a trust -d 'Marks a config file as trusted'
 complete -xc rtx -n "not $fssf $others" -a uninstall -d 'Removes runtime versions'
 complete -xc rtx -n "not $fssf $others" -a upgrade -d 'Upgrades outdated tool versions'
 complete -xc rtx -n "not $fssf $others" -a use -d 'Change the active version of a tool locally or globally.'
 complete -xc rtx -n "not $fssf $others" -a version -d 'Show rtx version'
 complete -xc rtx -n "not $fssf $others" -a where -d 'Display the installation path for a runtime'
+complete -xc rtx -n "not $fssf $others" -a where-used -d 'Show which tracked config files use a tool version'
 complete -xc rtx -n "not $fssf $others" -a which -d 'Shows the path that a bin name points to'
 
 # activate
-complete -xc rtx -n "$fssf activate" -a "bash fish nu xonsh zsh" -d 'Shell type to generate the script for'
+complete -xc rtx -n "$fssf activate" -a "bash elvish fish nu pwsh tcsh xonsh zsh" -d 'Shell type to generate the script for'
+complete -xc rtx -n "$fssf activate" -l shims -d 'Only update the environment when changing directories and use shims for everything else'
 complete -xc rtx -n "$fssf activate" -l status -d 'Show "rtx: <PLUGIN>@<VERSION>" message when changing directories'
 
 # alias
@@ -72,6 +79,20 @@ complete -xc rtx -n "$fssf alias; and $fssf unset" -a "(__rtx_plugins)" -d 'The
 
 # bin-paths
 
+# bundle
+set -l others create install
+complete -xc rtx -n "$fssf bundle; and not $fssf $others" -a create -d 'Create a bundle of the current toolset'
+complete -xc rtx -n "$fssf bundle; and not $fssf $others" -a install -d 'Install tools from a bundle created with `rtx bundle create`'
+
+# bundle create
+complete -xc rtx -n "$fssf bundle; and $fssf create" -s o -l output -a "(__fish_complete_path)" -d 'Path to write the bundle to'
+
+# bundle install
+complete -xc rtx -n "$fssf bundle; and $fssf install" -a "(__fish_complete_path)" -d 'Path to the bundle'
+complete -xc rtx -n "$fssf bundle; and $fssf install" -l config -d 'Also write the bundled config files into the current directory'
+complete -xc rtx -n "$fssf bundle; and $fssf install" -s f -l force -d 'Overwrite existing tool versions and plugins'
+
+
 # cache
 set -l others clear
 complete -xc rtx -n "$fssf cache; and not $fssf $others" -a clear -d 'Deletes all cache files in rtx'
@@ -80,7 +101,7 @@ complete -xc rtx -n "$fssf cache; and not $fssf $others" -a clear -d 'Deletes al
 
 
 # completion
-complete -xc rtx -n "$fssf completion" -a "bash fish zsh" -d 'Shell type to generate completions for'
+complete -xc rtx -n "$fssf completion" -a "bash fish pwsh zsh" -d 'Shell type to generate completions for'
 
 # current
 complete -xc rtx -n "$fssf current" -a "(__rtx_plugins)" -d 'Plugin to show versions of e.g.: ruby, node'
@@ -96,9 +117,15 @@ complete -xc rtx -n "$fssf direnv; and not $fssf $others" -a activate -d 'Output
 
 # doctor
 
+# du
+complete -xc rtx -n "$fssf du" -s J -l json -d 'Output in json format'
+complete -xc rtx -n "$fssf du" -a "(__rtx_plugins)" -d 'Only show disk usage of these plugins'
+complete -xc rtx -n "$fssf du" -s s -l sort -a "name size" -d 'How to sort plugins and versions'
+
 # env
 complete -xc rtx -n "$fssf env" -s J -l json -d 'Output in JSON format'
-complete -xc rtx -n "$fssf env" -s s -l shell -a "bash fish nu xonsh zsh" -d 'Shell type to generate environment variables for'
+complete -xc rtx -n "$fssf env" -l profile -d '[experimental] Profile(s) to use instead of RTX_ENV'
+complete -xc rtx -n "$fssf env" -s s -l shell -a "bash elvish fish nu pwsh tcsh xonsh zsh" -d 'Shell type to generate environment variables for'
 complete -xc rtx -n "$fssf env" -a "(__rtx_tool_versions)" -d 'Tool(s) to use'
 
 # env-vars
@@ -112,12 +139,30 @@ complete -xc rtx -n "$fssf exec" -s C -l cd -a "(__fish_complete_directories)" -
 complete -xc rtx -n "$fssf exec" -d 'Command string to execute (same as --command)'
 complete -xc rtx -n "$fssf exec" -a "(__rtx_tool_versions)" -d 'Tool(s) to start e.g.: node@20 python@3.10'
 
+# generate
+set -l others dockerfile github-action gitlab-ci
+complete -xc rtx -n "$fssf generate; and not $fssf $others" -a dockerfile -d 'Generate a Dockerfile that installs the current toolset'
+complete -xc rtx -n "$fssf generate; and not $fssf $others" -a github-action -d 'Generate a GitHub Actions workflow that installs and caches the toolset'
+complete -xc rtx -n "$fssf generate; and not $fssf $others" -a gitlab-ci -d 'Generate GitLab CI config that installs and caches the toolset'
+
+# generate dockerfile
+complete -xc rtx -n "$fssf generate; and $fssf dockerfile" -l base-image -d 'Base image to build on'
+complete -xc rtx -n "$fssf generate; and $fssf dockerfile" -s o -l output -a "(__fish_complete_path)" -d 'Write the Dockerfile to this path instead of stdout'
+
+# generate github-action
+complete -xc rtx -n "$fssf generate; and $fssf github-action" -s o -l output -a "(__fish_complete_path)" -d 'Write the workflow to this path instead of stdout'
+
+# generate gitlab-ci
+complete -xc rtx -n "$fssf generate; and $fssf gitlab-ci" -s o -l output -a "(__fish_complete_path)" -d 'Write the config to this path instead of stdout'
+
+
 # implode
 complete -xc rtx -n "$fssf implode" -l config -d 'Also remove config directory'
 complete -xc rtx -n "$fssf implode" -s n -l dry-run -d 'List directories that would be removed without actually removing them'
 
 # install
 complete -xc rtx -n "$fssf install" -s f -l force -d 'Force reinstall even if already installed'
+complete -xc rtx -n "$fssf install" -l recursive -d 'Install the tools of every config file under the project root'
 complete -xc rtx -n "$fssf install" -a "(__rtx_tool_versions)" -d 'Tool(s) to install e.g.: node@20'
 complete -xc rtx -n "$fssf install" -s v -l verbose -d 'Show installation output'
 
@@ -138,6 +183,8 @@ complete -xc rtx -n "$fssf ls" -s J -l json -d 'Output in json format'
 complete -xc rtx -n "$fssf ls" -s m -l missing -d 'Display missing tool versions'
 complete -xc rtx -n "$fssf ls" -a "(__rtx_plugins)" -d 'Only show tool versions from [PLUGIN]'
 complete -xc rtx -n "$fssf ls" -l prefix -d 'Display versions matching this prefix'
+complete -xc rtx -n "$fssf ls" -l recursive -d 'Show the versions of every config file under the project root'
+complete -xc rtx -n "$fssf ls" -s u -l usage -d 'Show the tracked config files that use each version'
 
 # ls-remote
 complete -xc rtx -n "$fssf ls-remote" -l all -d 'Show all installed plugins and versions'
@@ -180,6 +227,7 @@ complete -xc rtx -n "$fssf plugins; and $fssf ls" -l user -d 'List installed plu
 
 # plugins ls-remote
 complete -xc rtx -n "$fssf plugins; and $fssf ls-remote" -l only-names -d 'Only show the name of each plugin by default it will show a "*" next to installed plugins'
+complete -xc rtx -n "$fssf plugins; and $fssf ls-remote" -d 'Only show plugins with this in their name or description'
 complete -xc rtx -n "$fssf plugins; and $fssf ls-remote" -s u -l urls -d 'Show the git url for each plugin e.g.: https://github.com/rtx-plugins/rtx-nodejs.git'
 
 # plugins uninstall
@@ -188,12 +236,18 @@ complete -xc rtx -n "$fssf plugins; and $fssf uninstall" -a "(__rtx_plugins)" -d
 complete -xc rtx -n "$fssf plugins; and $fssf uninstall" -s p -l purge -d 'Also remove the plugin'\''s installs, downloads, and cache'
 
 # plugins update
+complete -xc rtx -n "$fssf plugins; and $fssf update" -s n -l dry-run -d 'Only fetch and show what would change'
+complete -xc rtx -n "$fssf plugins; and $fssf update" -s J -l json -d 'Output in json format'
 complete -xc rtx -n "$fssf plugins; and $fssf update" -a "(__rtx_plugins)" -d 'Plugin(s) to update'
+complete -xc rtx -n "$fssf plugins; and $fssf update" -l unpin -d 'Update to the latest commit on the default branch even if the plugin is pinned and remove the pin from `[plugins]`'
 
 
 # prune
 complete -xc rtx -n "$fssf prune" -s n -l dry-run -d 'Do not actually delete anything'
+complete -xc rtx -n "$fssf prune" -l exclude -d 'Keep versions matching this glob'
+complete -xc rtx -n "$fssf prune" -l keep -d 'Keep the N newest installed versions of each tool'
 complete -xc rtx -n "$fssf prune" -a "(__rtx_plugins)" -d 'Prune only versions from these plugins'
+complete -xc rtx -n "$fssf prune" -l used-within -d 'Keep versions used by a shim or `rtx exec` within this duration'
 
 # reshim
 
@@ -227,6 +281,13 @@ complete -xc rtx -n "$fssf settings; and $fssf unset" -d 'The setting to remove'
 complete -xc rtx -n "$fssf shell" -a "(__rtx_tool_versions)" -d 'Tool(s) to use'
 complete -xc rtx -n "$fssf shell" -s u -l unset -d 'Removes a previously set version'
 
+# shim-cache
+set -l others clear
+complete -xc rtx -n "$fssf shim-cache; and not $fssf $others" -a clear -d 'Deletes the cached shim resolutions for all directories'
+
+# shim-cache clear
+
+
 # sync
 set -l others node python
 complete -xc rtx -n "$fssf sync; and not $fssf $others" -a node -d 'Symlinks all tool versions from an external tool into rtx'
@@ -241,8 +302,12 @@ complete -xc rtx -n "$fssf sync; and $fssf node" -l nvm -d 'Get tool versions fr
 complete -xc rtx -n "$fssf sync; and $fssf python" -l pyenv -d 'Get tool versions from pyenv'
 
 
+# toolset-hash
+
 # trust
 complete -xc rtx -n "$fssf trust" -a "(__fish_complete_path)" -d 'The config file to trust'
+complete -xc rtx -n "$fssf trust" -l list -d 'Show all trusted config files'
+complete -xc rtx -n "$fssf trust" -l prune -d 'Forget trusted config files that no longer exist'
 complete -xc rtx -n "$fssf trust" -l untrust -d 'No longer trust this config'
 
 # uninstall
@@ -251,7 +316,10 @@ complete -xc rtx -n "$fssf uninstall" -s n -l dry-run -d 'Do not actually delete
 complete -xc rtx -n "$fssf uninstall" -a "(__rtx_tool_versions)" -d 'Tool(s) to remove'
 
 # upgrade
+complete -xc rtx -n "$fssf upgrade" -l bump -d 'Rewrite the versions in config files to the upgraded versions'
 complete -xc rtx -n "$fssf upgrade" -s n -l dry-run -d 'Just print what would be done, don'\''t actually do it'
+complete -xc rtx -n "$fssf upgrade" -s i -l interactive -d 'With --bump, confirm each change to a config file'
+complete -xc rtx -n "$fssf upgrade" -l latest -d 'With --bump, upgrade to the latest version even across major versions'
 complete -xc rtx -n "$fssf upgrade" -a "(__rtx_tool_versions)" -d 'Tool(s) to upgrade'
 
 # use
@@ -268,6 +336,9 @@ complete -xc rtx -n "$fssf use" -a "(__rtx_tool_versions)" -d 'Tool(s) to add to
 # where
 complete -xc rtx -n "$fssf where" -a "(__rtx_tool_versions)" -d 'Tool(s) to look up'
 
+# where-used
+complete -xc rtx -n "$fssf where-used" -a "(__rtx_tool_versions)" -d 'Tool(s) to look up'
+
 # which
 complete -xc rtx -n "$fssf which" -d 'The bin name to look up'
 complete -xc rtx -n "$fssf which" -l plugin -a "(__rtx_plugins)" -d 'Show the plugin name instead of the path'
@@ -0,0 +1,2235 @@
+using namespace System.Management.Automation
+using namespace System.Management.Automation.Language
+
+Register-ArgumentCompleter -Native -CommandName 'rtx' -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    $commandElements = $commandAst.CommandElements
+    $command = @(
+        'rtx'
+        for ($i = 1; $i -lt $commandElements.Count; $i++) {
+            $element = $commandElements[$i]
+            if ($element -isnot [StringConstantExpressionAst] -or
+                $element.StringConstantType -ne [StringConstantType]::BareWord -or
+                $element.Value.StartsWith('-') -or
+                $element.Value -eq $wordToComplete) {
+                break
+        }
+        $element.Value
+    }) -join ';'
+
+    $completions = @(switch ($command) {
+        'rtx' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', 'V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', 'version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('activate', 'activate', [CompletionResultType]::ParameterValue, 'Initializes rtx in the current shell')
+            [CompletionResult]::new('alias', 'alias', [CompletionResultType]::ParameterValue, 'Manage aliases')
+            [CompletionResult]::new('asdf', 'asdf', [CompletionResultType]::ParameterValue, '[internal] simulates asdf for plugins that call "asdf" internally')
+            [CompletionResult]::new('bin-paths', 'bin-paths', [CompletionResultType]::ParameterValue, 'List all the active runtime bin paths')
+            [CompletionResult]::new('bundle', 'bundle', [CompletionResultType]::ParameterValue, 'Create or install offline bundles of tools')
+            [CompletionResult]::new('cache', 'cache', [CompletionResultType]::ParameterValue, 'Manage the rtx cache')
+            [CompletionResult]::new('completion', 'completion', [CompletionResultType]::ParameterValue, 'Generate shell completions')
+            [CompletionResult]::new('current', 'current', [CompletionResultType]::ParameterValue, 'Shows current active and installed runtime versions')
+            [CompletionResult]::new('deactivate', 'deactivate', [CompletionResultType]::ParameterValue, 'Disable rtx for current shell session')
+            [CompletionResult]::new('direnv', 'direnv', [CompletionResultType]::ParameterValue, 'Output direnv function to use rtx inside direnv')
+            [CompletionResult]::new('doctor', 'doctor', [CompletionResultType]::ParameterValue, 'Check rtx installation for possible problems.')
+            [CompletionResult]::new('du', 'du', [CompletionResultType]::ParameterValue, 'Show disk usage of installed tools')
+            [CompletionResult]::new('env', 'env', [CompletionResultType]::ParameterValue, 'Exports env vars to activate rtx a single time')
+            [CompletionResult]::new('env-vars', 'env-vars', [CompletionResultType]::ParameterValue, 'Manage environment variables')
+            [CompletionResult]::new('exec', 'exec', [CompletionResultType]::ParameterValue, 'Execute a command with tool(s) set')
+            [CompletionResult]::new('generate', 'generate', [CompletionResultType]::ParameterValue, 'Generate files from the current toolset')
+            [CompletionResult]::new('global', 'global', [CompletionResultType]::ParameterValue, 'Sets/gets the global tool version(s)')
+            [CompletionResult]::new('hook-env', 'hook-env', [CompletionResultType]::ParameterValue, '[internal] called by activate hook to update env vars directory change')
+            [CompletionResult]::new('implode', 'implode', [CompletionResultType]::ParameterValue, 'Removes rtx CLI and all related data')
+            [CompletionResult]::new('install', 'install', [CompletionResultType]::ParameterValue, 'Install a tool version')
+            [CompletionResult]::new('latest', 'latest', [CompletionResultType]::ParameterValue, 'Gets the latest available version for a plugin')
+            [CompletionResult]::new('link', 'link', [CompletionResultType]::ParameterValue, 'Symlinks a tool version into rtx')
+            [CompletionResult]::new('local', 'local', [CompletionResultType]::ParameterValue, 'Sets/gets tool version in local .tool-versions or .rtx.toml')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'List installed and/or currently selected tool versions')
+            [CompletionResult]::new('ls-remote', 'ls-remote', [CompletionResultType]::ParameterValue, 'List runtime versions available for install')
+            [CompletionResult]::new('outdated', 'outdated', [CompletionResultType]::ParameterValue, 'Shows outdated tool versions')
+            [CompletionResult]::new('plugins', 'plugins', [CompletionResultType]::ParameterValue, 'Manage plugins')
+            [CompletionResult]::new('prune', 'prune', [CompletionResultType]::ParameterValue, 'Delete unused versions of tools')
+            [CompletionResult]::new('reshim', 'reshim', [CompletionResultType]::ParameterValue, 'rebuilds the shim farm')
+            [CompletionResult]::new('settings', 'settings', [CompletionResultType]::ParameterValue, 'Manage settings')
+            [CompletionResult]::new('shell', 'shell', [CompletionResultType]::ParameterValue, 'Sets a tool version for the current shell session')
+            [CompletionResult]::new('shim-cache', 'shim-cache', [CompletionResultType]::ParameterValue, 'Manage the cache shims use to skip loading config')
+            [CompletionResult]::new('sync', 'sync', [CompletionResultType]::ParameterValue, 'Add tool versions from external tools to rtx')
+            [CompletionResult]::new('toolset-hash', 'toolset-hash', [CompletionResultType]::ParameterValue, 'Print a hash of the current toolset')
+            [CompletionResult]::new('trust', 'trust', [CompletionResultType]::ParameterValue, 'Marks a config file as trusted')
+            [CompletionResult]::new('uninstall', 'uninstall', [CompletionResultType]::ParameterValue, 'Removes runtime versions')
+            [CompletionResult]::new('upgrade', 'upgrade', [CompletionResultType]::ParameterValue, 'Upgrades outdated tool versions')
+            [CompletionResult]::new('use', 'use', [CompletionResultType]::ParameterValue, 'Change the active version of a tool locally or globally.')
+            [CompletionResult]::new('version', 'version', [CompletionResultType]::ParameterValue, 'Show rtx version')
+            [CompletionResult]::new('where', 'where', [CompletionResultType]::ParameterValue, 'Display the installation path for a runtime')
+            [CompletionResult]::new('where-used', 'where-used', [CompletionResultType]::ParameterValue, 'Show which tracked config files use a tool version')
+            [CompletionResult]::new('which', 'which', [CompletionResultType]::ParameterValue, 'Shows the path that a bin name points to')
+            [CompletionResult]::new('render-completion', 'render-completion', [CompletionResultType]::ParameterValue, 'Generate shell completions')
+            [CompletionResult]::new('render-help', 'render-help', [CompletionResultType]::ParameterValue, 'internal command to generate markdown from help')
+            [CompletionResult]::new('render-mangen', 'render-mangen', [CompletionResultType]::ParameterValue, 'internal command to generate markdown from help')
+            [CompletionResult]::new('self-update', 'self-update', [CompletionResultType]::ParameterValue, 'Updates rtx itself')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;activate' {
+            [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'Shell type to generate the script for')
+            [CompletionResult]::new('--shell', 'shell', [CompletionResultType]::ParameterName, 'Shell type to generate the script for')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--status', 'status', [CompletionResultType]::ParameterName, 'Show "rtx: <PLUGIN>@<VERSION>" message when changing directories')
+            [CompletionResult]::new('--shims', 'shims', [CompletionResultType]::ParameterName, 'Only update the environment when changing directories and use shims for everything else')
+            [CompletionResult]::new('-q', 'q', [CompletionResultType]::ParameterName, 'noop')
+            [CompletionResult]::new('--quiet', 'quiet', [CompletionResultType]::ParameterName, 'noop')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;alias' {
+            [CompletionResult]::new('-p', 'p', [CompletionResultType]::ParameterName, 'filter aliases by plugin')
+            [CompletionResult]::new('--plugin', 'plugin', [CompletionResultType]::ParameterName, 'filter aliases by plugin')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('get', 'get', [CompletionResultType]::ParameterValue, 'Show an alias for a plugin')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'List aliases
+Shows the aliases that can be specified.
+These can come from user config or from plugins in `bin/list-aliases`.')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Add/update an alias for a plugin')
+            [CompletionResult]::new('unset', 'unset', [CompletionResultType]::ParameterValue, 'Clears an alias for a plugin')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;alias;get' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;alias;ls' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;alias;set' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;alias;unset' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;alias;help' {
+            [CompletionResult]::new('get', 'get', [CompletionResultType]::ParameterValue, 'Show an alias for a plugin')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'List aliases
+Shows the aliases that can be specified.
+These can come from user config or from plugins in `bin/list-aliases`.')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Add/update an alias for a plugin')
+            [CompletionResult]::new('unset', 'unset', [CompletionResultType]::ParameterValue, 'Clears an alias for a plugin')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;alias;help;get' {
+            break
+        }
+        'rtx;alias;help;ls' {
+            break
+        }
+        'rtx;alias;help;set' {
+            break
+        }
+        'rtx;alias;help;unset' {
+            break
+        }
+        'rtx;alias;help;help' {
+            break
+        }
+        'rtx;asdf' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rtx;bin-paths' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rtx;bundle' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('create', 'create', [CompletionResultType]::ParameterValue, 'Create a bundle of the current toolset')
+            [CompletionResult]::new('install', 'install', [CompletionResultType]::ParameterValue, 'Install tools from a bundle created with `rtx bundle create`')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;bundle;create' {
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Path to write the bundle to')
+            [CompletionResult]::new('--output', 'output', [CompletionResultType]::ParameterName, 'Path to write the bundle to')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;bundle;install' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'Overwrite existing tool versions and plugins')
+            [CompletionResult]::new('--force', 'force', [CompletionResultType]::ParameterName, 'Overwrite existing tool versions and plugins')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Also write the bundled config files into the current directory')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;bundle;help' {
+            [CompletionResult]::new('create', 'create', [CompletionResultType]::ParameterValue, 'Create a bundle of the current toolset')
+            [CompletionResult]::new('install', 'install', [CompletionResultType]::ParameterValue, 'Install tools from a bundle created with `rtx bundle create`')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;bundle;help;create' {
+            break
+        }
+        'rtx;bundle;help;install' {
+            break
+        }
+        'rtx;bundle;help;help' {
+            break
+        }
+        'rtx;cache' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Deletes all cache files in rtx')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;cache;clear' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rtx;cache;help' {
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Deletes all cache files in rtx')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;cache;help;clear' {
+            break
+        }
+        'rtx;cache;help;help' {
+            break
+        }
+        'rtx;completion' {
+            [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'Shell type to generate completions for')
+            [CompletionResult]::new('--shell', 'shell', [CompletionResultType]::ParameterName, 'Shell type to generate completions for')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;current' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;deactivate' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;direnv' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('envrc', 'envrc', [CompletionResultType]::ParameterValue, '[internal] This is an internal command that writes an envrc file
+for direnv to consume.')
+            [CompletionResult]::new('exec', 'exec', [CompletionResultType]::ParameterValue, '[internal] This is an internal command that writes an envrc file
+for direnv to consume.')
+            [CompletionResult]::new('activate', 'activate', [CompletionResultType]::ParameterValue, 'Output direnv function to use rtx inside direnv')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;direnv;envrc' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rtx;direnv;exec' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rtx;direnv;activate' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;direnv;help' {
+            [CompletionResult]::new('envrc', 'envrc', [CompletionResultType]::ParameterValue, '[internal] This is an internal command that writes an envrc file
+for direnv to consume.')
+            [CompletionResult]::new('exec', 'exec', [CompletionResultType]::ParameterValue, '[internal] This is an internal command that writes an envrc file
+for direnv to consume.')
+            [CompletionResult]::new('activate', 'activate', [CompletionResultType]::ParameterValue, 'Output direnv function to use rtx inside direnv')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;direnv;help;envrc' {
+            break
+        }
+        'rtx;direnv;help;exec' {
+            break
+        }
+        'rtx;direnv;help;activate' {
+            break
+        }
+        'rtx;direnv;help;help' {
+            break
+        }
+        'rtx;doctor' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;du' {
+            [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'How to sort plugins and versions')
+            [CompletionResult]::new('--sort', 'sort', [CompletionResultType]::ParameterName, 'How to sort plugins and versions')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-J', 'J ', [CompletionResultType]::ParameterName, 'Output in json format')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Output in json format')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;env' {
+            [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'Shell type to generate environment variables for')
+            [CompletionResult]::new('--shell', 'shell', [CompletionResultType]::ParameterName, 'Shell type to generate environment variables for')
+            [CompletionResult]::new('--profile', 'profile', [CompletionResultType]::ParameterName, '[experimental] Profile(s) to use instead of RTX_ENV
+e.g.: `rtx env --profile ci,linux`')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-J', 'J ', [CompletionResultType]::ParameterName, 'Output in JSON format')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Output in JSON format')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;env-vars' {
+            [CompletionResult]::new('--file', 'file', [CompletionResultType]::ParameterName, 'The TOML file to update')
+            [CompletionResult]::new('--remove', 'remove', [CompletionResultType]::ParameterName, 'Remove the environment variable from config file')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;exec' {
+            [CompletionResult]::new('-c', 'c', [CompletionResultType]::ParameterName, 'Command string to execute')
+            [CompletionResult]::new('--command', 'command', [CompletionResultType]::ParameterName, 'Command string to execute')
+            [CompletionResult]::new('-C', 'C ', [CompletionResultType]::ParameterName, 'Change to this directory before executing the command')
+            [CompletionResult]::new('--cd', 'cd', [CompletionResultType]::ParameterName, 'Change to this directory before executing the command')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;generate' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('dockerfile', 'dockerfile', [CompletionResultType]::ParameterValue, 'Generate a Dockerfile that installs the current toolset')
+            [CompletionResult]::new('github-action', 'github-action', [CompletionResultType]::ParameterValue, 'Generate a GitHub Actions workflow that installs and caches the toolset')
+            [CompletionResult]::new('gitlab-ci', 'gitlab-ci', [CompletionResultType]::ParameterValue, 'Generate GitLab CI config that installs and caches the toolset')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;generate;dockerfile' {
+            [CompletionResult]::new('--base-image', 'base-image', [CompletionResultType]::ParameterName, 'Base image to build on')
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Write the Dockerfile to this path instead of stdout')
+            [CompletionResult]::new('--output', 'output', [CompletionResultType]::ParameterName, 'Write the Dockerfile to this path instead of stdout')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;generate;github-action' {
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Write the workflow to this path instead of stdout')
+            [CompletionResult]::new('--output', 'output', [CompletionResultType]::ParameterName, 'Write the workflow to this path instead of stdout')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;generate;gitlab-ci' {
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Write the config to this path instead of stdout')
+            [CompletionResult]::new('--output', 'output', [CompletionResultType]::ParameterName, 'Write the config to this path instead of stdout')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;generate;help' {
+            [CompletionResult]::new('dockerfile', 'dockerfile', [CompletionResultType]::ParameterValue, 'Generate a Dockerfile that installs the current toolset')
+            [CompletionResult]::new('github-action', 'github-action', [CompletionResultType]::ParameterValue, 'Generate a GitHub Actions workflow that installs and caches the toolset')
+            [CompletionResult]::new('gitlab-ci', 'gitlab-ci', [CompletionResultType]::ParameterValue, 'Generate GitLab CI config that installs and caches the toolset')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;generate;help;dockerfile' {
+            break
+        }
+        'rtx;generate;help;github-action' {
+            break
+        }
+        'rtx;generate;help;gitlab-ci' {
+            break
+        }
+        'rtx;generate;help;help' {
+            break
+        }
+        'rtx;global' {
+            [CompletionResult]::new('--remove', 'remove', [CompletionResultType]::ParameterName, 'Remove the plugin(s) from ~/.tool-versions')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--pin', 'pin', [CompletionResultType]::ParameterName, 'Save exact version to `~/.tool-versions`
+e.g.: `rtx global --pin node@20` will save `node 20.0.0` to ~/.tool-versions')
+            [CompletionResult]::new('--fuzzy', 'fuzzy', [CompletionResultType]::ParameterName, 'Save fuzzy version to `~/.tool-versions`
+e.g.: `rtx global --fuzzy node@20` will save `node 20` to ~/.tool-versions
+this is the default behavior unless RTX_ASDF_COMPAT=1')
+            [CompletionResult]::new('--path', 'path', [CompletionResultType]::ParameterName, 'Get the path of the global config file')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;hook-env' {
+            [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'Shell type to generate script for')
+            [CompletionResult]::new('--shell', 'shell', [CompletionResultType]::ParameterName, 'Shell type to generate script for')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--status', 'status', [CompletionResultType]::ParameterName, 'Show "rtx: <PLUGIN>@<VERSION>" message when changing directories')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rtx;implode' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Also remove config directory')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'List directories that would be removed without actually removing them')
+            [CompletionResult]::new('--dry-run', 'dry-run', [CompletionResultType]::ParameterName, 'List directories that would be removed without actually removing them')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;install' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'Force reinstall even if already installed')
+            [CompletionResult]::new('--force', 'force', [CompletionResultType]::ParameterName, 'Force reinstall even if already installed')
+            [CompletionResult]::new('--recursive', 'recursive', [CompletionResultType]::ParameterName, 'Install the tools of every config file under the project root
+Directories ignored by .gitignore are skipped')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;latest' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-i', 'i', [CompletionResultType]::ParameterName, 'Show latest installed instead of available version')
+            [CompletionResult]::new('--installed', 'installed', [CompletionResultType]::ParameterName, 'Show latest installed instead of available version')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;link' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'Overwrite an existing tool version if it exists')
+            [CompletionResult]::new('--force', 'force', [CompletionResultType]::ParameterName, 'Overwrite an existing tool version if it exists')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;local' {
+            [CompletionResult]::new('--remove', 'remove', [CompletionResultType]::ParameterName, 'Remove the plugin(s) from .tool-versions')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-p', 'p', [CompletionResultType]::ParameterName, 'Recurse up to find a .tool-versions file rather than using the current directory only
+by default this command will only set the tool in the current directory ("$PWD/.tool-versions")')
+            [CompletionResult]::new('--parent', 'parent', [CompletionResultType]::ParameterName, 'Recurse up to find a .tool-versions file rather than using the current directory only
+by default this command will only set the tool in the current directory ("$PWD/.tool-versions")')
+            [CompletionResult]::new('--pin', 'pin', [CompletionResultType]::ParameterName, 'Save exact version to `.tool-versions`
+e.g.: `rtx local --pin node@20` will save `node 20.0.0` to .tool-versions')
+            [CompletionResult]::new('--fuzzy', 'fuzzy', [CompletionResultType]::ParameterName, 'Save fuzzy version to `.tool-versions` e.g.: `rtx local --fuzzy node@20` will save `node 20` to .tool-versions This is the default behavior unless RTX_ASDF_COMPAT=1')
+            [CompletionResult]::new('--path', 'path', [CompletionResultType]::ParameterName, 'Get the path of the config file')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;ls' {
+            [CompletionResult]::new('-p', 'p', [CompletionResultType]::ParameterName, 'p')
+            [CompletionResult]::new('--plugin', 'plugin', [CompletionResultType]::ParameterName, 'plugin')
+            [CompletionResult]::new('--prefix', 'prefix', [CompletionResultType]::ParameterName, 'Display versions matching this prefix')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-c', 'c', [CompletionResultType]::ParameterName, 'Only show tool versions currently specified in a .tool-versions/.rtx.toml')
+            [CompletionResult]::new('--current', 'current', [CompletionResultType]::ParameterName, 'Only show tool versions currently specified in a .tool-versions/.rtx.toml')
+            [CompletionResult]::new('-g', 'g', [CompletionResultType]::ParameterName, 'Only show tool versions currently specified in a the global .tool-versions/.rtx.toml')
+            [CompletionResult]::new('--global', 'global', [CompletionResultType]::ParameterName, 'Only show tool versions currently specified in a the global .tool-versions/.rtx.toml')
+            [CompletionResult]::new('-i', 'i', [CompletionResultType]::ParameterName, 'Only show tool versions that are installed Hides missing ones defined in .tool-versions/.rtx.toml but not yet installed')
+            [CompletionResult]::new('--installed', 'installed', [CompletionResultType]::ParameterName, 'Only show tool versions that are installed Hides missing ones defined in .tool-versions/.rtx.toml but not yet installed')
+            [CompletionResult]::new('--parseable', 'parseable', [CompletionResultType]::ParameterName, 'Output in an easily parseable format')
+            [CompletionResult]::new('-J', 'J ', [CompletionResultType]::ParameterName, 'Output in json format')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Output in json format')
+            [CompletionResult]::new('-m', 'm', [CompletionResultType]::ParameterName, 'Display missing tool versions')
+            [CompletionResult]::new('--missing', 'missing', [CompletionResultType]::ParameterName, 'Display missing tool versions')
+            [CompletionResult]::new('-u', 'u', [CompletionResultType]::ParameterName, 'Show the tracked config files that use each version
+See also `rtx where-used`')
+            [CompletionResult]::new('--usage', 'usage', [CompletionResultType]::ParameterName, 'Show the tracked config files that use each version
+See also `rtx where-used`')
+            [CompletionResult]::new('--recursive', 'recursive', [CompletionResultType]::ParameterName, 'Show the versions of every config file under the project root
+as a matrix of directories and tools
+Directories ignored by .gitignore are skipped')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;ls-remote' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--all', 'all', [CompletionResultType]::ParameterName, 'Show all installed plugins and versions')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;outdated' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;plugins' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-a', 'a', [CompletionResultType]::ParameterName, 'list all available remote plugins')
+            [CompletionResult]::new('--all', 'all', [CompletionResultType]::ParameterName, 'list all available remote plugins')
+            [CompletionResult]::new('-c', 'c', [CompletionResultType]::ParameterName, 'The built-in plugins only
+Normally these are not shown')
+            [CompletionResult]::new('--core', 'core', [CompletionResultType]::ParameterName, 'The built-in plugins only
+Normally these are not shown')
+            [CompletionResult]::new('--user', 'user', [CompletionResultType]::ParameterName, 'List installed plugins')
+            [CompletionResult]::new('-u', 'u', [CompletionResultType]::ParameterName, 'show the git url for each plugin')
+            [CompletionResult]::new('--urls', 'urls', [CompletionResultType]::ParameterName, 'show the git url for each plugin')
+            [CompletionResult]::new('--refs', 'refs', [CompletionResultType]::ParameterName, 'show the git refs for each plugin')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('install', 'install', [CompletionResultType]::ParameterValue, 'Install a plugin')
+            [CompletionResult]::new('link', 'link', [CompletionResultType]::ParameterValue, 'Symlinks a plugin into rtx')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'List installed plugins')
+            [CompletionResult]::new('ls-remote', 'ls-remote', [CompletionResultType]::ParameterValue, 'List all available remote plugins')
+            [CompletionResult]::new('uninstall', 'uninstall', [CompletionResultType]::ParameterValue, 'Removes a plugin')
+            [CompletionResult]::new('update', 'update', [CompletionResultType]::ParameterValue, 'Updates a plugin to the latest version')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;plugins;install' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'Reinstall even if plugin exists')
+            [CompletionResult]::new('--force', 'force', [CompletionResultType]::ParameterName, 'Reinstall even if plugin exists')
+            [CompletionResult]::new('-a', 'a', [CompletionResultType]::ParameterName, 'Install all missing plugins
+This will only install plugins that have matching shorthands.
+i.e.: they don''t need the full git repo url')
+            [CompletionResult]::new('--all', 'all', [CompletionResultType]::ParameterName, 'Install all missing plugins
+This will only install plugins that have matching shorthands.
+i.e.: they don''t need the full git repo url')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;plugins;link' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'Overwrite existing plugin')
+            [CompletionResult]::new('--force', 'force', [CompletionResultType]::ParameterName, 'Overwrite existing plugin')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;plugins;ls' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-a', 'a', [CompletionResultType]::ParameterName, 'List all available remote plugins
+Same as `rtx plugins ls-remote`')
+            [CompletionResult]::new('--all', 'all', [CompletionResultType]::ParameterName, 'List all available remote plugins
+Same as `rtx plugins ls-remote`')
+            [CompletionResult]::new('-c', 'c', [CompletionResultType]::ParameterName, 'The built-in plugins only
+Normally these are not shown')
+            [CompletionResult]::new('--core', 'core', [CompletionResultType]::ParameterName, 'The built-in plugins only
+Normally these are not shown')
+            [CompletionResult]::new('--user', 'user', [CompletionResultType]::ParameterName, 'List installed plugins')
+            [CompletionResult]::new('-u', 'u', [CompletionResultType]::ParameterName, 'Show the git url for each plugin
+e.g.: https://github.com/asdf-vm/asdf-node.git')
+            [CompletionResult]::new('--urls', 'urls', [CompletionResultType]::ParameterName, 'Show the git url for each plugin
+e.g.: https://github.com/asdf-vm/asdf-node.git')
+            [CompletionResult]::new('--refs', 'refs', [CompletionResultType]::ParameterName, 'Show the git refs for each plugin
+e.g.: main 1234abc')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;plugins;ls-remote' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-u', 'u', [CompletionResultType]::ParameterName, 'Show the git url for each plugin e.g.: https://github.com/rtx-plugins/rtx-nodejs.git')
+            [CompletionResult]::new('--urls', 'urls', [CompletionResultType]::ParameterName, 'Show the git url for each plugin e.g.: https://github.com/rtx-plugins/rtx-nodejs.git')
+            [CompletionResult]::new('--only-names', 'only-names', [CompletionResultType]::ParameterName, 'Only show the name of each plugin by default it will show a "*" next to installed plugins')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;plugins;uninstall' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-p', 'p', [CompletionResultType]::ParameterName, 'Also remove the plugin''s installs, downloads, and cache')
+            [CompletionResult]::new('--purge', 'purge', [CompletionResultType]::ParameterName, 'Also remove the plugin''s installs, downloads, and cache')
+            [CompletionResult]::new('-a', 'a', [CompletionResultType]::ParameterName, 'Remove all plugins')
+            [CompletionResult]::new('--all', 'all', [CompletionResultType]::ParameterName, 'Remove all plugins')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;plugins;update' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-a', 'a', [CompletionResultType]::ParameterName, 'Update all plugins')
+            [CompletionResult]::new('--all', 'all', [CompletionResultType]::ParameterName, 'Update all plugins')
+            [CompletionResult]::new('--unpin', 'unpin', [CompletionResultType]::ParameterName, 'Update to the latest commit on the default branch even if the plugin is pinned and remove the pin from `[plugins]`')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Only fetch and show what would change')
+            [CompletionResult]::new('--dry-run', 'dry-run', [CompletionResultType]::ParameterName, 'Only fetch and show what would change')
+            [CompletionResult]::new('-J', 'J ', [CompletionResultType]::ParameterName, 'Output in json format')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Output in json format')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;plugins;help' {
+            [CompletionResult]::new('install', 'install', [CompletionResultType]::ParameterValue, 'Install a plugin')
+            [CompletionResult]::new('link', 'link', [CompletionResultType]::ParameterValue, 'Symlinks a plugin into rtx')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'List installed plugins')
+            [CompletionResult]::new('ls-remote', 'ls-remote', [CompletionResultType]::ParameterValue, 'List all available remote plugins')
+            [CompletionResult]::new('uninstall', 'uninstall', [CompletionResultType]::ParameterValue, 'Removes a plugin')
+            [CompletionResult]::new('update', 'update', [CompletionResultType]::ParameterValue, 'Updates a plugin to the latest version')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;plugins;help;install' {
+            break
+        }
+        'rtx;plugins;help;link' {
+            break
+        }
+        'rtx;plugins;help;ls' {
+            break
+        }
+        'rtx;plugins;help;ls-remote' {
+            break
+        }
+        'rtx;plugins;help;uninstall' {
+            break
+        }
+        'rtx;plugins;help;update' {
+            break
+        }
+        'rtx;plugins;help;help' {
+            break
+        }
+        'rtx;prune' {
+            [CompletionResult]::new('--keep', 'keep', [CompletionResultType]::ParameterName, 'Keep the N newest installed versions of each tool')
+            [CompletionResult]::new('--used-within', 'used-within', [CompletionResultType]::ParameterName, 'Keep versions used by a shim or `rtx exec` within this duration
+e.g.: 30d, 12h')
+            [CompletionResult]::new('--exclude', 'exclude', [CompletionResultType]::ParameterName, 'Keep versions matching this glob
+e.g.: node@18.*, python')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Do not actually delete anything')
+            [CompletionResult]::new('--dry-run', 'dry-run', [CompletionResultType]::ParameterName, 'Do not actually delete anything')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;reshim' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;settings' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('get', 'get', [CompletionResultType]::ParameterValue, 'Show a current setting')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'Show current settings')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Add/update a setting')
+            [CompletionResult]::new('unset', 'unset', [CompletionResultType]::ParameterValue, 'Clears a setting')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;settings;get' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;settings;ls' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;settings;set' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;settings;unset' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;settings;help' {
+            [CompletionResult]::new('get', 'get', [CompletionResultType]::ParameterValue, 'Show a current setting')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'Show current settings')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Add/update a setting')
+            [CompletionResult]::new('unset', 'unset', [CompletionResultType]::ParameterValue, 'Clears a setting')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;settings;help;get' {
+            break
+        }
+        'rtx;settings;help;ls' {
+            break
+        }
+        'rtx;settings;help;set' {
+            break
+        }
+        'rtx;settings;help;unset' {
+            break
+        }
+        'rtx;settings;help;help' {
+            break
+        }
+        'rtx;shell' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-u', 'u', [CompletionResultType]::ParameterName, 'Removes a previously set version')
+            [CompletionResult]::new('--unset', 'unset', [CompletionResultType]::ParameterName, 'Removes a previously set version')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;shim-cache' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Deletes the cached shim resolutions for all directories')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;shim-cache;clear' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;shim-cache;help' {
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Deletes the cached shim resolutions for all directories')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;shim-cache;help;clear' {
+            break
+        }
+        'rtx;shim-cache;help;help' {
+            break
+        }
+        'rtx;sync' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('node', 'node', [CompletionResultType]::ParameterValue, 'Symlinks all tool versions from an external tool into rtx')
+            [CompletionResult]::new('python', 'python', [CompletionResultType]::ParameterValue, 'Symlinks all tool versions from an external tool into rtx')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;sync;node' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--brew', 'brew', [CompletionResultType]::ParameterName, 'Get tool versions from Homebrew')
+            [CompletionResult]::new('--nvm', 'nvm', [CompletionResultType]::ParameterName, 'Get tool versions from nvm')
+            [CompletionResult]::new('--nodenv', 'nodenv', [CompletionResultType]::ParameterName, 'Get tool versions from nodenv')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;sync;python' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--pyenv', 'pyenv', [CompletionResultType]::ParameterName, 'Get tool versions from pyenv')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;sync;help' {
+            [CompletionResult]::new('node', 'node', [CompletionResultType]::ParameterValue, 'Symlinks all tool versions from an external tool into rtx')
+            [CompletionResult]::new('python', 'python', [CompletionResultType]::ParameterValue, 'Symlinks all tool versions from an external tool into rtx')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;sync;help;node' {
+            break
+        }
+        'rtx;sync;help;python' {
+            break
+        }
+        'rtx;sync;help;help' {
+            break
+        }
+        'rtx;toolset-hash' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;trust' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--untrust', 'untrust', [CompletionResultType]::ParameterName, 'No longer trust this config')
+            [CompletionResult]::new('--list', 'list', [CompletionResultType]::ParameterName, 'Show all trusted config files')
+            [CompletionResult]::new('--prune', 'prune', [CompletionResultType]::ParameterName, 'Forget trusted config files that no longer exist')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;uninstall' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-a', 'a', [CompletionResultType]::ParameterName, 'Delete all installed versions')
+            [CompletionResult]::new('--all', 'all', [CompletionResultType]::ParameterName, 'Delete all installed versions')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Do not actually delete anything')
+            [CompletionResult]::new('--dry-run', 'dry-run', [CompletionResultType]::ParameterName, 'Do not actually delete anything')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;upgrade' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Just print what would be done, don''t actually do it')
+            [CompletionResult]::new('--dry-run', 'dry-run', [CompletionResultType]::ParameterName, 'Just print what would be done, don''t actually do it')
+            [CompletionResult]::new('--bump', 'bump', [CompletionResultType]::ParameterName, 'Rewrite the versions in config files to the upgraded versions')
+            [CompletionResult]::new('--latest', 'latest', [CompletionResultType]::ParameterName, 'With --bump, upgrade to the latest version even across major versions
+e.g.: `node = "20"` -> `node = "21"`')
+            [CompletionResult]::new('-i', 'i', [CompletionResultType]::ParameterName, 'With --bump, confirm each change to a config file')
+            [CompletionResult]::new('--interactive', 'interactive', [CompletionResultType]::ParameterName, 'With --bump, confirm each change to a config file')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;use' {
+            [CompletionResult]::new('--remove', 'remove', [CompletionResultType]::ParameterName, 'Remove the tool(s) from config file')
+            [CompletionResult]::new('-e', 'e', [CompletionResultType]::ParameterName, '[experimental] Modify an environment-specific config file like .rtx.<env>.toml
+It is used when <env> is one of the profiles in RTX_ENV, e.g.: RTX_ENV=ci,<env>')
+            [CompletionResult]::new('--env', 'env', [CompletionResultType]::ParameterName, '[experimental] Modify an environment-specific config file like .rtx.<env>.toml
+It is used when <env> is one of the profiles in RTX_ENV, e.g.: RTX_ENV=ci,<env>')
+            [CompletionResult]::new('-p', 'p', [CompletionResultType]::ParameterName, 'Specify a path to a config file or directory If a directory is specified, it will look for .rtx.toml (default) or .tool-versions')
+            [CompletionResult]::new('--path', 'path', [CompletionResultType]::ParameterName, 'Specify a path to a config file or directory If a directory is specified, it will look for .rtx.toml (default) or .tool-versions')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--pin', 'pin', [CompletionResultType]::ParameterName, 'Save exact version to config file
+e.g.: `rtx use --pin node@20` will save 20.0.0 as the version')
+            [CompletionResult]::new('--fuzzy', 'fuzzy', [CompletionResultType]::ParameterName, 'Save fuzzy version to config file
+e.g.: `rtx use --fuzzy node@20` will save 20 as the version
+this is the default behavior unless RTX_ASDF_COMPAT=1')
+            [CompletionResult]::new('-g', 'g', [CompletionResultType]::ParameterName, 'Use the global config file (~/.config/rtx/config.toml) instead of the local one')
+            [CompletionResult]::new('--global', 'global', [CompletionResultType]::ParameterName, 'Use the global config file (~/.config/rtx/config.toml) instead of the local one')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;version' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rtx;where' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;where-used' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;which' {
+            [CompletionResult]::new('-t', 't', [CompletionResultType]::ParameterName, 'Use a specific tool@version
+e.g.: `rtx which npm --tool=node@20`')
+            [CompletionResult]::new('--tool', 'tool', [CompletionResultType]::ParameterName, 'Use a specific tool@version
+e.g.: `rtx which npm --tool=node@20`')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--plugin', 'plugin', [CompletionResultType]::ParameterName, 'Show the plugin name instead of the path')
+            [CompletionResult]::new('--version', 'version', [CompletionResultType]::ParameterName, 'Show the version instead of the path')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;render-completion' {
+            [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'Shell type to generate completions for')
+            [CompletionResult]::new('--shell', 'shell', [CompletionResultType]::ParameterName, 'Shell type to generate completions for')
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rtx;render-help' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rtx;render-mangen' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Answer yes to all prompts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rtx;self-update' {
+            [CompletionResult]::new('-j', 'j', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Number of plugins and runtimes to install in parallel
+[default: 4]')
+            [CompletionResult]::new('--log-level', 'log-level', [CompletionResultType]::ParameterName, 'Set the log output verbosity')
+            [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'Update even if already up to date')
+            [CompletionResult]::new('--force', 'force', [CompletionResultType]::ParameterName, 'Update even if already up to date')
+            [CompletionResult]::new('--no-plugins', 'no-plugins', [CompletionResultType]::ParameterName, 'Disable auto-updating plugins')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Skip confirmation prompt')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Skip confirmation prompt')
+            [CompletionResult]::new('--debug', 'debug', [CompletionResultType]::ParameterName, 'Sets log level to debug')
+            [CompletionResult]::new('--trace', 'trace', [CompletionResultType]::ParameterName, 'Sets log level to trace')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('--raw', 'raw', [CompletionResultType]::ParameterName, 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Show installation output')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rtx;help' {
+            [CompletionResult]::new('activate', 'activate', [CompletionResultType]::ParameterValue, 'Initializes rtx in the current shell')
+            [CompletionResult]::new('alias', 'alias', [CompletionResultType]::ParameterValue, 'Manage aliases')
+            [CompletionResult]::new('asdf', 'asdf', [CompletionResultType]::ParameterValue, '[internal] simulates asdf for plugins that call "asdf" internally')
+            [CompletionResult]::new('bin-paths', 'bin-paths', [CompletionResultType]::ParameterValue, 'List all the active runtime bin paths')
+            [CompletionResult]::new('bundle', 'bundle', [CompletionResultType]::ParameterValue, 'Create or install offline bundles of tools')
+            [CompletionResult]::new('cache', 'cache', [CompletionResultType]::ParameterValue, 'Manage the rtx cache')
+            [CompletionResult]::new('completion', 'completion', [CompletionResultType]::ParameterValue, 'Generate shell completions')
+            [CompletionResult]::new('current', 'current', [CompletionResultType]::ParameterValue, 'Shows current active and installed runtime versions')
+            [CompletionResult]::new('deactivate', 'deactivate', [CompletionResultType]::ParameterValue, 'Disable rtx for current shell session')
+            [CompletionResult]::new('direnv', 'direnv', [CompletionResultType]::ParameterValue, 'Output direnv function to use rtx inside direnv')
+            [CompletionResult]::new('doctor', 'doctor', [CompletionResultType]::ParameterValue, 'Check rtx installation for possible problems.')
+            [CompletionResult]::new('du', 'du', [CompletionResultType]::ParameterValue, 'Show disk usage of installed tools')
+            [CompletionResult]::new('env', 'env', [CompletionResultType]::ParameterValue, 'Exports env vars to activate rtx a single time')
+            [CompletionResult]::new('env-vars', 'env-vars', [CompletionResultType]::ParameterValue, 'Manage environment variables')
+            [CompletionResult]::new('exec', 'exec', [CompletionResultType]::ParameterValue, 'Execute a command with tool(s) set')
+            [CompletionResult]::new('generate', 'generate', [CompletionResultType]::ParameterValue, 'Generate files from the current toolset')
+            [CompletionResult]::new('global', 'global', [CompletionResultType]::ParameterValue, 'Sets/gets the global tool version(s)')
+            [CompletionResult]::new('hook-env', 'hook-env', [CompletionResultType]::ParameterValue, '[internal] called by activate hook to update env vars directory change')
+            [CompletionResult]::new('implode', 'implode', [CompletionResultType]::ParameterValue, 'Removes rtx CLI and all related data')
+            [CompletionResult]::new('install', 'install', [CompletionResultType]::ParameterValue, 'Install a tool version')
+            [CompletionResult]::new('latest', 'latest', [CompletionResultType]::ParameterValue, 'Gets the latest available version for a plugin')
+            [CompletionResult]::new('link', 'link', [CompletionResultType]::ParameterValue, 'Symlinks a tool version into rtx')
+            [CompletionResult]::new('local', 'local', [CompletionResultType]::ParameterValue, 'Sets/gets tool version in local .tool-versions or .rtx.toml')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'List installed and/or currently selected tool versions')
+            [CompletionResult]::new('ls-remote', 'ls-remote', [CompletionResultType]::ParameterValue, 'List runtime versions available for install')
+            [CompletionResult]::new('outdated', 'outdated', [CompletionResultType]::ParameterValue, 'Shows outdated tool versions')
+            [CompletionResult]::new('plugins', 'plugins', [CompletionResultType]::ParameterValue, 'Manage plugins')
+            [CompletionResult]::new('prune', 'prune', [CompletionResultType]::ParameterValue, 'Delete unused versions of tools')
+            [CompletionResult]::new('reshim', 'reshim', [CompletionResultType]::ParameterValue, 'rebuilds the shim farm')
+            [CompletionResult]::new('settings', 'settings', [CompletionResultType]::ParameterValue, 'Manage settings')
+            [CompletionResult]::new('shell', 'shell', [CompletionResultType]::ParameterValue, 'Sets a tool version for the current shell session')
+            [CompletionResult]::new('shim-cache', 'shim-cache', [CompletionResultType]::ParameterValue, 'Manage the cache shims use to skip loading config')
+            [CompletionResult]::new('sync', 'sync', [CompletionResultType]::ParameterValue, 'Add tool versions from external tools to rtx')
+            [CompletionResult]::new('toolset-hash', 'toolset-hash', [CompletionResultType]::ParameterValue, 'Print a hash of the current toolset')
+            [CompletionResult]::new('trust', 'trust', [CompletionResultType]::ParameterValue, 'Marks a config file as trusted')
+            [CompletionResult]::new('uninstall', 'uninstall', [CompletionResultType]::ParameterValue, 'Removes runtime versions')
+            [CompletionResult]::new('upgrade', 'upgrade', [CompletionResultType]::ParameterValue, 'Upgrades outdated tool versions')
+            [CompletionResult]::new('use', 'use', [CompletionResultType]::ParameterValue, 'Change the active version of a tool locally or globally.')
+            [CompletionResult]::new('version', 'version', [CompletionResultType]::ParameterValue, 'Show rtx version')
+            [CompletionResult]::new('where', 'where', [CompletionResultType]::ParameterValue, 'Display the installation path for a runtime')
+            [CompletionResult]::new('where-used', 'where-used', [CompletionResultType]::ParameterValue, 'Show which tracked config files use a tool version')
+            [CompletionResult]::new('which', 'which', [CompletionResultType]::ParameterValue, 'Shows the path that a bin name points to')
+            [CompletionResult]::new('render-completion', 'render-completion', [CompletionResultType]::ParameterValue, 'Generate shell completions')
+            [CompletionResult]::new('render-help', 'render-help', [CompletionResultType]::ParameterValue, 'internal command to generate markdown from help')
+            [CompletionResult]::new('render-mangen', 'render-mangen', [CompletionResultType]::ParameterValue, 'internal command to generate markdown from help')
+            [CompletionResult]::new('self-update', 'self-update', [CompletionResultType]::ParameterValue, 'Updates rtx itself')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rtx;help;activate' {
+            break
+        }
+        'rtx;help;alias' {
+            [CompletionResult]::new('get', 'get', [CompletionResultType]::ParameterValue, 'Show an alias for a plugin')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'List aliases
+Shows the aliases that can be specified.
+These can come from user config or from plugins in `bin/list-aliases`.')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Add/update an alias for a plugin')
+            [CompletionResult]::new('unset', 'unset', [CompletionResultType]::ParameterValue, 'Clears an alias for a plugin')
+            break
+        }
+        'rtx;help;alias;get' {
+            break
+        }
+        'rtx;help;alias;ls' {
+            break
+        }
+        'rtx;help;alias;set' {
+            break
+        }
+        'rtx;help;alias;unset' {
+            break
+        }
+        'rtx;help;asdf' {
+            break
+        }
+        'rtx;help;bin-paths' {
+            break
+        }
+        'rtx;help;bundle' {
+            [CompletionResult]::new('create', 'create', [CompletionResultType]::ParameterValue, 'Create a bundle of the current toolset')
+            [CompletionResult]::new('install', 'install', [CompletionResultType]::ParameterValue, 'Install tools from a bundle created with `rtx bundle create`')
+            break
+        }
+        'rtx;help;bundle;create' {
+            break
+        }
+        'rtx;help;bundle;install' {
+            break
+        }
+        'rtx;help;cache' {
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Deletes all cache files in rtx')
+            break
+        }
+        'rtx;help;cache;clear' {
+            break
+        }
+        'rtx;help;completion' {
+            break
+        }
+        'rtx;help;current' {
+            break
+        }
+        'rtx;help;deactivate' {
+            break
+        }
+        'rtx;help;direnv' {
+            [CompletionResult]::new('envrc', 'envrc', [CompletionResultType]::ParameterValue, '[internal] This is an internal command that writes an envrc file
+for direnv to consume.')
+            [CompletionResult]::new('exec', 'exec', [CompletionResultType]::ParameterValue, '[internal] This is an internal command that writes an envrc file
+for direnv to consume.')
+            [CompletionResult]::new('activate', 'activate', [CompletionResultType]::ParameterValue, 'Output direnv function to use rtx inside direnv')
+            break
+        }
+        'rtx;help;direnv;envrc' {
+            break
+        }
+        'rtx;help;direnv;exec' {
+            break
+        }
+        'rtx;help;direnv;activate' {
+            break
+        }
+        'rtx;help;doctor' {
+            break
+        }
+        'rtx;help;du' {
+            break
+        }
+        'rtx;help;env' {
+            break
+        }
+        'rtx;help;env-vars' {
+            break
+        }
+        'rtx;help;exec' {
+            break
+        }
+        'rtx;help;generate' {
+            [CompletionResult]::new('dockerfile', 'dockerfile', [CompletionResultType]::ParameterValue, 'Generate a Dockerfile that installs the current toolset')
+            [CompletionResult]::new('github-action', 'github-action', [CompletionResultType]::ParameterValue, 'Generate a GitHub Actions workflow that installs and caches the toolset')
+            [CompletionResult]::new('gitlab-ci', 'gitlab-ci', [CompletionResultType]::ParameterValue, 'Generate GitLab CI config that installs and caches the toolset')
+            break
+        }
+        'rtx;help;generate;dockerfile' {
+            break
+        }
+        'rtx;help;generate;github-action' {
+            break
+        }
+        'rtx;help;generate;gitlab-ci' {
+            break
+        }
+        'rtx;help;global' {
+            break
+        }
+        'rtx;help;hook-env' {
+            break
+        }
+        'rtx;help;implode' {
+            break
+        }
+        'rtx;help;install' {
+            break
+        }
+        'rtx;help;latest' {
+            break
+        }
+        'rtx;help;link' {
+            break
+        }
+        'rtx;help;local' {
+            break
+        }
+        'rtx;help;ls' {
+            break
+        }
+        'rtx;help;ls-remote' {
+            break
+        }
+        'rtx;help;outdated' {
+            break
+        }
+        'rtx;help;plugins' {
+            [CompletionResult]::new('install', 'install', [CompletionResultType]::ParameterValue, 'Install a plugin')
+            [CompletionResult]::new('link', 'link', [CompletionResultType]::ParameterValue, 'Symlinks a plugin into rtx')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'List installed plugins')
+            [CompletionResult]::new('ls-remote', 'ls-remote', [CompletionResultType]::ParameterValue, 'List all available remote plugins')
+            [CompletionResult]::new('uninstall', 'uninstall', [CompletionResultType]::ParameterValue, 'Removes a plugin')
+            [CompletionResult]::new('update', 'update', [CompletionResultType]::ParameterValue, 'Updates a plugin to the latest version')
+            break
+        }
+        'rtx;help;plugins;install' {
+            break
+        }
+        'rtx;help;plugins;link' {
+            break
+        }
+        'rtx;help;plugins;ls' {
+            break
+        }
+        'rtx;help;plugins;ls-remote' {
+            break
+        }
+        'rtx;help;plugins;uninstall' {
+            break
+        }
+        'rtx;help;plugins;update' {
+            break
+        }
+        'rtx;help;prune' {
+            break
+        }
+        'rtx;help;reshim' {
+            break
+        }
+        'rtx;help;settings' {
+            [CompletionResult]::new('get', 'get', [CompletionResultType]::ParameterValue, 'Show a current setting')
+            [CompletionResult]::new('ls', 'ls', [CompletionResultType]::ParameterValue, 'Show current settings')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Add/update a setting')
+            [CompletionResult]::new('unset', 'unset', [CompletionResultType]::ParameterValue, 'Clears a setting')
+            break
+        }
+        'rtx;help;settings;get' {
+            break
+        }
+        'rtx;help;settings;ls' {
+            break
+        }
+        'rtx;help;settings;set' {
+            break
+        }
+        'rtx;help;settings;unset' {
+            break
+        }
+        'rtx;help;shell' {
+            break
+        }
+        'rtx;help;shim-cache' {
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Deletes the cached shim resolutions for all directories')
+            break
+        }
+        'rtx;help;shim-cache;clear' {
+            break
+        }
+        'rtx;help;sync' {
+            [CompletionResult]::new('node', 'node', [CompletionResultType]::ParameterValue, 'Symlinks all tool versions from an external tool into rtx')
+            [CompletionResult]::new('python', 'python', [CompletionResultType]::ParameterValue, 'Symlinks all tool versions from an external tool into rtx')
+            break
+        }
+        'rtx;help;sync;node' {
+            break
+        }
+        'rtx;help;sync;python' {
+            break
+        }
+        'rtx;help;toolset-hash' {
+            break
+        }
+        'rtx;help;trust' {
+            break
+        }
+        'rtx;help;uninstall' {
+            break
+        }
+        'rtx;help;upgrade' {
+            break
+        }
+        'rtx;help;use' {
+            break
+        }
+        'rtx;help;version' {
+            break
+        }
+        'rtx;help;where' {
+            break
+        }
+        'rtx;help;where-used' {
+            break
+        }
+        'rtx;help;which' {
+            break
+        }
+        'rtx;help;render-completion' {
+            break
+        }
+        'rtx;help;render-help' {
+            break
+        }
+        'rtx;help;render-mangen' {
+            break
+        }
+        'rtx;help;self-update' {
+            break
+        }
+        'rtx;help;help' {
+            break
+        }
+    })
+
+    $completions.Where{ $_.CompletionText -like "$wordToComplete*" } |
+        Sort-Object -Property ListItemText
+}
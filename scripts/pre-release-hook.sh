@@ -12,4 +12,4 @@ just render-mangen render-help
 ./scripts/update-shorthand-repo.sh
 just lint-fix
 
-git add man src/default_shorthands.rs
+git add man registry.toml
@@ -3,12 +3,11 @@ set -euo pipefail
 
 rm -rf asdf-plugins
 git clone --depth 1 https://github.com/asdf-vm/asdf-plugins
-rm -f src/default_shorthands.rs
 
 custom_plugins=(
-  '("pipenv", "https://github.com/rtx-plugins/rtx-pipenv.git"),'
-  '("poetry", "https://github.com/rtx-plugins/rtx-poetry.git"),'
-  '("tiny",   "https://github.com/rtx-plugins/rtx-tiny.git"),'
+  'pipenv = { repo = "https://github.com/rtx-plugins/rtx-pipenv.git", backends = ["rtx"], description = "Python dependency and virtualenv manager" }'
+  'poetry = { repo = "https://github.com/rtx-plugins/rtx-poetry.git", backends = ["rtx"], description = "Python packaging and dependency manager" }'
+  'tiny = { repo = "https://github.com/rtx-plugins/rtx-tiny.git", backends = ["rtx"], description = "A tiny plugin used for testing rtx" }'
 )
 
 asdf_plugins=$(find asdf-plugins/plugins -maxdepth 1 |
@@ -17,51 +16,30 @@ asdf_plugins=$(find asdf-plugins/plugins -maxdepth 1 |
   grep -v '/golang$' |
   grep -v '/java$' |
   grep -v '/nodejs$' |
+  grep -v '/pipenv$' |
   grep -v '/plugins$' |
+  grep -v '/poetry$' |
   grep -v '/python$' |
-  grep -v '/ruby$')
+  grep -v '/ruby$' |
+  grep -v '/tiny$')
 
-num_plugins=$(echo "$asdf_plugins" | wc -l | tr -d ' ')
-num_plugins=$((num_plugins + ${#custom_plugins[@]}))
+cat >registry.toml <<HEADER
+# This file is generated by scripts/update-shorthand-repo.sh
+# DO NOT EDIT THIS FILE MANUALLY. YOUR PR WILL BE REJECTED.
+#
+# plugins that can be installed by name, this is the base of the plugin registry
 
-cat >src/default_shorthands.rs <<EOF
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// !GENERATED FILE!
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// !GENERATED FILE!
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// !GENERATED FILE!
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// This file is generated by scripts/update-shorthand-repo.sh
-// DO NOT EDIT THIS FILE MANUALLY. YOUR PR WILL BE REJECTED.
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// !GENERATED FILE!
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// !GENERATED FILE!
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// !GENERATED FILE!
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-
-use once_cell::sync::Lazy;
-use std::collections::HashMap;
-
-pub static DEFAULT_SHORTHANDS: Lazy<HashMap<&'static str, &'static str>> =
-    Lazy::new(|| HashMap::from(DEFAULT_SHORTHAND_LIST));
-
-#[rustfmt::skip]
-#[cfg_attr(coverage_nightly, no_coverage)]
-const DEFAULT_SHORTHAND_LIST: [(&str, &str); $num_plugins] = [
-    // asdf original shorthands from https://github.com/asdf-vm/asdf-plugins
-EOF
+# asdf original shorthands from https://github.com/asdf-vm/asdf-plugins
+HEADER
 for file in $asdf_plugins; do
   plugin=$(basename "$file")
   repository=$(cat "$file")
   repository="${repository/#repository = /}"
-  echo "    (\"$plugin\", \"$repository\")," >>src/default_shorthands.rs
+  echo "$plugin = { repo = \"$repository\", backends = [\"asdf\"] }" >>registry.toml
 done
-echo "    // rtx custom shorthands" >>src/default_shorthands.rs
+echo "" >>registry.toml
+echo "# rtx custom shorthands" >>registry.toml
 for plugin in "${custom_plugins[@]}"; do
-  echo "    $plugin" >>src/default_shorthands.rs
+  echo "$plugin" >>registry.toml
 done
-echo "];" >>src/default_shorthands.rs
 rm -rf asdf-plugins
@@ -75,7 +75,7 @@ where
         Ok(val)
     }
 
-    fn parse(&self) -> Result<T> {
+    pub fn parse(&self) -> Result<T> {
         let path = &self.cache_file_path;
         trace!("reading {}", display_path(path));
         let mut zlib = ZlibDecoder::new(File::open(path)?);
@@ -40,6 +40,14 @@ pub struct Activate {
     #[clap(long)]
     status: bool,
 
+    /// Only update the environment when changing directories and use shims for everything else
+    ///
+    /// This avoids running rtx on every prompt. The shims directory is added to PATH
+    /// so tools still resolve correctly in subprocesses and after editing config files
+    /// without changing directories.
+    #[clap(long, verbatim_doc_comment)]
+    shims: bool,
+
     /// noop
     #[clap(long, short, hide = true)]
     quiet: bool,
@@ -53,7 +61,7 @@ impl Activate {
         // touch ROOT to allow hook-env to run
         let _ = touch_dir(&dirs::DATA);
 
-        let output = shell.activate(&RTX_EXE, self.status);
+        let output = shell.activate(&RTX_EXE, self.status, self.shims);
         out.stdout.write(output);
 
         Ok(())
@@ -64,7 +72,11 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
     r#"<bold><underline>Examples:</underline></bold>
   $ <bold>eval "$(rtx activate bash)"</bold>
   $ <bold>eval "$(rtx activate zsh)"</bold>
+  $ <bold>eval "$(rtx activate zsh --shims)"</bold>
   $ <bold>rtx activate fish | source</bold>
+  $ <bold>rtx activate pwsh | Out-String | Invoke-Expression</bold>
+  $ <bold>eval (rtx activate elvish | slurp)</bold>
+  $ <bold>eval "`rtx activate tcsh`"</bold>
   $ <bold>execx($(rtx activate xonsh))</bold>
 "#
 );
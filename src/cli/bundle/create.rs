@@ -0,0 +1,119 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use clap::ValueHint;
+use color_eyre::eyre::Result;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use crate::cli::bundle::{BundleManifest, BundleTool, MANIFEST_FILENAME};
+use crate::cli::version::{ARCH, OS, RAW_VERSION};
+use crate::config::Config;
+use crate::file::display_path;
+use crate::output::Output;
+use crate::plugins::PluginType;
+use crate::toolset::ToolsetBuilder;
+use crate::{dirs, file};
+
+/// Create a bundle of the current toolset
+///
+/// Includes every installed tool version currently active, the repos of any
+/// external plugins they use, and the local config files.
+/// Tools that are not installed are skipped, run `rtx install` first.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
+pub struct BundleCreate {
+    /// Path to write the bundle to
+    #[clap(short, long, value_hint = ValueHint::FilePath, default_value = "rtx-bundle.tar.gz")]
+    output: PathBuf,
+}
+
+impl BundleCreate {
+    pub fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        let ts = ToolsetBuilder::new().build(&mut config)?;
+        let mut manifest = BundleManifest {
+            rtx_version: RAW_VERSION.to_string(),
+            os: OS.to_string(),
+            arch: ARCH.to_string(),
+            ..Default::default()
+        };
+        // (path in archive, path on disk)
+        let mut entries: Vec<(PathBuf, PathBuf)> = vec![];
+        for (p, tv) in ts.list_current_installed_versions(&config) {
+            let install_path = tv.install_path();
+            let Ok(rel) = install_path.strip_prefix(&*dirs::INSTALLS) else {
+                // path: versions live outside of rtx
+                continue;
+            };
+            let rel = rel.to_string_lossy().to_string();
+            if p.get_type() == PluginType::External && !manifest.plugins.contains(&tv.plugin_name) {
+                let plugin_path = dirs::PLUGINS.join(&tv.plugin_name);
+                entries.push((Path::new("plugins").join(&tv.plugin_name), plugin_path));
+                manifest.plugins.push(tv.plugin_name.clone());
+            }
+            entries.push((Path::new("installs").join(&rel), install_path));
+            manifest.tools.push(BundleTool {
+                plugin: tv.plugin_name.clone(),
+                version: tv.version.clone(),
+                path: rel,
+            });
+        }
+        for (path, cf) in &config.config_files {
+            let filename = path.file_name().unwrap().to_string_lossy().to_string();
+            if cf.is_global() || manifest.config_files.contains(&filename) {
+                continue;
+            }
+            entries.push((Path::new("config").join(&filename), path.clone()));
+            manifest.config_files.push(filename);
+        }
+        for (dest, src) in &entries {
+            manifest.add_checksums(dest, src)?;
+        }
+        self.write_archive(&manifest, &entries)?;
+        rtxprintln!(
+            out,
+            "created {} with {} tool(s)",
+            display_path(&self.output),
+            manifest.tools.len()
+        );
+        Ok(())
+    }
+
+    fn write_archive(
+        &self,
+        manifest: &BundleManifest,
+        entries: &[(PathBuf, PathBuf)],
+    ) -> Result<()> {
+        if let Some(parent) = self.output.parent() {
+            file::create_dir_all(parent)?;
+        }
+        let gz = GzEncoder::new(File::create(&self.output)?, Compression::default());
+        let mut tar = tar::Builder::new(gz);
+        tar.follow_symlinks(false);
+
+        let json = serde_json::to_string_pretty(manifest)?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(json.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        tar.append_data(&mut header, MANIFEST_FILENAME, json.as_bytes())?;
+
+        for (dest, src) in entries {
+            if src.is_dir() {
+                tar.append_dir_all(dest, src)?;
+            } else {
+                // config files are often symlinks into a dotfiles repo, store what they point to
+                tar.append_file(dest, &mut File::open(src)?)?;
+            }
+        }
+        tar.into_inner()?.finish()?;
+        Ok(())
+    }
+}
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  $ <bold>rtx bundle create -o toolchain.tar.gz</bold>
+  created toolchain.tar.gz with 2 tool(s)
+"#
+);
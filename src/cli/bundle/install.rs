@@ -0,0 +1,314 @@
+use std::path::{Path, PathBuf};
+
+use clap::ValueHint;
+use color_eyre::eyre::{Context, Result};
+
+use crate::cli::bundle::{BundleManifest, MANIFEST_FILENAME};
+use crate::cli::version::{ARCH, OS};
+use crate::config::Config;
+use crate::file::display_path;
+use crate::output::Output;
+use crate::{dirs, file};
+
+/// Install tools from a bundle created with `rtx bundle create`
+///
+/// Tool versions and plugins are unpacked into the rtx data directory and
+/// shims are rebuilt. Checksums are verified before anything is installed.
+/// Versions and plugins that already exist are kept unless --force is used.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
+pub struct BundleInstall {
+    /// Path to the bundle
+    #[clap(value_hint = ValueHint::FilePath)]
+    bundle: PathBuf,
+
+    /// Overwrite existing tool versions and plugins
+    #[clap(long, short)]
+    force: bool,
+
+    /// Also write the bundled config files into the current directory
+    ///
+    /// Existing files are not overwritten unless --force is used
+    #[clap(long, verbatim_doc_comment)]
+    config: bool,
+}
+
+impl BundleInstall {
+    pub fn run(self, _config: Config, out: &mut Output) -> Result<()> {
+        self.install(out, &dirs::INSTALLS, &dirs::PLUGINS, &dirs::CURRENT)?;
+
+        let mut config = Config::load()?;
+        config.rebuild_shims_and_runtime_symlinks()?;
+        Ok(())
+    }
+
+    /// unpacks the bundle into `installs` and `plugins`, config files go into `cwd`
+    fn install(&self, out: &mut Output, installs: &Path, plugins: &Path, cwd: &Path) -> Result<()> {
+        let data_dir = installs.parent().unwrap();
+        file::create_dir_all(data_dir)?;
+        // unpack next to the installs dir so the final moves are just renames
+        let tmp = tempfile::tempdir_in(data_dir)?;
+        file::untar(&self.bundle, tmp.path())
+            .wrap_err_with(|| format!("failed to extract {}", display_path(&self.bundle)))?;
+        let manifest_path = tmp.path().join(MANIFEST_FILENAME);
+        let manifest: BundleManifest = serde_json::from_str(&file::read_to_string(manifest_path)?)
+            .wrap_err("invalid bundle manifest")?;
+        if manifest.os != *OS || manifest.arch != *ARCH {
+            warn!(
+                "bundle was created on {}-{}, this machine is {}-{}",
+                manifest.os, manifest.arch, *OS, *ARCH
+            );
+        }
+        manifest.verify(tmp.path())?;
+
+        for plugin in &manifest.plugins {
+            let src = tmp.path().join("plugins").join(plugin);
+            if self.move_into_place(&src, &plugins.join(plugin))? {
+                rtxprintln!(out, "installed plugin {plugin}");
+            }
+        }
+        for tool in &manifest.tools {
+            let src = tmp.path().join("installs").join(&tool.path);
+            if self.move_into_place(&src, &installs.join(&tool.path))? {
+                rtxprintln!(out, "installed {}@{}", tool.plugin, tool.version);
+            }
+        }
+        if self.config {
+            for filename in &manifest.config_files {
+                let src = tmp.path().join("config").join(filename);
+                if self.move_into_place(&src, &cwd.join(filename))? {
+                    rtxprintln!(out, "wrote {filename}");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn move_into_place(&self, src: &Path, dest: &Path) -> Result<bool> {
+        if dest.exists() || dest.is_symlink() {
+            if !self.force {
+                debug!("{} already exists, skipping", display_path(dest));
+                return Ok(false);
+            }
+            file::remove_all(dest)?;
+        }
+        file::create_dir_all(dest.parent().unwrap())?;
+        file::rename(src, dest)?;
+        Ok(true)
+    }
+}
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  $ <bold>rtx bundle install toolchain.tar.gz</bold>
+  installed tiny@3.1.0
+"#
+);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::fs::File;
+    use std::path::Path;
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    use crate::cli::bundle::{checksum, BundleManifest, BundleTool};
+    use crate::output::Output;
+    use crate::{assert_cli, dirs};
+
+    use super::*;
+
+    #[test]
+    fn test_bundle() {
+        let bundle = dirs::CACHE.join("test-bundle.tar.gz");
+        let bundle = bundle.to_string_lossy().to_string();
+        let stdout = assert_cli!("bundle", "create", "-o", &bundle);
+        assert!(stdout.contains("tool(s)"));
+        // everything in the bundle is already installed so nothing gets replaced,
+        // --force is tested in test_bundle_install since it would swap out installs other tests use
+        let stdout = assert_cli!("bundle", "install", &bundle);
+        assert_eq!(stdout, "");
+    }
+
+    #[test]
+    fn test_bundle_install() {
+        let bundle = dirs::CACHE.join("test-bundle-install.tar.gz");
+        assert_cli!(
+            "bundle",
+            "create",
+            "-o",
+            &bundle.to_string_lossy().to_string()
+        );
+        let root = tempfile::tempdir().unwrap();
+        let install = |force: bool| {
+            let mut out = Output::tracked();
+            let cmd = BundleInstall {
+                bundle: bundle.clone(),
+                force,
+                config: true,
+            };
+            let root = root.path();
+            cmd.install(
+                &mut out,
+                &root.join("installs"),
+                &root.join("plugins"),
+                root,
+            )
+            .unwrap();
+            out.stdout.content
+        };
+        let stdout = install(false);
+        assert!(stdout.contains("installed tiny@"));
+        assert!(stdout.contains("wrote .test-tool-versions"));
+        assert!(root.path().join("installs/tiny").is_dir());
+        assert!(root.path().join(".test-tool-versions").is_file());
+        assert_eq!(install(false), "");
+        assert!(install(true).contains("installed tiny@"));
+    }
+
+    #[test]
+    fn test_bundle_install_invalid() {
+        let root = tempfile::tempdir().unwrap();
+        let install = |manifest: &BundleManifest, files: &[(&str, &str)]| {
+            let bundle = root.path().join("bundle.tar.gz");
+            write_bundle(&bundle, manifest, files, &[]);
+            let cmd = BundleInstall {
+                bundle,
+                force: false,
+                config: false,
+            };
+            let installs = root.path().join("data/installs");
+            let plugins = root.path().join("data/plugins");
+            cmd.install(&mut Output::tracked(), &installs, &plugins, root.path())
+                .unwrap_err()
+                .to_string()
+        };
+        let tool = |path: &str| BundleTool {
+            plugin: "tiny".into(),
+            version: "1.0.0".into(),
+            path: path.into(),
+        };
+        let files = [("installs/tiny/1.0.0/bin/tiny", "echo tiny")];
+
+        let manifest = BundleManifest {
+            tools: vec![tool("tiny/1.0.0")],
+            checksums: BTreeMap::from([(files[0].0.to_string(), "abc".to_string())]),
+            ..Default::default()
+        };
+        assert!(install(&manifest, &files).contains("Checksum mismatch"));
+
+        let manifest = BundleManifest {
+            tools: vec![tool("tiny/1.0.0")],
+            ..Default::default()
+        };
+        assert!(install(&manifest, &files).contains("has no checksum"));
+
+        let manifest = BundleManifest {
+            tools: vec![tool("../../escaped")],
+            ..Default::default()
+        };
+        assert!(install(&manifest, &[]).contains("invalid path"));
+
+        let manifest = BundleManifest {
+            plugins: vec!["/tmp/escaped".into()],
+            ..Default::default()
+        };
+        assert!(install(&manifest, &[]).contains("invalid path"));
+        assert!(!root.path().join("escaped").exists());
+    }
+
+    #[test]
+    fn test_bundle_install_symlinks() {
+        let root = tempfile::tempdir().unwrap();
+        let install = |links: &[(&str, &str)], checksums: BTreeMap<String, String>| {
+            let bundle = root.path().join("bundle.tar.gz");
+            let manifest = BundleManifest {
+                tools: vec![BundleTool {
+                    plugin: "tiny".into(),
+                    version: "1.0.0".into(),
+                    path: "tiny/1.0.0".into(),
+                }],
+                checksums,
+                ..Default::default()
+            };
+            write_bundle(&bundle, &manifest, &[], links);
+            let cmd = BundleInstall {
+                bundle,
+                force: true,
+                config: false,
+            };
+            let installs = root.path().join("data/installs");
+            let plugins = root.path().join("data/plugins");
+            cmd.install(&mut Output::tracked(), &installs, &plugins, root.path())
+        };
+        let link_checksum = |key: &str, target: &str| {
+            let path = root.path().join("link");
+            let _ = fs::remove_file(&path);
+            std::os::unix::fs::symlink(target, &path).unwrap();
+            (key.to_string(), checksum(&path).unwrap())
+        };
+
+        let link = ("installs/tiny/1.0.0/bin/tiny", "../lib/tiny");
+        let checksums = BTreeMap::from([link_checksum(link.0, link.1)]);
+        install(&[link], checksums.clone()).unwrap();
+        let installed = root.path().join("data/installs/tiny/1.0.0/bin/tiny");
+        assert_eq!(fs::read_link(installed).unwrap(), Path::new(link.1));
+
+        let err = install(&[(link.0, "../lib/other")], checksums)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Checksum mismatch for link"));
+
+        for target in ["../../../../../etc/passwd", "/etc/passwd", "../../2.0.0"] {
+            let link = ("installs/tiny/1.0.0/bin/tiny", target);
+            let checksums = BTreeMap::from([link_checksum(link.0, link.1)]);
+            let err = install(&[link], checksums).unwrap_err().to_string();
+            assert!(
+                err.contains("links outside of the bundle"),
+                "{target}: {err}"
+            );
+        }
+
+        // `up` resolves to a directory higher than its path suggests
+        let links = [
+            ("installs/tiny/1.0.0/bin/up", ".."),
+            ("installs/tiny/1.0.0/bin/evil", "up/../.."),
+        ];
+        let checksums = links.iter().map(|(k, t)| link_checksum(k, t)).collect();
+        let err = install(&links, checksums).unwrap_err().to_string();
+        assert!(err.contains("evil links outside of the bundle"), "{err}");
+    }
+
+    fn write_bundle(
+        path: &Path,
+        manifest: &BundleManifest,
+        files: &[(&str, &str)],
+        links: &[(&str, &str)],
+    ) {
+        let gz = GzEncoder::new(File::create(path).unwrap(), Compression::default());
+        let mut tar = tar::Builder::new(gz);
+        let mut append = |name: &str, content: &str| {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            tar.append_data(&mut header, name, content.as_bytes())
+                .unwrap();
+        };
+        append(MANIFEST_FILENAME, &serde_json::to_string(manifest).unwrap());
+        for (name, content) in files {
+            append(name, content);
+        }
+        for (name, target) in links {
+            let mut header = tar::Header::new_gnu();
+            header.set_entry_type(tar::EntryType::Symlink);
+            header.set_size(0);
+            header.set_mode(0o777);
+            tar.append_link(&mut header, name, target).unwrap();
+        }
+        tar.into_inner().unwrap().finish().unwrap();
+    }
+}
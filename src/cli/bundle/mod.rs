@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use clap::Subcommand;
+use color_eyre::eyre::{bail, ensure, Result};
+use itertools::Itertools;
+use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::config::Config;
+use crate::file;
+use crate::hash::{ensure_checksum_sha256, file_hash_sha256};
+use crate::output::Output;
+
+mod create;
+mod install;
+
+/// Create or install offline bundles of tools
+///
+/// A bundle is a tarball containing the installed versions of the current
+/// toolset, the repos of any external plugins they use, and the config files
+/// that define them. It can be used to set up machines without network access.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment)]
+pub struct Bundle {
+    #[clap(subcommand)]
+    command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    Create(create::BundleCreate),
+    Install(install::BundleInstall),
+}
+
+impl Commands {
+    pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        match self {
+            Self::Create(cmd) => cmd.run(config, out),
+            Self::Install(cmd) => cmd.run(config, out),
+        }
+    }
+}
+
+impl Bundle {
+    pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        self.command.run(config, out)
+    }
+}
+
+const MANIFEST_FILENAME: &str = "manifest.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BundleManifest {
+    rtx_version: String,
+    os: String,
+    arch: String,
+    tools: Vec<BundleTool>,
+    plugins: Vec<String>,
+    config_files: Vec<String>,
+    /// sha256 of every file in the bundle (or of the target of a symlink), keyed by its path in
+    /// the archive
+    checksums: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BundleTool {
+    plugin: String,
+    version: String,
+    /// directory under installs/ in the archive
+    path: String,
+}
+
+impl BundleManifest {
+    /// records checksums for every file under `src`, keyed by `prefix` in the archive
+    fn add_checksums(&mut self, prefix: &Path, src: &Path) -> Result<()> {
+        if !src.is_dir() {
+            // single files are archived with symlinks followed
+            let checksum = file_hash_sha256(src)?;
+            self.checksums
+                .insert(prefix.to_string_lossy().to_string(), checksum);
+            return Ok(());
+        }
+        for f in file::recursive_ls(src)? {
+            let key = prefix.join(f.strip_prefix(src)?);
+            self.checksums
+                .insert(key.to_string_lossy().to_string(), checksum(&f)?);
+        }
+        Ok(())
+    }
+
+    /// ensures every path in the manifest stays inside the directory it is installed into,
+    /// every symlink in the extracted bundle at `root` points inside the tool or plugin it belongs
+    /// to, and every file is listed with a matching checksum
+    fn verify(&self, root: &Path) -> Result<()> {
+        for plugin in &self.plugins {
+            ensure_file_name(plugin)?;
+        }
+        for filename in &self.config_files {
+            ensure_file_name(filename)?;
+        }
+        for tool in &self.tools {
+            ensure_relative_path(&tool.path)?;
+        }
+        for path in self.checksums.keys() {
+            ensure_relative_path(path)?;
+        }
+        let root = root.canonicalize()?;
+        // each of these is moved into place on its own so links can't point between them
+        let entries = self
+            .tools
+            .iter()
+            .map(|t| root.join("installs").join(&t.path))
+            .chain(self.plugins.iter().map(|p| root.join("plugins").join(p)))
+            .collect_vec();
+        for f in file::recursive_ls(&root)? {
+            let key = f.strip_prefix(&root)?.to_string_lossy().to_string();
+            if key == MANIFEST_FILENAME {
+                continue;
+            }
+            if f.is_symlink() {
+                let entry = entries.iter().find(|e| f.starts_with(e));
+                ensure_link_inside(&key, &f, entry)?;
+            }
+            if !self.checksums.contains_key(&key) {
+                bail!("{key} is in the bundle but has no checksum in the manifest");
+            }
+        }
+        for (path, expected) in &self.checksums {
+            let f = root.join(path);
+            match f.is_symlink() {
+                true => {
+                    let actual = checksum(&f)?;
+                    ensure!(
+                        &actual == expected,
+                        "Checksum mismatch for link {path}:\nExpected: {expected}\nActual:   {actual}"
+                    );
+                }
+                false => ensure_checksum_sha256(&f, expected)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// sha256 of a file's contents, or of the target path if it is a symlink
+fn checksum(path: &Path) -> Result<String> {
+    match path.is_symlink() {
+        true => {
+            let target = fs::read_link(path)?;
+            let hash = Sha256::digest(target.to_string_lossy().as_bytes());
+            Ok(format!("{hash:x}"))
+        }
+        false => file_hash_sha256(path),
+    }
+}
+
+/// errors unless the symlink `link` (`key` in the archive) resolves to a path inside `base`,
+/// links that aren't part of a tool or plugin have no `base` and are always rejected
+///
+/// `link` and `base` must be canonical apart from the link itself
+fn ensure_link_inside(key: &str, link: &Path, base: Option<&PathBuf>) -> Result<()> {
+    let target = fs::read_link(link)?;
+    if let Some(base) = base {
+        if target.is_relative() && resolve_link(link, &target)?.starts_with(base) {
+            return Ok(());
+        }
+    }
+    bail!("{key} links outside of the bundle: {}", target.display())
+}
+
+fn resolve_link(link: &Path, target: &Path) -> Result<PathBuf> {
+    let path = link.parent().unwrap().join(target);
+    // resolve the part that exists on disk, the rest can't contain symlinks
+    let existing = path
+        .ancestors()
+        .find(|p| p.exists())
+        .unwrap_or(link.parent().unwrap());
+    let mut resolved = existing.canonicalize()?;
+    for c in path.strip_prefix(existing)?.components() {
+        match c {
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            Component::Normal(c) => resolved.push(c),
+            _ => {}
+        }
+    }
+    Ok(resolved)
+}
+
+/// errors unless `path` is relative and made up only of plain names (no `..`, `/` or prefix)
+fn ensure_relative_path(path: &str) -> Result<()> {
+    let p = Path::new(path);
+    ensure!(
+        !path.is_empty() && p.components().all(|c| matches!(c, Component::Normal(_))),
+        "invalid path in bundle manifest: {path}"
+    );
+    Ok(())
+}
+
+fn ensure_file_name(name: &str) -> Result<()> {
+    ensure_relative_path(name)?;
+    ensure!(
+        Path::new(name).components().count() == 1,
+        "invalid name in bundle manifest: {name}"
+    );
+    Ok(())
+}
@@ -24,6 +24,7 @@ impl Completion {
         let c = match self.shell.or(self.shell_type).unwrap() {
             Shell::Bash => include_str!("../../completions/rtx.bash"),
             Shell::Fish => include_str!("../../completions/rtx.fish"),
+            Shell::Pwsh => include_str!("../../completions/rtx.ps1"),
             Shell::Zsh => include_str!("../../completions/_rtx"),
         };
         rtxprintln!(out, "{}", c.trim());
@@ -37,6 +38,7 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   $ <bold>rtx completion bash > /etc/bash_completion.d/rtx</bold>
   $ <bold>rtx completion zsh  > /usr/local/share/zsh/site-functions/_rtx</bold>
   $ <bold>rtx completion fish > ~/.config/fish/completions/rtx.fish</bold>
+  $ <bold>rtx completion pwsh | Out-String | Invoke-Expression</bold>
 "#
 );
 
@@ -44,17 +46,19 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
 enum Shell {
     Bash,
     Fish,
+    Pwsh,
     Zsh,
 }
 
 impl ValueEnum for Shell {
     fn value_variants<'a>() -> &'a [Self] {
-        &[Self::Bash, Self::Fish, Self::Zsh]
+        &[Self::Bash, Self::Fish, Self::Pwsh, Self::Zsh]
     }
     fn from_str(input: &str, _ignore_case: bool) -> std::result::Result<Self, String> {
         match input {
             "bash" => Ok(Self::Bash),
             "fish" => Ok(Self::Fish),
+            "pwsh" | "powershell" => Ok(Self::Pwsh),
             "zsh" => Ok(Self::Zsh),
             _ => Err(format!("unknown shell type: {}", input)),
         }
@@ -69,6 +73,7 @@ impl Display for Shell {
         match self {
             Self::Bash => write!(f, "bash"),
             Self::Fish => write!(f, "fish"),
+            Self::Pwsh => write!(f, "pwsh"),
             Self::Zsh => write!(f, "zsh"),
         }
     }
@@ -83,5 +88,6 @@ mod tests {
         assert_cli!("completion", "zsh");
         assert_cli!("completion", "bash");
         assert_cli!("completion", "fish");
+        assert_cli!("completion", "pwsh");
     }
 }
@@ -1,10 +1,12 @@
+use std::env::join_paths;
+
 use color_eyre::eyre::{eyre, Result};
 use console::style;
 
 use crate::config::Config;
-use crate::hook_env;
 use crate::output::Output;
-use crate::shell::get_shell;
+use crate::shell::{get_shell, is_shims_mode};
+use crate::{dirs, env, hook_env};
 
 /// Disable rtx for current shell session
 ///
@@ -22,6 +24,12 @@ impl Deactivate {
         let shell = get_shell(None).expect("no shell detected");
 
         out.stdout.write(hook_env::clear_old_env(&*shell));
+        if is_shims_mode() {
+            let path = env::PATH.iter().filter(|p| **p != *dirs::SHIMS);
+            let path = join_paths(path)?.to_string_lossy().to_string();
+            out.stdout.write(shell.set_env("PATH", &path));
+            out.stdout.write(shell.unset_env("__RTX_SHIMS"));
+        }
         let output = shell.deactivate();
         out.stdout.write(output);
 
@@ -11,7 +11,7 @@ use crate::config::Config;
 use crate::git::Git;
 use crate::output::Output;
 use crate::plugins::PluginType;
-use crate::shell::ShellType;
+use crate::shell::{is_shims_mode, ShellType};
 use crate::toolset::ToolsetBuilder;
 use crate::{cli, cmd, dirs};
 use crate::{duration, env};
@@ -27,6 +27,7 @@ impl Doctor {
         rtxprintln!(out, "{}", rtx_version());
         rtxprintln!(out, "{}", build_info());
         rtxprintln!(out, "{}", shell());
+        rtxprintln!(out, "{}", activation_mode(&config));
         rtxprintln!(out, "{}", rtx_data_dir());
         rtxprintln!(out, "{}", rtx_env_vars());
         rtxprintln!(
@@ -92,6 +93,20 @@ fn shims_on_path() -> bool {
     env::PATH.contains(&*dirs::SHIMS)
 }
 
+fn activation_mode(config: &Config) -> String {
+    let mut s = style("activation mode:\n").bold().to_string();
+    let mode = match (config.is_activated(), shims_on_path()) {
+        (true, true) if is_shims_mode() => {
+            "hybrid (rtx activate --shims)\nthe environment is updated when changing directories, shims are used otherwise"
+        }
+        (true, _) => "rtx activate\nthe environment is updated on every prompt",
+        (false, true) => "shims\ntools are resolved every time a shim runs",
+        (false, false) => "not activated",
+    };
+    s.push_str(&indent(format!("{mode}\n")));
+    s
+}
+
 fn rtx_data_dir() -> String {
     let mut s = style("rtx data directory:\n").bold().to_string();
     s.push_str(&format!("  {}\n", env::RTX_DATA_DIR.to_string_lossy()));
@@ -0,0 +1,289 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+
+use color_eyre::eyre::Result;
+use console::{pad_str, style, Alignment};
+use indicatif::HumanBytes;
+use itertools::Itertools;
+use rayon::prelude::*;
+use serde_derive::Serialize;
+use versions::Versioning;
+
+use crate::config::Config;
+use crate::output::Output;
+use crate::plugins::core::is_plugin_cache_dir;
+use crate::plugins::{unalias_plugin, PluginName};
+use crate::runtime_symlinks::is_runtime_symlink;
+use crate::{dirs, file};
+
+/// Show disk usage of installed tools
+///
+/// Sizes include the install, download, and cache directories of each version
+/// and the plugin's own files. Versions used by a tracked config file are marked
+/// with "(used)", `rtx prune` will delete the rest.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
+pub struct Du {
+    /// Only show disk usage of these plugins
+    #[clap()]
+    plugin: Option<Vec<PluginName>>,
+
+    /// How to sort plugins and versions
+    #[clap(long, short, value_enum, default_value_t = DuSort::Name)]
+    sort: DuSort,
+
+    /// Output in json format
+    #[clap(long, short = 'J')]
+    json: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum DuSort {
+    /// By plugin name then version
+    Name,
+    /// Largest first
+    Size,
+}
+
+impl Du {
+    pub fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        let used = config
+            .get_tracked_versions()?
+            .into_iter()
+            .filter_map(|(_, tv)| {
+                let version = tv.install_path().file_name()?.to_string_lossy().to_string();
+                Some((tv.plugin_name, version))
+            })
+            .collect::<HashSet<_>>();
+        let mut plugins = self
+            .list_plugins()?
+            .into_par_iter()
+            .map(|plugin| PluginUsage::new(plugin, &used))
+            .collect::<Result<Vec<_>>>()?;
+        match self.sort {
+            DuSort::Name => {
+                plugins.sort_by(|a, b| a.plugin.cmp(&b.plugin));
+                for p in &mut plugins {
+                    p.versions
+                        .sort_by_cached_key(|v| Versioning::new(&v.version).unwrap_or_default());
+                }
+            }
+            DuSort::Size => {
+                plugins.sort_by_key(|p| Reverse(p.total));
+                for p in &mut plugins {
+                    p.versions.sort_by_key(|v| Reverse(v.total));
+                }
+            }
+        }
+
+        if self.json {
+            rtxprintln!(out, "{}", serde_json::to_string_pretty(&plugins)?);
+        } else {
+            self.display(&plugins, out);
+        }
+        Ok(())
+    }
+
+    /// plugins with any installs, downloads, or plugin files
+    fn list_plugins(&self) -> Result<Vec<PluginName>> {
+        let mut plugins = BTreeSet::new();
+        for dir in [&*dirs::PLUGINS, &*dirs::INSTALLS, &*dirs::DOWNLOADS] {
+            plugins.extend(file::dir_subdirs(dir)?);
+        }
+        if let Some(only) = &self.plugin {
+            let only = only.iter().map(|p| unalias_plugin(p)).collect_vec();
+            plugins.retain(|p| only.contains(&p.as_str()));
+        }
+        Ok(plugins.into_iter().collect())
+    }
+
+    fn display(&self, plugins: &[PluginUsage], out: &mut Output) {
+        let mut rows = vec![];
+        for p in plugins {
+            if p.plugin_size > 0 {
+                rows.push([
+                    p.plugin.clone(),
+                    "(plugin)".into(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    format_size(p.plugin_size),
+                ]);
+            }
+            for v in &p.versions {
+                let version = match v.used {
+                    true => format!("{} (used)", v.version),
+                    false => v.version.clone(),
+                };
+                rows.push([
+                    p.plugin.clone(),
+                    version,
+                    format_size(v.installs),
+                    format_size(v.downloads),
+                    format_size(v.cache),
+                    format_size(v.total),
+                ]);
+            }
+        }
+        let header = ["Tool", "Version", "Installs", "Downloads", "Cache", "Total"];
+        let widths = header.map(|h| h.len());
+        let widths = rows.iter().fold(widths, |mut widths, row| {
+            for (w, cell) in widths.iter_mut().zip(row) {
+                *w = (*w).max(cell.len());
+            }
+            widths
+        });
+        let format_row = |row: &[&str]| {
+            row.iter()
+                .zip(widths)
+                .enumerate()
+                .map(|(i, (cell, width))| match i {
+                    0 | 1 => pad_str(cell, width, Alignment::Left, None),
+                    _ => pad_str(cell, width, Alignment::Right, None),
+                })
+                .join("  ")
+        };
+        rtxprintln!(out, "{}", style(format_row(&header)).dim());
+        for row in &rows {
+            let row = format_row(&row.iter().map(|s| s.as_str()).collect_vec());
+            rtxprintln!(out, "{}", row.trim_end());
+        }
+        let total = plugins.iter().map(|p| p.total).sum();
+        rtxprintln!(
+            out,
+            "{}",
+            style(format!("total {}", HumanBytes(total))).bold()
+        );
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct PluginUsage {
+    plugin: PluginName,
+    /// the plugin's own files, e.g.: its git repo, remote version cache, or python's pyenv
+    plugin_size: u64,
+    versions: Vec<VersionUsage>,
+    total: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct VersionUsage {
+    version: String,
+    installs: u64,
+    downloads: u64,
+    cache: u64,
+    total: u64,
+    /// used by a tracked config file
+    used: bool,
+}
+
+impl PluginUsage {
+    fn new(plugin: PluginName, used: &HashSet<(PluginName, String)>) -> Result<Self> {
+        let installs = dirs::INSTALLS.join(&plugin);
+        let downloads = dirs::DOWNLOADS.join(&plugin);
+        let cache = dirs::CACHE.join(&plugin);
+        let mut versions = BTreeSet::new();
+        for dir in [&installs, &downloads, &cache] {
+            versions.extend(
+                file::dir_subdirs(dir)?
+                    .into_iter()
+                    .filter(|v| !is_runtime_symlink(&dir.join(v)))
+                    .filter(|v| !is_plugin_cache_dir(&plugin, v)),
+            );
+        }
+        let versions = versions
+            .into_par_iter()
+            .map(|version| {
+                let size = |dir: &Path| file::dir_size(&dir.join(&version));
+                let (installs, downloads, cache) =
+                    (size(&installs)?, size(&downloads)?, size(&cache)?);
+                Ok(VersionUsage {
+                    used: used.contains(&(plugin.clone(), version.clone())),
+                    version,
+                    installs,
+                    downloads,
+                    cache,
+                    total: installs + downloads + cache,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut plugin_size = file::dir_size(&dirs::PLUGINS.join(&plugin))?;
+        for f in file::dir_files(&cache)? {
+            plugin_size += cache.join(f).metadata()?.len();
+        }
+        for dir in file::dir_subdirs(&cache)? {
+            if is_plugin_cache_dir(&plugin, &dir) {
+                plugin_size += file::dir_size(&cache.join(dir))?;
+            }
+        }
+        let total = plugin_size + versions.iter().map(|v| v.total).sum::<u64>();
+        Ok(Self {
+            plugin,
+            plugin_size,
+            versions,
+            total,
+        })
+    }
+}
+
+fn format_size(size: u64) -> String {
+    match size {
+        0 => "-".into(),
+        size => HumanBytes(size).to_string(),
+    }
+}
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  $ <bold>rtx du</bold>
+  Tool    Version          Installs  Downloads     Cache       Total
+  node    18.0.0         170.00 MiB          -         -  170.00 MiB
+  node    20.0.0 (used)  180.00 MiB  40.00 MiB  12.00 KiB 220.01 MiB
+  python  (plugin)                                          1.20 MiB
+  python  3.11.0 (used)  210.00 MiB          -   4.00 KiB 210.00 MiB
+  total 601.21 MiB
+
+  # largest first
+  $ <bold>rtx du --sort size</bold>
+
+  $ <bold>rtx du node --json</bold>
+"#
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_cli, dirs, file};
+
+    #[test]
+    fn test_du() {
+        let stdout = assert_cli!("du", "tiny", "--sort", "size");
+        assert!(stdout.contains("3.1.0"));
+        let stdout = assert_cli!("du", "dummy", "--json");
+        let du: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        assert_eq!(du[0]["plugin"], "dummy");
+    }
+
+    #[test]
+    fn test_du_plugin_cache_dirs() {
+        let pyenv = dirs::CACHE.join("python/pyenv");
+        let download = dirs::DOWNLOADS.join("python/3.12.0");
+        file::create_dir_all(&pyenv).unwrap();
+        file::create_dir_all(&download).unwrap();
+        file::write(pyenv.join("README.md"), "pyenv").unwrap();
+        let stdout = assert_cli!("du", "python", "--json");
+        // other tests use these dirs so only what this test created is removed
+        file::remove_file(pyenv.join("README.md")).unwrap();
+        file::remove_all(&download).unwrap();
+        let du: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        assert_eq!(du[0]["plugin_size"], 5);
+        let versions = du[0]["versions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["version"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert!(versions.contains(&"3.12.0"));
+        assert!(!versions.contains(&"pyenv"));
+    }
+}
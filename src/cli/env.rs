@@ -2,6 +2,7 @@ use color_eyre::eyre::Result;
 
 use crate::cli::args::tool::{ToolArg, ToolArgParser};
 use crate::config::Config;
+use crate::env;
 use crate::output::Output;
 use crate::shell::{get_shell, ShellType};
 use crate::toolset::{Toolset, ToolsetBuilder};
@@ -10,6 +11,8 @@ use crate::toolset::{Toolset, ToolsetBuilder};
 ///
 /// Use this if you don't want to permanently install rtx. It's not necessary to
 /// use this if you have `rtx activate` in your shell rc file.
+///
+/// Use `--profile` to preview the environment of other profiles than the ones in RTX_ENV.
 #[derive(Debug, clap::Args)]
 #[clap(visible_alias = "e", verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
 pub struct Env {
@@ -24,10 +27,19 @@ pub struct Env {
     /// Output in JSON format
     #[clap(long, short = 'J', overrides_with = "shell")]
     json: bool,
+
+    /// [experimental] Profile(s) to use instead of RTX_ENV
+    /// e.g.: `rtx env --profile ci,linux`
+    #[clap(long, value_name = "PROFILE", verbatim_doc_comment)]
+    profile: Option<String>,
 }
 
 impl Env {
     pub fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        if let Some(profile) = &self.profile {
+            // profiles are read while loading config so it needs to be loaded again
+            config = Config::load_with_profiles(Some(env::split_profiles(profile)))?;
+        }
         let mut ts = ToolsetBuilder::new()
             .with_args(&self.tool)
             .build(&mut config)?;
@@ -64,6 +76,9 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   $ <bold>eval "$(rtx env -s zsh)"</bold>
   $ <bold>rtx env -s fish | source</bold>
   $ <bold>execx($(rtx env -s xonsh))</bold>
+
+  # what the environment would be in CI on linux
+  $ <bold>rtx env --profile ci,linux</bold>
 "#
 );
 
@@ -74,8 +89,8 @@ mod tests {
     use pretty_assertions::assert_str_eq;
 
     use crate::cli::tests::grep;
-    use crate::dirs;
     use crate::{assert_cli, assert_cli_snapshot};
+    use crate::{dirs, file};
 
     #[test]
     fn test_env() {
@@ -127,6 +142,22 @@ mod tests {
         assert!(stdout.contains("export PATH="));
     }
 
+    #[test]
+    fn test_env_profile() {
+        let cf_path = dirs::CURRENT.join(".test.rtx.toml");
+        file::write(&cf_path, "[env.ci]\nRTX_PROFILE_TEST = 'ci'\n").unwrap();
+
+        let stdout = assert_cli!("env", "--profile", "ci", "-s", "bash");
+        assert_str_eq!(
+            grep(stdout, "RTX_PROFILE_TEST"),
+            "export RTX_PROFILE_TEST=ci"
+        );
+        let stdout = assert_cli!("env", "-s", "bash");
+        assert!(!stdout.contains("RTX_PROFILE_TEST"));
+
+        file::remove_file(&cf_path).unwrap();
+    }
+
     #[test]
     fn test_env_json() {
         assert_cli_snapshot!("env", "-J");
@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 use std::ffi::{OsStr, OsString};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use clap::ValueHint;
 use color_eyre::eyre::{eyre, Result};
@@ -9,10 +9,10 @@ use duct::IntoExecutablePath;
 use crate::cli::args::tool::{ToolArg, ToolArgParser};
 #[cfg(test)]
 use crate::cmd;
-use crate::config::Config;
-use crate::env;
+use crate::config::{Config, MissingRuntimeBehavior};
 use crate::output::Output;
 use crate::toolset::ToolsetBuilder;
+use crate::{env, last_used};
 
 /// Execute a command with tool(s) set
 ///
@@ -45,16 +45,37 @@ pub struct Exec {
 }
 
 impl Exec {
-    pub fn run(self, mut config: Config, _out: &mut Output) -> Result<()> {
+    pub fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
         let mut ts = ToolsetBuilder::new()
             .with_args(&self.tool)
             .build(&mut config)?;
         ts.install_arg_versions(&mut config)?;
+        if config.settings.missing_runtime_behavior != MissingRuntimeBehavior::Warn {
+            // warnings are left to hook-env, every shim invocation goes through here
+            let missing = ts
+                .list_missing_versions(&config)
+                .into_iter()
+                .cloned()
+                .collect();
+            ts.install_missing_versions(&mut config, missing)?;
+        }
+
+        for (_, tv) in ts.list_current_installed_versions(&config) {
+            if self.tool.iter().any(|t| t.plugin == tv.plugin_name) {
+                last_used::touch(&tv);
+            }
+        }
 
-        let (program, args) = parse_command(&env::SHELL, &self.command, &self.c);
         let env = ts.env_with_path(&config);
+        self.run_with_env(env, out)
+    }
 
-        self.exec(program, args, env)
+    /// runs the command with an env that was already resolved, e.g.: by the shim cache
+    pub fn run_with_env(self, exec_env: BTreeMap<String, String>, _out: &mut Output) -> Result<()> {
+        let (program, args) = parse_command(&env::SHELL, &self.command, &self.c);
+        // shims run the bin they resolved directly
+        last_used::touch_bin(Path::new(&program));
+        self.exec(program, args, exec_env)
     }
 
     #[cfg(not(test))]
@@ -130,8 +151,11 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
 
 #[cfg(test)]
 mod tests {
-    use crate::assert_cli;
     use crate::cli::tests::cli_run;
+    use crate::config::config_file::tool_versions::ToolVersions;
+    use crate::{assert_cli, dirs};
+
+    use super::*;
 
     #[test]
     fn test_exec_ok() {
@@ -153,4 +177,25 @@ mod tests {
     fn test_exec_cd() {
         assert_cli!("exec", "-C", "/tmp", "--", "pwd");
     }
+
+    #[test]
+    fn test_exec_autoinstall() {
+        let mut config = Config::load().unwrap();
+        let path = dirs::CURRENT.join(".autoinstall-tool-versions");
+        let cf = ToolVersions::parse_str("dummy 2.0.1", path.clone(), true).unwrap();
+        config.config_files.insert(path, Box::new(cf));
+        config
+            .config_files
+            .move_index(config.config_files.len() - 1, 0);
+        config.settings.missing_runtime_behavior = MissingRuntimeBehavior::AutoInstall;
+        let exec = Exec {
+            tool: vec![],
+            command: Some(vec!["dummy".into()]),
+            c: None,
+            cd: None,
+        };
+        exec.run(config, &mut Output::tracked()).unwrap();
+        assert!(dirs::INSTALLS.join("dummy/2.0.1/bin/dummy").exists());
+        assert_cli!("uninstall", "dummy@2.0.1");
+    }
 }
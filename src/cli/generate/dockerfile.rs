@@ -0,0 +1,199 @@
+use std::collections::HashSet;
+use std::env::split_paths;
+use std::path::PathBuf;
+
+use clap::ValueHint;
+use color_eyre::eyre::Result;
+use itertools::Itertools;
+
+use crate::config::Config;
+use crate::output::Output;
+use crate::plugins::PluginType;
+use crate::toolset::{ToolVersionRequest, ToolsetBuilder};
+use crate::{dirs, env, file};
+
+/// Generate a Dockerfile that installs the current toolset
+///
+/// The image installs rtx and exactly the tool versions that are active in the
+/// current directory. Environment variables that rtx would set (including PATH)
+/// are added as ENV lines so the tools work without `rtx activate`.
+/// Only installed tools contribute to the ENV lines, run `rtx install` first.
+/// Values rendered from templates or with names that look like secrets are not
+/// written to the Dockerfile, they're declared as ARG lines to pass with --build-arg.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
+pub struct GenerateDockerfile {
+    /// Base image to build on
+    #[clap(long, default_value = "ubuntu:22.04")]
+    base_image: String,
+
+    /// Write the Dockerfile to this path instead of stdout
+    #[clap(short, long, value_hint = ValueHint::FilePath)]
+    output: Option<PathBuf>,
+}
+
+/// where rtx keeps its data inside the image
+const CONTAINER_DATA_DIR: &str = "/rtx";
+
+impl GenerateDockerfile {
+    pub fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        let ts = ToolsetBuilder::new().build(&mut config)?;
+        let mut plugins: Vec<String> = vec![];
+        let mut plugin_installs = vec![];
+        let mut tools = vec![];
+        for (p, tv) in ts.list_current_versions(&config) {
+            match tv.request {
+                ToolVersionRequest::Path(..) | ToolVersionRequest::System(_) => {
+                    warn!("{tv} is not managed by rtx, skipping");
+                    continue;
+                }
+                _ => tools.push(tv.to_string()),
+            }
+            if p.get_type() == PluginType::External && !plugins.contains(&tv.plugin_name) {
+                plugins.push(tv.plugin_name.clone());
+                let cmd = match p
+                    .get_remote_url()
+                    .or_else(|| config.get_repo_url(&tv.plugin_name))
+                {
+                    Some(url) => format!("RUN rtx plugins install {} {url}", p.name()),
+                    None => {
+                        warn!("no repository url known for plugin {}, skipping", p.name());
+                        format!(
+                            "# {}: no repository url known, install it manually",
+                            p.name()
+                        )
+                    }
+                };
+                plugin_installs.push(cmd);
+            }
+        }
+
+        let mut lines = vec![
+            "# generated by `rtx generate dockerfile`".to_string(),
+            format!("FROM {}", self.base_image),
+            String::new(),
+            "RUN apt-get update && apt-get install -y \\".to_string(),
+            "    build-essential \\".to_string(),
+            "    ca-certificates \\".to_string(),
+            "    curl \\".to_string(),
+            "    git \\".to_string(),
+            "    && apt-get clean && rm -rf /var/lib/apt/lists/*".to_string(),
+            String::new(),
+            format!("ENV RTX_DATA_DIR=\"{CONTAINER_DATA_DIR}\""),
+            format!("ENV RTX_CONFIG_DIR=\"{CONTAINER_DATA_DIR}\""),
+            format!("ENV RTX_CACHE_DIR=\"{CONTAINER_DATA_DIR}/cache\""),
+            format!("ENV RTX_INSTALL_PATH=\"{CONTAINER_DATA_DIR}/bin/rtx\""),
+            format!("ENV PATH=\"{CONTAINER_DATA_DIR}/bin:$PATH\""),
+            "RUN curl -fsSL https://rtx.pub/install.sh | sh".to_string(),
+            String::new(),
+        ];
+        lines.extend(plugin_installs);
+        if !tools.is_empty() {
+            lines.push(format!("RUN rtx install {}", tools.join(" ")));
+        }
+        lines.push(String::new());
+        let templated: HashSet<String> = config
+            .config_files
+            .values()
+            .flat_map(|cf| cf.env_templated())
+            .collect();
+        for (k, v) in ts.env_with_path(&config) {
+            if templated.contains(&k) || looks_secret(&k) {
+                // ENV would bake the value into the image, these are passed with --build-arg
+                lines.push(format!("ARG {k}"));
+                continue;
+            }
+            let v = match k.as_str() {
+                "PATH" => match container_path(&v) {
+                    p if p.is_empty() => continue,
+                    p => format!("{p}:$PATH"),
+                },
+                _ => escape(&to_container_path(&v)),
+            };
+            lines.push(format!("ENV {k}=\"{v}\""));
+        }
+
+        let dockerfile = lines.join("\n").trim_end().to_string() + "\n";
+        match &self.output {
+            Some(path) => file::write(path, dockerfile)?,
+            None => rtxprint!(out, "{dockerfile}"),
+        }
+        Ok(())
+    }
+}
+
+/// PATH entries rtx adds, excluding the ones already on the host's PATH
+fn container_path(path: &str) -> String {
+    split_paths(path)
+        .filter(|p| !env::PATH.contains(p))
+        .map(|p| escape(&to_container_path(&p.to_string_lossy())))
+        .unique()
+        .join(":")
+}
+
+/// env var names containing any of these are treated as secrets
+const SECRET_KEY_PARTS: [&str; 8] = [
+    "SECRET",
+    "TOKEN",
+    "PASSWORD",
+    "PASSWD",
+    "CREDENTIAL",
+    "PRIVATE",
+    "API_KEY",
+    "AUTH",
+];
+
+fn looks_secret(key: &str) -> bool {
+    let key = key.to_uppercase();
+    SECRET_KEY_PARTS.iter().any(|s| key.contains(s))
+}
+
+fn to_container_path(s: &str) -> String {
+    s.replace(dirs::DATA.to_string_lossy().as_ref(), CONTAINER_DATA_DIR)
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('$', "\\$")
+}
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  $ <bold>rtx generate dockerfile > Dockerfile</bold>
+  $ <bold>rtx generate dockerfile --base-image debian:12 -o Dockerfile</bold>
+"#
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_cli, assert_cli_snapshot, dirs, file};
+
+    #[test]
+    fn test_generate_dockerfile() {
+        assert_cli_snapshot!("generate", "dockerfile");
+    }
+
+    #[test]
+    fn test_generate_dockerfile_secrets() {
+        let cf_path = dirs::CURRENT.join(".test.rtx.toml");
+        file::write(
+            &cf_path,
+            indoc! {r#"
+            [env]
+            GITHUB_TOKEN = "ghp_abc"
+            DB_URL = "postgres://{{ env.HOME }}"
+            GREETING = "hello"
+            "#},
+        )
+        .unwrap();
+        let output = assert_cli!("generate", "dockerfile");
+        file::remove_file(&cf_path).unwrap();
+
+        assert!(output.contains("ARG GITHUB_TOKEN\n"));
+        assert!(output.contains("ARG DB_URL\n"));
+        assert!(output.contains("ENV GREETING=\"hello\"\n"));
+        assert!(!output.contains("ghp_abc"));
+        assert!(!output.contains("postgres://"));
+    }
+}
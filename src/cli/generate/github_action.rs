@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+use clap::ValueHint;
+use color_eyre::eyre::Result;
+
+use crate::config::Config;
+use crate::file;
+use crate::output::Output;
+
+/// Generate a GitHub Actions workflow that installs and caches the toolset
+///
+/// The installs directory is cached with a key from `rtx toolset-hash` so the
+/// cache is reused until a tool version changes.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
+pub struct GenerateGithubAction {
+    /// Write the workflow to this path instead of stdout
+    #[clap(short, long, value_hint = ValueHint::FilePath)]
+    output: Option<PathBuf>,
+}
+
+impl GenerateGithubAction {
+    pub fn run(self, _config: Config, out: &mut Output) -> Result<()> {
+        match &self.output {
+            Some(path) => file::write(path, WORKFLOW)?,
+            None => rtxprint!(out, "{WORKFLOW}"),
+        }
+        Ok(())
+    }
+}
+
+const WORKFLOW: &str = indoc! {r#"
+    # generated by `rtx generate github-action`
+    name: ci
+    on:
+      push:
+      pull_request:
+    jobs:
+      build:
+        runs-on: ubuntu-latest
+        steps:
+          - uses: actions/checkout@v4
+          - name: Install rtx
+            run: |
+              curl -fsSL https://rtx.pub/install.sh | sh
+              echo "$HOME/.local/share/rtx/bin" >> "$GITHUB_PATH"
+              echo "$HOME/.local/share/rtx/shims" >> "$GITHUB_PATH"
+          - name: Compute toolset hash
+            id: toolset-hash
+            run: echo "hash=$(rtx toolset-hash)" >> "$GITHUB_OUTPUT"
+          - uses: actions/cache@v3
+            with:
+              path: ~/.local/share/rtx/installs
+              key: rtx-installs-${{ steps.toolset-hash.outputs.hash }}
+          - run: rtx install
+"#};
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  $ <bold>rtx generate github-action -o .github/workflows/ci.yml</bold>
+"#
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_cli_snapshot;
+
+    #[test]
+    fn test_generate_github_action() {
+        assert_cli_snapshot!("generate", "github-action");
+    }
+}
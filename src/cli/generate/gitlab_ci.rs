@@ -0,0 +1,84 @@
+use std::path::PathBuf;
+
+use clap::ValueHint;
+use color_eyre::eyre::Result;
+
+use crate::config::Config;
+use crate::file;
+use crate::output::Output;
+
+/// Generate GitLab CI config that installs and caches the toolset
+///
+/// A first job computes `rtx toolset-hash` and passes it on as a dotenv
+/// artifact, jobs extending `.rtx` then use it as the key for caching the
+/// installs directory.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
+pub struct GenerateGitlabCi {
+    /// Write the config to this path instead of stdout
+    #[clap(short, long, value_hint = ValueHint::FilePath)]
+    output: Option<PathBuf>,
+}
+
+impl GenerateGitlabCi {
+    pub fn run(self, _config: Config, out: &mut Output) -> Result<()> {
+        match &self.output {
+            Some(path) => file::write(path, CONFIG)?,
+            None => rtxprint!(out, "{CONFIG}"),
+        }
+        Ok(())
+    }
+}
+
+const CONFIG: &str = indoc! {r#"
+    # generated by `rtx generate gitlab-ci`
+    variables:
+      # gitlab can only cache paths inside of the project
+      RTX_DATA_DIR: $CI_PROJECT_DIR/.rtx
+
+    stages:
+      - prepare
+      - build
+
+    rtx-toolset-hash:
+      stage: prepare
+      script:
+        - curl -fsSL https://rtx.pub/install.sh | sh
+        - echo "RTX_TOOLSET_HASH=$($RTX_DATA_DIR/bin/rtx toolset-hash)" >> rtx.env
+      artifacts:
+        reports:
+          dotenv: rtx.env
+
+    .rtx:
+      needs: [rtx-toolset-hash]
+      cache:
+        key: rtx-installs-$RTX_TOOLSET_HASH
+        paths:
+          - .rtx/installs
+      before_script:
+        - curl -fsSL https://rtx.pub/install.sh | sh
+        - export PATH="$RTX_DATA_DIR/bin:$RTX_DATA_DIR/shims:$PATH"
+        - rtx install
+
+    build:
+      stage: build
+      extends: .rtx
+      script:
+        - rtx current
+"#};
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  $ <bold>rtx generate gitlab-ci -o .gitlab-ci.yml</bold>
+"#
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_cli_snapshot;
+
+    #[test]
+    fn test_generate_gitlab_ci() {
+        assert_cli_snapshot!("generate", "gitlab-ci");
+    }
+}
@@ -0,0 +1,40 @@
+use clap::Subcommand;
+use color_eyre::eyre::Result;
+
+use crate::config::Config;
+use crate::output::Output;
+
+mod dockerfile;
+mod github_action;
+mod gitlab_ci;
+
+/// Generate files from the current toolset
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment)]
+pub struct Generate {
+    #[clap(subcommand)]
+    command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    Dockerfile(dockerfile::GenerateDockerfile),
+    GithubAction(github_action::GenerateGithubAction),
+    GitlabCi(gitlab_ci::GenerateGitlabCi),
+}
+
+impl Commands {
+    pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        match self {
+            Self::Dockerfile(cmd) => cmd.run(config, out),
+            Self::GithubAction(cmd) => cmd.run(config, out),
+            Self::GitlabCi(cmd) => cmd.run(config, out),
+        }
+    }
+}
+
+impl Generate {
+    pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        self.command.run(config, out)
+    }
+}
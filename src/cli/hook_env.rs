@@ -16,7 +16,7 @@ use crate::env_diff::{EnvDiff, EnvDiffOperation};
 use crate::output::Output;
 use crate::shell::{get_shell, ShellType};
 use crate::toolset::{Toolset, ToolsetBuilder};
-use crate::{env, hook_env};
+use crate::{env, hook_env, shims};
 
 /// [internal] called by activate hook to update env vars directory change
 #[derive(Debug, clap::Args)]
@@ -33,7 +33,17 @@ pub struct HookEnv {
 
 impl HookEnv {
     pub fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
-        let ts = ToolsetBuilder::new().build(&mut config)?;
+        let mut ts = ToolsetBuilder::new().build(&mut config)?;
+        // only warn/prompt/install when entering a directory or editing config, not every prompt
+        if hook_env::have_config_files_changed(&config_watch_files(&config)) {
+            let missing = ts
+                .list_missing_versions(&config)
+                .into_iter()
+                .cloned()
+                .collect();
+            ts.install_missing_versions(&mut config, missing)?;
+        }
+        shims::reshim_if_bin_paths_changed(&config, &ts)?;
         let shell = get_shell(self.shell).expect("no shell provided, use `--shell=zsh`");
         out.stdout.write(hook_env::clear_old_env(&*shell));
         let mut env = ts.env(&config);
@@ -50,7 +60,7 @@ impl HookEnv {
 
         patches.extend(self.build_path_operations(&paths, &__RTX_DIFF.path)?);
         patches.push(self.build_diff_operation(&diff)?);
-        patches.push(self.build_watch_operation(&config)?);
+        patches.push(self.build_watch_operation(&config, &paths)?);
 
         let output = hook_env::build_env_commands(&*shell, &patches);
         out.stdout.write(output);
@@ -141,13 +151,13 @@ impl HookEnv {
         ))
     }
 
-    fn build_watch_operation(&self, config: &Config) -> Result<EnvDiffOperation> {
-        let watch_files: Vec<_> = config
-            .config_files
-            .values()
-            .flat_map(|p| p.watch_files())
-            .collect();
-        let watches = hook_env::build_watches(&watch_files)?;
+    fn build_watch_operation(
+        &self,
+        config: &Config,
+        paths: &[PathBuf],
+    ) -> Result<EnvDiffOperation> {
+        // bin paths are watched so hook-env can reshim when something like `go install` adds a bin
+        let watches = hook_env::build_watches(&config_watch_files(config), paths)?;
         Ok(EnvDiffOperation::Add(
             "__RTX_WATCH".into(),
             hook_env::serialize_watches(&watches)?,
@@ -155,6 +165,14 @@ impl HookEnv {
     }
 }
 
+fn config_watch_files(config: &Config) -> Vec<PathBuf> {
+    config
+        .config_files
+        .values()
+        .flat_map(|p| p.watch_files())
+        .collect()
+}
+
 fn patch_to_status(patch: EnvDiffOperation) -> String {
     match patch {
         EnvDiffOperation::Add(k, _) => format!("+{}", k),
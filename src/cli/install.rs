@@ -1,4 +1,5 @@
 use color_eyre::eyre::Result;
+use itertools::Itertools;
 
 use crate::cli::args::tool::{ToolArg, ToolArgParser};
 use crate::config::Config;
@@ -16,6 +17,9 @@ use crate::ui::multi_progress_report::MultiProgressReport;
 /// Or you can call a tool version explicitly with `rtx exec <TOOL>@<VERSION> -- <COMMAND>`.
 ///
 /// Tools will be installed in parallel. To disable, set `--jobs=1` or `RTX_JOBS=1`
+///
+/// In a monorepo, `--recursive` installs the tools of every config file under the project root
+/// in a single run.
 #[derive(Debug, clap::Args)]
 #[clap(visible_alias = "i", verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
 pub struct Install {
@@ -28,6 +32,11 @@ pub struct Install {
     #[clap(long, short, requires = "tool")]
     force: bool,
 
+    /// Install the tools of every config file under the project root
+    /// Directories ignored by .gitignore are skipped
+    #[clap(long, conflicts_with = "tool", verbatim_doc_comment)]
+    recursive: bool,
+
     /// Show installation output
     #[clap(long, short, action = clap::ArgAction::Count)]
     verbose: u8,
@@ -37,6 +46,7 @@ impl Install {
     pub fn run(self, config: Config, _out: &mut Output) -> Result<()> {
         match &self.tool {
             Some(runtime) => self.install_runtimes(config, runtime)?,
+            None if self.recursive => self.install_recursive(config)?,
             None => self.install_missing_runtimes(config)?,
         }
 
@@ -119,6 +129,31 @@ impl Install {
         ts.install_versions(&mut config, versions, &mpr, self.force)?;
         Ok(())
     }
+
+    fn install_recursive(&self, mut config: Config) -> Result<()> {
+        let versions = config
+            .get_project_versions()?
+            .into_iter()
+            .map(|(_, tv)| tv)
+            .filter(|tv| {
+                !config
+                    .get_or_create_plugin(&tv.plugin_name)
+                    .is_version_installed(tv)
+            })
+            .unique_by(|tv| tv.to_string())
+            // versions of the same plugin must be adjacent to be installed in order
+            .sorted_by_key(|tv| tv.plugin_name.clone())
+            .collect_vec();
+        if versions.is_empty() {
+            info!("all runtimes are installed");
+            return Ok(());
+        }
+        let mut ts = ToolsetBuilder::new()
+            .with_latest_versions()
+            .build(&mut config)?;
+        let mpr = MultiProgressReport::new(config.show_progress_bars());
+        ts.install_versions(&mut config, versions, &mpr, self.force)
+    }
 }
 
 static AFTER_LONG_HELP: &str = color_print::cstr!(
@@ -127,6 +162,7 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   $ <bold>rtx install node@20</bold>      # install fuzzy node version
   $ <bold>rtx install node</bold>         # install version specified in .tool-versions or .rtx.toml
   $ <bold>rtx install</bold>                # installs everything specified in .tool-versions or .rtx.toml
+  $ <bold>rtx install --recursive</bold>    # installs everything specified in any config file of a monorepo
 "#
 );
 
@@ -134,7 +170,7 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
 mod tests {
     use pretty_assertions::assert_str_eq;
 
-    use crate::{assert_cli, assert_cli_snapshot, dirs};
+    use crate::{assert_cli, assert_cli_snapshot, dirs, file};
 
     #[test]
     fn test_install_force() {
@@ -164,6 +200,27 @@ mod tests {
         assert_cli!("global", "--unset", "dummy");
     }
 
+    #[test]
+    fn test_install_recursive() {
+        let nested = dirs::CURRENT.join("nested");
+        let ignored = dirs::CURRENT.join("ignored");
+        let gitignore = dirs::CURRENT.join(".gitignore");
+        file::create_dir_all(&nested).unwrap();
+        file::create_dir_all(&ignored).unwrap();
+        file::write(nested.join(".test-tool-versions"), "tiny 1.0.0\n").unwrap();
+        file::write(ignored.join(".test-tool-versions"), "tiny 1.1.0\n").unwrap();
+        file::write(&gitignore, "/ignored/\n").unwrap();
+
+        assert_cli!("install", "--recursive");
+        assert!(dirs::INSTALLS.join("tiny/1.0.0").exists());
+        assert!(!dirs::INSTALLS.join("tiny/1.1.0").exists());
+
+        assert_cli!("uninstall", "tiny@1.0.0");
+        file::remove_all(&nested).unwrap();
+        file::remove_all(&ignored).unwrap();
+        file::remove_file(&gitignore).unwrap();
+    }
+
     #[test]
     fn test_install_nothing() {
         // this doesn't do anything since dummy isn't specified
@@ -1,6 +1,6 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use color_eyre::eyre::Result;
@@ -12,7 +12,9 @@ use serde_derive::Serialize;
 use versions::Versioning;
 
 use crate::config::Config;
+use crate::dirs;
 use crate::errors::Error::PluginNotInstalled;
+use crate::file::display_path;
 use crate::output::Output;
 use crate::plugins::{unalias_plugin, Plugin, PluginName};
 use crate::toolset::{ToolSource, ToolVersion, ToolsetBuilder};
@@ -56,6 +58,17 @@ pub struct Ls {
     /// Display versions matching this prefix
     #[clap(long, requires = "plugin")]
     prefix: Option<String>,
+
+    /// Show the tracked config files that use each version
+    /// See also `rtx where-used`
+    #[clap(long, short, verbatim_doc_comment)]
+    usage: bool,
+
+    /// Show the versions of every config file under the project root
+    /// as a matrix of directories and tools
+    /// Directories ignored by .gitignore are skipped
+    #[clap(long, conflicts_with_all = ["current", "global", "parseable", "prefix", "usage"], verbatim_doc_comment)]
+    recursive: bool,
 }
 
 impl Ls {
@@ -66,6 +79,9 @@ impl Ls {
             .or(self.plugin_flag.clone())
             .map(|p| PluginName::from(unalias_plugin(&p)));
         self.verify_plugin(&config)?;
+        if self.recursive {
+            return self.display_recursive(&mut config, out);
+        }
 
         let mut runtimes = self.get_runtime_list(&mut config)?;
         if self.current || self.global {
@@ -82,12 +98,16 @@ impl Ls {
         if let Some(prefix) = &self.prefix {
             runtimes.retain(|(_, tv, _)| tv.version.starts_with(prefix));
         }
+        let usage = match self.usage {
+            true => Some(get_usage(&mut config)?),
+            false => None,
+        };
         if self.json {
-            self.display_json(runtimes, out)
+            self.display_json(runtimes, usage, out)
         } else if self.parseable {
             self.display_parseable(runtimes, out)
         } else {
-            self.display_user(&config, runtimes, out)
+            self.display_user(&config, runtimes, usage, out)
         }
     }
 
@@ -104,13 +124,25 @@ impl Ls {
         Ok(())
     }
 
-    fn display_json(&self, runtimes: Vec<RuntimeRow>, out: &mut Output) -> Result<()> {
+    fn display_json(
+        &self,
+        runtimes: Vec<RuntimeRow>,
+        usage: Option<Usage>,
+        out: &mut Output,
+    ) -> Result<()> {
+        let to_json = |row: RuntimeRow| {
+            let used_by = usage.as_ref().map(|usage| row_usage(usage, &row.1));
+            JSONToolVersion {
+                used_by,
+                ..row.into()
+            }
+        };
         if let Some(plugin) = &self.plugin {
             // only runtimes for 1 plugin
             let runtimes: Vec<JSONToolVersion> = runtimes
                 .into_iter()
                 .filter(|(p, _, _)| plugin.eq(&p.name()))
-                .map(|row| row.into())
+                .map(to_json)
                 .collect();
             out.stdout.writeln(serde_json::to_string_pretty(&runtimes)?);
             return Ok(());
@@ -121,7 +153,7 @@ impl Ls {
             .into_iter()
             .group_by(|(p, _, _)| p.name().to_string())
         {
-            let runtimes = runtimes.map(|row| row.into()).collect();
+            let runtimes = runtimes.map(to_json).collect();
             plugins.insert(plugin_name.clone(), runtimes);
         }
         out.stdout.writeln(serde_json::to_string_pretty(&plugins)?);
@@ -150,11 +182,13 @@ impl Ls {
         &self,
         config: &Config,
         runtimes: Vec<RuntimeRow>,
+        usage: Option<Usage>,
         out: &mut Output,
     ) -> Result<()> {
         let output = runtimes
             .into_iter()
             .map(|(p, tv, source)| {
+                let used_by = usage.as_ref().map(|usage| row_usage(usage, &tv));
                 let plugin = p.name().to_string();
                 let version = if let Some(symlink_path) = p.symlink_path(&tv) {
                     VersionStatus::Symlink(tv.version, symlink_path, source.is_some())
@@ -169,12 +203,12 @@ impl Ls {
                     VersionStatus::Inactive(tv.version)
                 };
                 let request = source.map(|source| (source.to_string(), tv.request.version()));
-                (plugin, version, request)
+                (plugin, version, request, used_by)
             })
             .collect::<Vec<_>>();
         let (max_plugin_len, max_version_len, max_source_len) = output.iter().fold(
             (0, 0, 0),
-            |(max_plugin, max_version, max_source), (plugin, version, request)| {
+            |(max_plugin, max_version, max_source), (plugin, version, request, _)| {
                 let plugin = max_plugin.max(plugin.len());
                 let version = max_version.max(version.to_plain_string().len());
                 let source = match request {
@@ -184,7 +218,7 @@ impl Ls {
                 (plugin.min(10), version.min(15), source.min(30))
             },
         );
-        for (plugin, version, request) in output {
+        for (plugin, version, request, used_by) in output {
             let pad = |s, len| console::pad_str(s, len, Left, None);
             let plugin_extra =
                 ((plugin.len() as i8 - max_plugin_len as i8).max(0) as usize).min(max_version_len);
@@ -205,6 +239,94 @@ impl Ls {
                 }
             };
             rtxprintln!(out, "{}", line.trim_end());
+            for path in used_by.unwrap_or_default() {
+                rtxprintln!(out, "  {}", style(display_path(&path)).dim());
+            }
+        }
+        Ok(())
+    }
+
+    fn display_recursive(&self, config: &mut Config, out: &mut Output) -> Result<()> {
+        let mut packages: BTreeMap<String, BTreeMap<PluginName, Vec<PackageVersion>>> =
+            BTreeMap::new();
+        for (path, tv) in config.get_project_versions()? {
+            if self.plugin.as_ref().is_some_and(|p| p != &tv.plugin_name) {
+                continue;
+            }
+            let installed = config
+                .get_or_create_plugin(&tv.plugin_name)
+                .is_version_installed(&tv);
+            if (self.installed && !installed) || (self.missing && installed) {
+                continue;
+            }
+            let versions = packages
+                .entry(package_name(&path))
+                .or_default()
+                .entry(tv.plugin_name)
+                .or_default();
+            if !versions.iter().any(|v| v.version == tv.version) {
+                versions.push(PackageVersion {
+                    version: tv.version,
+                    installed,
+                });
+            }
+        }
+        if self.json {
+            rtxprintln!(out, "{}", serde_json::to_string_pretty(&packages)?);
+            return Ok(());
+        }
+
+        let tools = packages
+            .values()
+            .flat_map(|tools| tools.keys())
+            .collect::<BTreeSet<_>>();
+        let mut header = vec!["Package".to_string()];
+        header.extend(tools.iter().map(|t| t.to_string()));
+        let rows = packages
+            .iter()
+            .map(|(package, versions)| {
+                let mut row = vec![(package.clone(), package.clone())];
+                for tool in &tools {
+                    let statuses = versions
+                        .get(*tool)
+                        .into_iter()
+                        .flatten()
+                        .map(|v| match v.installed {
+                            true => VersionStatus::Active(v.version.clone(), false),
+                            false => VersionStatus::Missing(v.version.clone()),
+                        })
+                        .collect_vec();
+                    row.push(match statuses.is_empty() {
+                        true => ("-".into(), style("-").dim().to_string()),
+                        false => (
+                            statuses.iter().map(|s| s.to_plain_string()).join(", "),
+                            statuses.iter().join(", "),
+                        ),
+                    });
+                }
+                row
+            })
+            .collect_vec();
+        let mut widths = header.iter().map(|h| h.len()).collect_vec();
+        for row in &rows {
+            for (w, (plain, _)) in widths.iter_mut().zip(row) {
+                *w = (*w).max(plain.len());
+            }
+        }
+        let pad = |s: &str, width| console::pad_str(s, width, Left, None).to_string();
+        let header = header
+            .iter()
+            .zip(&widths)
+            .map(|(h, w)| pad(h, *w))
+            .join("  ");
+        rtxprintln!(out, "{}", style(header.trim_end()).dim());
+        for row in rows {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|((_, styled), w)| pad(styled, *w))
+                .join("  ");
+            rtxprintln!(out, "{}", line.trim_end());
         }
         Ok(())
     }
@@ -251,6 +373,47 @@ impl Ls {
     }
 }
 
+/// tracked config files using each version
+type Usage = HashMap<(PluginName, String), Vec<PathBuf>>;
+
+fn get_usage(config: &mut Config) -> Result<Usage> {
+    let mut usage = Usage::new();
+    for (path, tv) in config.get_tracked_versions()? {
+        usage
+            .entry((tv.plugin_name, tv.version))
+            .or_default()
+            .push(path);
+    }
+    for paths in usage.values_mut() {
+        paths.sort();
+        paths.dedup();
+    }
+    Ok(usage)
+}
+
+fn row_usage(usage: &Usage, tv: &ToolVersion) -> Vec<PathBuf> {
+    usage
+        .get(&(tv.plugin_name.clone(), tv.version.clone()))
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// a directory under the project root, relative to the current directory
+fn package_name(config_path: &Path) -> String {
+    let dir = config_path.parent().unwrap_or(config_path);
+    match dir.strip_prefix(&*dirs::CURRENT) {
+        Ok(p) if p.as_os_str().is_empty() => ".".into(),
+        Ok(p) => p.display().to_string(),
+        Err(_) => display_path(dir),
+    }
+}
+
+#[derive(Serialize)]
+struct PackageVersion {
+    version: String,
+    installed: bool,
+}
+
 type JSONOutput = IndexMap<PluginName, Vec<JSONToolVersion>>;
 
 #[derive(Serialize)]
@@ -263,6 +426,8 @@ struct JSONToolVersion {
     source: Option<IndexMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     symlinked_to: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    used_by: Option<Vec<PathBuf>>,
 }
 
 type RuntimeRow = (Arc<dyn Plugin>, ToolVersion, Option<ToolSource>);
@@ -276,6 +441,7 @@ impl From<RuntimeRow> for JSONToolVersion {
             version: tv.version,
             requested_version: source.as_ref().map(|_| tv.request.version()),
             source: source.map(|source| source.as_json()),
+            used_by: None,
         }
     }
 }
@@ -357,6 +523,20 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   node    20.0.0 ~/src/myapp/.tool-versions 20
   python  3.11.0 ~/.tool-versions           3.11.0
 
+  $ <bold>rtx ls --usage node</bold>
+  node    20.0.0 ~/src/myapp/.tool-versions 20
+    ~/src/myapp/.tool-versions
+    ~/src/other/.rtx.toml
+  node    18.0.0
+    ~/src/legacy/.rtx.toml
+
+  # every package of a monorepo
+  $ <bold>rtx ls --recursive</bold>
+  Package        node     python
+  .              20.0.0   3.11.0
+  packages/api   18.19.0  -
+  packages/web   20.0.0   -
+
   $ <bold>rtx ls --json</bold>
   {
     "node": [
@@ -405,6 +585,24 @@ mod tests {
         assert_cli_snapshot!("ls", "-c");
     }
 
+    #[test]
+    fn test_ls_usage() {
+        assert_cli!("install");
+        let stdout = assert_cli!("ls", "--usage", "tiny");
+        assert!(stdout.contains("~/cwd/.test-tool-versions"));
+    }
+
+    #[test]
+    fn test_ls_recursive() {
+        assert_cli!("install");
+        let stdout = assert_cli!("ls", "--recursive");
+        assert!(stdout.contains("Package"));
+        assert!(stdout.contains("3.1.0"));
+        let stdout = assert_cli!("ls", "--recursive", "--json", "tiny");
+        let packages: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        assert_eq!(packages["."]["tiny"][0]["version"], "3.1.0");
+    }
+
     #[test]
     fn test_ls_json() {
         let _ = remove_all(dirs::INSTALLS.as_path());
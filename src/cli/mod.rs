@@ -10,16 +10,19 @@ mod alias;
 pub mod args;
 mod asdf;
 mod bin_paths;
+mod bundle;
 mod cache;
 mod completion;
 mod current;
 mod deactivate;
 mod direnv;
 mod doctor;
+mod du;
 mod env;
 mod env_vars;
 pub mod exec;
 mod external;
+mod generate;
 mod global;
 mod hook_env;
 mod implode;
@@ -42,13 +45,16 @@ mod reshim;
 mod self_update;
 mod settings;
 mod shell;
+mod shim_cache;
 mod sync;
+mod toolset_hash;
 mod trust;
 mod uninstall;
 mod upgrade;
 mod r#use;
 pub mod version;
 mod r#where;
+mod where_used;
 mod r#which;
 
 pub struct Cli {
@@ -62,15 +68,18 @@ pub enum Commands {
     Alias(alias::Alias),
     Asdf(asdf::Asdf),
     BinPaths(bin_paths::BinPaths),
+    Bundle(bundle::Bundle),
     Cache(cache::Cache),
     Completion(completion::Completion),
     Current(current::Current),
     Deactivate(deactivate::Deactivate),
     Direnv(direnv::Direnv),
     Doctor(doctor::Doctor),
+    Du(du::Du),
     Env(env::Env),
     EnvVars(env_vars::EnvVars),
     Exec(exec::Exec),
+    Generate(generate::Generate),
     Global(global::Global),
     HookEnv(hook_env::HookEnv),
     Implode(implode::Implode),
@@ -86,13 +95,16 @@ pub enum Commands {
     Reshim(reshim::Reshim),
     Settings(settings::Settings),
     Shell(shell::Shell),
+    ShimCache(shim_cache::ShimCache),
     Sync(sync::Sync),
+    ToolsetHash(toolset_hash::ToolsetHash),
     Trust(trust::Trust),
     Uninstall(uninstall::Uninstall),
     Upgrade(upgrade::Upgrade),
     Use(r#use::Use),
     Version(version::Version),
     Where(r#where::Where),
+    WhereUsed(where_used::WhereUsed),
     Which(which::Which),
 
     #[cfg(feature = "clap_complete")]
@@ -112,15 +124,18 @@ impl Commands {
             Self::Alias(cmd) => cmd.run(config, out),
             Self::Asdf(cmd) => cmd.run(config, out),
             Self::BinPaths(cmd) => cmd.run(config, out),
+            Self::Bundle(cmd) => cmd.run(config, out),
             Self::Cache(cmd) => cmd.run(config, out),
             Self::Completion(cmd) => cmd.run(config, out),
             Self::Current(cmd) => cmd.run(config, out),
             Self::Deactivate(cmd) => cmd.run(config, out),
             Self::Direnv(cmd) => cmd.run(config, out),
             Self::Doctor(cmd) => cmd.run(config, out),
+            Self::Du(cmd) => cmd.run(config, out),
             Self::Env(cmd) => cmd.run(config, out),
             Self::EnvVars(cmd) => cmd.run(config, out),
             Self::Exec(cmd) => cmd.run(config, out),
+            Self::Generate(cmd) => cmd.run(config, out),
             Self::Global(cmd) => cmd.run(config, out),
             Self::HookEnv(cmd) => cmd.run(config, out),
             Self::Implode(cmd) => cmd.run(config, out),
@@ -136,13 +151,16 @@ impl Commands {
             Self::Reshim(cmd) => cmd.run(config, out),
             Self::Settings(cmd) => cmd.run(config, out),
             Self::Shell(cmd) => cmd.run(config, out),
+            Self::ShimCache(cmd) => cmd.run(config, out),
             Self::Sync(cmd) => cmd.run(config, out),
+            Self::ToolsetHash(cmd) => cmd.run(config, out),
             Self::Trust(cmd) => cmd.run(config, out),
             Self::Uninstall(cmd) => cmd.run(config, out),
             Self::Upgrade(cmd) => cmd.run(config, out),
             Self::Use(cmd) => cmd.run(config, out),
             Self::Version(cmd) => cmd.run(config, out),
             Self::Where(cmd) => cmd.run(config, out),
+            Self::WhereUsed(cmd) => cmd.run(config, out),
             Self::Which(cmd) => cmd.run(config, out),
 
             #[cfg(feature = "clap_complete")]
@@ -131,7 +131,7 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
 mod tests {
     use std::env;
 
-    use crate::assert_cli_snapshot;
+    use crate::{assert_cli, assert_cli_snapshot};
 
     #[test]
     fn test_current() {
@@ -142,4 +142,12 @@ mod tests {
     fn test_current_with_runtimes() {
         assert_cli_snapshot!("outdated", "tiny");
     }
+
+    #[test]
+    fn test_outdated_range() {
+        assert_cli!("install", "tiny@2.1.0");
+        // 3.1.0 is newer but outside of the range
+        let stdout = assert_cli!("outdated", "tiny@~2.1");
+        assert_eq!(stdout, "");
+    }
 }
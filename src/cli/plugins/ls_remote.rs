@@ -20,6 +20,9 @@ pub struct PluginsLsRemote {
     /// by default it will show a "*" next to installed plugins
     #[clap(long)]
     pub only_names: bool,
+
+    /// Only show plugins with this in their name or description
+    pub search: Option<String>,
 }
 
 impl PluginsLsRemote {
@@ -31,26 +34,41 @@ impl PluginsLsRemote {
             .map(|p| p.name())
             .collect::<HashSet<_>>();
 
-        let shorthands = config.get_shorthands().iter().sorted().collect_vec();
-        let max_plugin_len = shorthands
+        let registry = config
+            .get_registry()
+            .iter()
+            .filter(|(name, entry)| match &self.search {
+                Some(search) => entry.matches(name, search),
+                None => true,
+            })
+            .collect_vec();
+        let max_plugin_len = registry
             .iter()
             .map(|(plugin, _)| measure_text_width(plugin))
             .max()
             .unwrap_or(0);
 
-        if shorthands.is_empty() {
+        if config.get_registry().is_empty() {
             warn!("default shorthands are disabled");
         }
 
-        for (plugin, repo) in shorthands {
+        for (plugin, entry) in registry {
             let installed = if !self.only_names && installed_plugins.contains(plugin.as_str()) {
                 "*"
             } else {
                 " "
             };
-            let url = if self.urls { repo } else { "" };
+            let url = if self.urls {
+                entry.url()
+            } else {
+                String::new()
+            };
+            let description = match &entry.description {
+                Some(d) if !self.only_names => format!(" {d}"),
+                _ => String::new(),
+            };
             let plugin = pad_str(plugin, max_plugin_len, Alignment::Left, None);
-            rtxprintln!(out, "{} {}{}", plugin, installed, url);
+            rtxprintln!(out, "{} {}{}{}", plugin, installed, url, description);
         }
 
         Ok(())
@@ -60,10 +78,12 @@ impl PluginsLsRemote {
 const LONG_ABOUT: &str = r#"
 List all available remote plugins
 
-The full list is here: https://github.com/jdx/rtx/blob/main/src/default_shorthands.rs
+The full list is here: https://github.com/jdx/rtx/blob/main/registry.toml
+Plugins from `plugin_registry_url` and `shorthands_file` are included as well.
 
 Examples:
   $ rtx plugins ls-remote
+  $ rtx plugins ls-remote python
 "#;
 
 #[cfg(test)]
@@ -75,4 +95,13 @@ mod tests {
         let stdout = assert_cli!("plugin", "ls-remote");
         assert!(stdout.contains("tiny"));
     }
+
+    #[test]
+    fn test_plugin_list_remote_search() {
+        let stdout = assert_cli!("plugin", "ls-remote", "--urls", "tiny");
+        assert_eq!(
+            stdout.trim(),
+            "tiny *https://github.com/rtx-plugins/rtx-tiny.git A tiny plugin used for testing rtx"
+        );
+    }
 }
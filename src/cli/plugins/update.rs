@@ -1,13 +1,25 @@
 use color_eyre::eyre::{eyre, Result};
 use console::style;
+use itertools::Itertools;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use serde_derive::Serialize;
 
 use crate::config::Config;
+use crate::file::display_path;
+use crate::git::{Git, GitUpdate};
 use crate::output::Output;
 use crate::plugins::{unalias_plugin, PluginName};
 
 /// Updates a plugin to the latest version
 ///
 /// note: this updates the plugin itself, not the runtime versions
+///
+/// Plugins are updated in parallel (see --jobs) and the commits each
+/// plugin moved through are shown afterwards.
+///
+/// Plugins pinned to a ref or sha in `[plugins]` are updated to that ref
+/// unless --unpin is passed, which also removes the pin from the config file.
 #[derive(Debug, clap::Args)]
 #[clap(verbatim_doc_comment, alias = "upgrade", after_long_help = AFTER_LONG_HELP)]
 pub struct Update {
@@ -18,13 +30,26 @@ pub struct Update {
     /// Update all plugins
     #[clap(long, short = 'a', conflicts_with = "plugin", hide = true)]
     all: bool,
+
+    /// Update to the latest commit on the default branch even if the plugin is pinned
+    /// and remove the pin from `[plugins]`
+    #[clap(long)]
+    unpin: bool,
+
+    /// Only fetch and show what would change
+    #[clap(long, short = 'n')]
+    dry_run: bool,
+
+    /// Output in json format
+    #[clap(long, short = 'J')]
+    json: bool,
 }
 
 impl Update {
-    pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
-        let plugins: Vec<_> = match self.plugin {
+    pub fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        let plugins: Vec<_> = match &self.plugin {
             Some(plugins) => plugins
-                .into_iter()
+                .iter()
                 .map(|p| {
                     let (p, ref_) = match p.split_once('#') {
                         Some((p, ref_)) => (p, Some(ref_.to_string())),
@@ -43,13 +68,116 @@ impl Update {
                 .map(|(_, p)| (p, None))
                 .collect::<Vec<_>>(),
         };
+        let plugins = plugins
+            .into_iter()
+            .map(|(plugin, ref_)| {
+                let ref_ = match ref_ {
+                    Some(ref_) => Some(ref_),
+                    None if self.unpin => None,
+                    None => config
+                        .get_repo_url(&plugin.name().to_string())
+                        .and_then(|url| Git::split_url_and_ref(&url).1),
+                };
+                (plugin, ref_)
+            })
+            .collect_vec();
+
+        let unpinned = plugins
+            .iter()
+            .filter(|(_, ref_)| self.unpin && ref_.is_none())
+            .map(|(plugin, _)| plugin.name().to_string())
+            .collect_vec();
+
+        let results = ThreadPoolBuilder::new()
+            .num_threads(config.settings.jobs)
+            .build()?
+            .install(|| {
+                plugins
+                    .into_par_iter()
+                    .map(|(plugin, ref_)| {
+                        let update = plugin.update(ref_, self.dry_run);
+                        (plugin.name().to_string(), update)
+                    })
+                    .collect::<Vec<_>>()
+            });
+
+        let mut updates = vec![];
+        let mut failed = vec![];
+        for (plugin, update) in results {
+            match update {
+                Ok(Some(update)) => updates.push(PluginUpdate { plugin, update }),
+                Ok(None) => {}
+                Err(err) => {
+                    warn!("failed to update plugin {plugin}: {err:#}");
+                    failed.push(plugin);
+                }
+            }
+        }
 
-        for (plugin, ref_) in plugins {
-            rtxprintln!(out, "updating plugin {plugin}");
-            plugin.update(ref_)?;
+        if self.json {
+            rtxprintln!(out, "{}", serde_json::to_string_pretty(&updates)?);
+        } else {
+            for PluginUpdate { plugin, update } in &updates {
+                self.display_update(out, plugin, update);
+            }
+        }
+        if !self.dry_run {
+            let unpinned = unpinned.iter().filter(|p| !failed.contains(p));
+            self.remove_pins(&mut config, unpinned)?;
+        }
+        if !failed.is_empty() {
+            return Err(eyre!("failed to update plugins: {}", failed.join(", ")));
         }
         Ok(())
     }
+
+    /// plugins would fail the `[plugins]` pin check after being moved off of it with --unpin
+    fn remove_pins<'a>(
+        &self,
+        config: &mut Config,
+        plugins: impl Iterator<Item = &'a PluginName>,
+    ) -> Result<()> {
+        for plugin in plugins {
+            for cf in config.config_files.values_mut() {
+                if cf.unpin_plugin(plugin) {
+                    cf.save()?;
+                    info!(
+                        "removed the pin for {} from {}",
+                        style(plugin).cyan().for_stderr(),
+                        display_path(cf.get_path())
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn display_update(&self, out: &mut Output, plugin: &str, update: &GitUpdate) {
+        let plugin = style(plugin).cyan();
+        if update.from == update.to {
+            rtxprintln!(out, "{plugin} is up to date");
+            return;
+        }
+        let range = format!("{}..{}", short_sha(&update.from), short_sha(&update.to));
+        match self.dry_run {
+            true => rtxprintln!(out, "{plugin} can be updated {range}"),
+            false => rtxprintln!(out, "{plugin} updated {range}"),
+        }
+        for commit in &update.commits {
+            rtxprintln!(out, "  {} {}", style(&commit.sha).yellow(), commit.subject);
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct PluginUpdate {
+    plugin: String,
+    #[serde(flatten)]
+    update: GitUpdate,
+}
+
+fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(7)]
 }
 
 static AFTER_LONG_HELP: &str = color_print::cstr!(
@@ -57,12 +185,18 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   $ <bold>rtx plugins update</bold>            # update all plugins
   $ <bold>rtx plugins update node</bold>       # update only node
   $ <bold>rtx plugins update node#beta</bold>  # specify a ref
+  $ <bold>rtx plugins update --unpin</bold>    # update past refs pinned in [plugins] and remove them
+  $ <bold>rtx plugins update --dry-run</bold>  # show what would change
 "#
 );
 
 #[cfg(test)]
 mod tests {
-    use crate::assert_cli;
+    use std::path::Path;
+
+    use insta::assert_snapshot;
+
+    use crate::{assert_cli, dirs, file};
 
     #[test]
     fn test_plugin_update() {
@@ -75,4 +209,53 @@ mod tests {
         // assert_cli!("p", "update"); tested in e2e
         assert_cli!("plugins", "update", "tiny");
     }
+
+    #[test]
+    fn test_plugin_update_dry_run_json() {
+        // the test plugins aren't git repos so there is nothing to update
+        let stdout = assert_cli!("plugins", "update", "tiny", "--dry-run", "--json");
+        assert_snapshot!(stdout, @"[]");
+    }
+
+    #[test]
+    fn test_plugin_update_dry_run_git() {
+        let upstream = tempfile::tempdir().unwrap();
+        let upstream = upstream.path();
+        let plugin_path = dirs::PLUGINS.join("tiny-git");
+        let git = |dir: &Path, args: &[&str]| {
+            let mut cmd = vec!["-C", dir.to_str().unwrap()];
+            cmd.extend(["-c", "user.name=rtx", "-c", "user.email=rtx@example.com"]);
+            cmd.extend(args);
+            crate::cmd::cmd("git", cmd).read().unwrap()
+        };
+        file::create_dir_all(upstream.join("bin")).unwrap();
+        file::write(upstream.join("bin/list-all"), "#!/bin/sh\necho 1.0.0\n").unwrap();
+        file::make_executable(&upstream.join("bin/list-all")).unwrap();
+        git(upstream, &["init", "-q"]);
+        git(upstream, &["add", "."]);
+        git(upstream, &["commit", "-qm", "v1"]);
+        git(
+            &dirs::PLUGINS,
+            &["clone", "-q", upstream.to_str().unwrap(), "tiny-git"],
+        );
+        git(upstream, &["commit", "-q", "--allow-empty", "-m", "v2"]);
+        let from = git(&plugin_path, &["rev-parse", "HEAD"]);
+        let to = git(upstream, &["rev-parse", "HEAD"]);
+
+        let stdout = assert_cli!("plugins", "update", "tiny-git", "--dry-run", "--json");
+        let head = git(&plugin_path, &["rev-parse", "HEAD"]);
+        file::remove_all(&plugin_path).unwrap();
+
+        assert_eq!(head, from, "--dry-run moved HEAD");
+        let updates: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        let update = &updates[0];
+        assert_eq!(updates.as_array().unwrap().len(), 1);
+        assert_eq!(update["plugin"], "tiny-git");
+        assert_eq!(update["from"], from);
+        assert_eq!(update["to"], to);
+        let commits = update["commits"].as_array().unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0]["subject"], "v2");
+        assert!(to.starts_with(commits[0]["sha"].as_str().unwrap()));
+    }
 }
@@ -1,15 +1,26 @@
 use std::collections::BTreeMap;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use color_eyre::eyre::Result;
 use console::style;
+use indicatif::HumanBytes;
+use itertools::Itertools;
+use regex::Regex;
 
+use crate::config::tracking::Tracker;
 use crate::config::Config;
+use crate::file::display_path;
 use crate::output::Output;
+use crate::plugins::core::is_plugin_cache_dir;
 use crate::plugins::{Plugin, PluginName};
 use crate::toolset::{ToolVersion, ToolsetBuilder};
 use crate::ui::multi_progress_report::MultiProgressReport;
 use crate::ui::prompt;
+use crate::{dirs, file, last_used};
+
+type PruneMap = BTreeMap<String, (Arc<dyn Plugin>, ToolVersion)>;
 
 /// Delete unused versions of tools
 ///
@@ -17,6 +28,9 @@ use crate::ui::prompt;
 /// Versions which are no longer the latest specified in any of those configs are deleted.
 /// Versions installed only with environment variables (`RTX_<PLUGIN>_VERSION`) will be deleted,
 /// as will versions only referenced on the command line (`rtx exec <PLUGIN>@<VERSION>`).
+///
+/// Downloads and caches left behind by versions that are no longer installed are deleted
+/// along with tracked config files that no longer exist.
 #[derive(Debug, clap::Args)]
 #[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
 pub struct Prune {
@@ -27,38 +41,99 @@ pub struct Prune {
     /// Do not actually delete anything
     #[clap(long, short = 'n')]
     pub dry_run: bool,
+
+    /// Keep the N newest installed versions of each tool
+    #[clap(long, value_name = "N")]
+    pub keep: Option<usize>,
+
+    /// Keep versions used by a shim or `rtx exec` within this duration
+    /// e.g.: 30d, 12h
+    #[clap(long, value_name = "DURATION", value_parser = humantime::parse_duration, verbatim_doc_comment)]
+    pub used_within: Option<Duration>,
+
+    /// Keep versions matching this glob
+    /// e.g.: node@18.*, python
+    #[clap(long, value_name = "GLOB", verbatim_doc_comment)]
+    pub exclude: Vec<String>,
 }
 
 impl Prune {
-    pub fn run(self, mut config: Config, _out: &mut Output) -> Result<()> {
-        let ts = ToolsetBuilder::new().build(&mut config)?;
-        let mut to_delete = ts
-            .list_installed_versions(&config)?
-            .into_iter()
-            .map(|(p, tv)| (tv.to_string(), (p, tv)))
-            .collect::<BTreeMap<String, (Arc<dyn Plugin>, ToolVersion)>>();
+    pub fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        self.forget_missing_configs(out)?;
+        let to_delete = self.to_delete(&mut config)?;
+
+        let mut reclaimed = self.delete(&config, to_delete.into_values().collect())?;
+        reclaimed += self.delete_orphans(&config, out)?;
+        if reclaimed > 0 {
+            match self.dry_run {
+                true => rtxstatusln!(out, "would reclaim {}", HumanBytes(reclaimed)),
+                false => rtxstatusln!(out, "reclaimed {}", HumanBytes(reclaimed)),
+            }
+        }
+        Ok(())
+    }
+
+    fn forget_missing_configs(&self, out: &mut Output) -> Result<()> {
+        let tracker = Tracker::new();
+        if self.dry_run {
+            for path in tracker.list_missing()? {
+                rtxstatusln!(out, "would forget tracked config {}", display_path(&path));
+            }
+        } else {
+            for path in tracker.clean()? {
+                rtxstatusln!(out, "forgot tracked config {}", display_path(&path));
+            }
+        }
+        Ok(())
+    }
+
+    /// installed versions that aren't used by a tracked config or kept by `--keep`,
+    /// `--used-within`, or `--exclude`, keyed by `<plugin>@<version>`
+    fn to_delete(&self, config: &mut Config) -> Result<PruneMap> {
+        let ts = ToolsetBuilder::new().build(config)?;
+        let installed = ts.list_installed_versions(config)?;
+        let mut to_delete = installed
+            .iter()
+            .map(|(p, tv)| (tv.to_string(), (p.clone(), tv.clone())))
+            .collect::<PruneMap>();
 
         if let Some(plugins) = &self.plugin {
             to_delete.retain(|_, (_, tv)| plugins.contains(&tv.plugin_name));
         }
 
-        for cf in config.get_tracked_config_files()?.values() {
-            let mut ts = cf.to_toolset().clone();
-            ts.resolve(&mut config);
-            for (_, tv) in ts.list_current_versions(&config) {
-                to_delete.remove(&tv.to_string());
-            }
+        for (_, tv) in config.get_tracked_versions()? {
+            to_delete.remove(&tv.to_string());
         }
 
-        self.delete(&config, to_delete.into_values().collect())
+        if let Some(keep) = self.keep {
+            // installed versions are sorted oldest first
+            let by_plugin = installed
+                .iter()
+                .into_group_map_by(|(p, _)| p.name().to_string());
+            for tvs in by_plugin.values() {
+                for (_, tv) in tvs.iter().rev().take(keep) {
+                    to_delete.remove(&tv.to_string());
+                }
+            }
+        }
+        if let Some(used_within) = self.used_within {
+            to_delete.retain(|_, (_, tv)| match last_used::get(tv) {
+                Some(last_used) => last_used.elapsed().unwrap_or_default() > used_within,
+                None => true,
+            });
+        }
+        to_delete.retain(|_, (_, tv)| !self.is_excluded(tv));
+        Ok(to_delete)
     }
 
+    /// deletes the versions, returns the number of bytes deleted
     fn delete(
         &self,
         config: &Config,
         to_delete: Vec<(Arc<dyn Plugin>, ToolVersion)>,
-    ) -> Result<()> {
+    ) -> Result<u64> {
         let mpr = MultiProgressReport::new(config.show_progress_bars());
+        let mut reclaimed = 0;
         for (p, tv) in to_delete {
             let mut pr = mpr.add();
             if self.dry_run {
@@ -66,32 +141,201 @@ impl Prune {
             }
             if self.dry_run || config.settings.yes || prompt::confirm(&format!("remove {} ?", &tv))?
             {
+                reclaimed += [tv.install_path(), tv.download_path(), tv.cache_path()]
+                    .iter()
+                    .map(|dir| file::dir_size(dir).unwrap_or_default())
+                    .sum::<u64>();
                 p.decorate_progress_bar(&mut pr, Some(&tv));
                 p.uninstall_version(config, &tv, &pr, self.dry_run)?;
                 pr.finish();
             }
         }
-        Ok(())
+        Ok(reclaimed)
+    }
+
+    /// deletes downloads and caches of versions that are no longer installed,
+    /// returns the number of bytes deleted
+    fn delete_orphans(&self, config: &Config, out: &mut Output) -> Result<u64> {
+        let mut reclaimed = 0;
+        for dir in orphans(config, self.plugin.as_deref())? {
+            reclaimed += file::dir_size(&dir)?;
+            match self.dry_run {
+                true => rtxstatusln!(out, "would remove {}", display_path(&dir)),
+                false => {
+                    rtxstatusln!(out, "removing {}", display_path(&dir));
+                    file::remove_all(&dir)?;
+                }
+            }
+        }
+        Ok(reclaimed)
+    }
+
+    fn is_excluded(&self, tv: &ToolVersion) -> bool {
+        self.exclude.iter().any(|glob| {
+            let target = match glob.contains('@') {
+                true => format!("{}@{}", tv.plugin_name, tv.version),
+                false => tv.plugin_name.clone(),
+            };
+            glob_match(glob, &target)
+        })
     }
 }
 
+/// download and cache dirs without a matching install,
+/// downloads of plugins that are no longer installed are included
+/// dirs plugins keep in their cache for themselves are never orphans
+fn orphans(config: &Config, only: Option<&[PluginName]>) -> Result<Vec<PathBuf>> {
+    let included = |plugin: &PluginName| only.map_or(true, |only| only.contains(plugin));
+    let mut orphans = vec![];
+    for plugin in file::dir_subdirs(&dirs::DOWNLOADS)? {
+        if !config.plugins.contains_key(&plugin) && included(&plugin) {
+            orphans.push(dirs::DOWNLOADS.join(plugin));
+        }
+    }
+    for plugin in config.plugins.keys().filter(|p| included(p)) {
+        let installs = dirs::INSTALLS.join(plugin);
+        for dir in [dirs::DOWNLOADS.join(plugin), dirs::CACHE.join(plugin)] {
+            for version in file::dir_subdirs(&dir)? {
+                if !installs.join(&version).exists() && !is_plugin_cache_dir(plugin, &version) {
+                    orphans.push(dir.join(version));
+                }
+            }
+        }
+    }
+    orphans.sort();
+    Ok(orphans)
+}
+
+/// matches `*` and `?` wildcards
+fn glob_match(glob: &str, s: &str) -> bool {
+    let re = regex::escape(glob).replace(r"\*", ".*").replace(r"\?", ".");
+    Regex::new(&format!("^{re}$")).is_ok_and(|re| re.is_match(s))
+}
+
 static AFTER_LONG_HELP: &str = color_print::cstr!(
     r#"<bold><underline>Examples:</underline></bold>
   $ <bold>rtx prune --dry-run</bold>
   rm -rf ~/.local/share/rtx/versions/node/20.0.0
   rm -rf ~/.local/share/rtx/versions/node/20.0.1
+
+  # keeps the 2 newest versions of each tool and anything used in the last month
+  $ <bold>rtx prune --keep 2 --used-within 30d</bold>
+
+  # never prunes node 18 versions
+  $ <bold>rtx prune --exclude 'node@18.*'</bold>
 "#
 );
 
 #[cfg(test)]
 mod tests {
-    use crate::assert_cli;
+    use std::time::{Duration, SystemTime};
+
+    use filetime::{set_file_mtime, FileTime};
+    use itertools::Itertools;
+
+    use crate::config::Config;
+    use crate::{assert_cli, dirs, file, last_used};
+
+    use super::*;
 
     #[test]
     fn test_prune() {
         assert_cli!("prune", "--dry-run");
+        assert_cli!(
+            "prune",
+            "--dry-run",
+            "--keep=1",
+            "--used-within=1d",
+            "--exclude=tiny@1.*"
+        );
         assert_cli!("prune", "tiny");
         assert_cli!("prune");
         assert_cli!("install");
     }
+
+    #[test]
+    fn test_prune_to_delete() {
+        assert_cli!("install", "dummy@100.0.0", "dummy@100.1.0");
+        let mut config = Config::load().unwrap();
+        let prune = || Prune {
+            plugin: None,
+            dry_run: true,
+            keep: None,
+            used_within: None,
+            exclude: vec![],
+        };
+        let tvs = prune().to_delete(&mut config).unwrap();
+        let (_, old) = &tvs["dummy@100.0.0"];
+        let (_, new) = &tvs["dummy@100.1.0"];
+        // only looks at the versions installed by this test
+        let mut to_delete = |prune: Prune| {
+            prune
+                .to_delete(&mut config)
+                .unwrap()
+                .into_keys()
+                .filter(|k| k.starts_with("dummy@100."))
+                .collect_vec()
+        };
+        assert_eq!(to_delete(prune()), vec!["dummy@100.0.0", "dummy@100.1.0"]);
+        let only_tiny = Prune {
+            plugin: Some(vec!["tiny".into()]),
+            ..prune()
+        };
+        assert!(to_delete(only_tiny).is_empty());
+
+        let keep = Prune {
+            keep: Some(1),
+            ..prune()
+        };
+        assert_eq!(to_delete(keep), vec!["dummy@100.0.0"]);
+
+        let exclude = Prune {
+            exclude: vec!["dummy@100.0.*".into()],
+            ..prune()
+        };
+        assert_eq!(to_delete(exclude), vec!["dummy@100.1.0"]);
+
+        last_used::touch(old);
+        last_used::touch(new);
+        let two_days_ago = SystemTime::now() - Duration::from_secs(2 * 24 * 60 * 60);
+        let old_last_used = dirs::DATA.join("last-used/dummy/100.0.0");
+        set_file_mtime(old_last_used, FileTime::from_system_time(two_days_ago)).unwrap();
+        let used_within = Prune {
+            used_within: Some(Duration::from_secs(24 * 60 * 60)),
+            ..prune()
+        };
+        assert_eq!(to_delete(used_within), vec!["dummy@100.0.0"]);
+
+        assert_cli!("uninstall", "dummy@100.0.0", "dummy@100.1.0");
+        last_used::remove(old).unwrap();
+        last_used::remove(new).unwrap();
+    }
+
+    #[test]
+    fn test_orphans() {
+        let config = Config::load().unwrap();
+        let cache = dirs::CACHE.join("python");
+        file::create_dir_all(cache.join("pyenv")).unwrap();
+        file::create_dir_all(cache.join("3.0.0")).unwrap();
+        let python = ["python".to_string()];
+        let tiny = ["tiny".to_string()];
+        let python_orphans = orphans(&config, Some(&python)).unwrap();
+        assert!(python_orphans.contains(&cache.join("3.0.0")));
+        assert!(!python_orphans.contains(&cache.join("pyenv")));
+        assert!(!orphans(&config, Some(&tiny))
+            .unwrap()
+            .iter()
+            .any(|p| p.starts_with(&cache)));
+        // other tests use the python cache so only what this test created is removed
+        file::remove_all(cache.join("3.0.0")).unwrap();
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("node@18.*", "node@18.1.0"));
+        assert!(!glob_match("node@18.*", "node@180.1.0"));
+        assert!(glob_match("node@1?.*", "node@18.1.0"));
+        assert!(glob_match("python", "python"));
+        assert!(!glob_match("python", "python3"));
+    }
 }
@@ -51,6 +51,7 @@ mod tests {
     fn test_completion() {
         assert_cli!("render-completion", "bash");
         assert_cli!("render-completion", "fish");
+        assert_cli!("render-completion", "powershell");
         assert_cli!("render-completion", "zsh");
     }
 }
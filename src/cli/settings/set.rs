@@ -24,13 +24,25 @@ impl SettingsSet {
             "always_keep_download" => parse_bool(&self.value)?,
             "always_keep_install" => parse_bool(&self.value)?,
             "legacy_version_file" => parse_bool(&self.value)?,
+            "legacy_version_file_manifests" => parse_bool(&self.value)?,
             "plugin_autoupdate_last_check_duration" => parse_i64(&self.value)?,
             "verbose" => parse_bool(&self.value)?,
             "asdf_compat" => parse_bool(&self.value)?,
             "jobs" => parse_i64(&self.value)?,
             "shorthands_file" => self.value.into(),
             "disable_default_shorthands" => parse_bool(&self.value)?,
+            "plugin_registry_url" => self.value.into(),
             "raw" => parse_bool(&self.value)?,
+            "missing_runtime_behavior" => match self.value.as_str() {
+                "autoinstall" | "prompt" | "warn" | "ignore" => self.value.into(),
+                _ => {
+                    return Err(eyre!(
+                        "{} must be autoinstall, prompt, warn or ignore",
+                        self.key
+                    ))
+                }
+            },
+            "plugin_sandbox" => parse_bool(&self.value)?,
             _ => return Err(eyre!("Unknown setting: {}", self.key)),
         };
 
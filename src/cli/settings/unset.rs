@@ -51,7 +51,10 @@ mod tests {
         jobs = 2
         legacy_version_file = true
         legacy_version_file_disable_tools = []
+        legacy_version_file_manifests = false
+        missing_runtime_behavior = warn
         plugin_autoupdate_last_check_duration = 20m
+        plugin_sandbox = false
         raw = false
         trusted_config_paths = []
         verbose = true
@@ -0,0 +1,31 @@
+use color_eyre::eyre::Result;
+
+use crate::config::Config;
+use crate::output::Output;
+use crate::shim_cache::ShimCache;
+
+/// Deletes the cached shim resolutions for all directories
+///
+/// Shims will load config again the next time they run.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, visible_alias = "c")]
+pub struct ShimCacheClear {}
+
+impl ShimCacheClear {
+    pub fn run(self, _config: Config, out: &mut Output) -> Result<()> {
+        ShimCache::clear()?;
+        rtxstatusln!(out, "shim cache cleared");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_cli, dirs};
+
+    #[test]
+    fn test_shim_cache_clear() {
+        assert_cli!("shim-cache", "clear");
+        assert!(!dirs::CACHE.join("shims").exists());
+    }
+}
@@ -0,0 +1,39 @@
+use clap::Subcommand;
+use color_eyre::eyre::Result;
+
+use crate::config::Config;
+use crate::output::Output;
+
+mod clear;
+
+/// Manage the cache shims use to skip loading config
+///
+/// The first time a shim runs in a directory, the path it resolves to is
+/// cached along with the mtimes of the config files that were used.
+/// Later runs in the same directory exec the cached path directly as long as
+/// none of those files have changed. The cache is cleared on every reshim.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment)]
+pub struct ShimCache {
+    #[clap(subcommand)]
+    command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    Clear(clear::ShimCacheClear),
+}
+
+impl Commands {
+    pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        match self {
+            Self::Clear(cmd) => cmd.run(config, out),
+        }
+    }
+}
+
+impl ShimCache {
+    pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        self.command.run(config, out)
+    }
+}
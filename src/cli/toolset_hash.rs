@@ -0,0 +1,42 @@
+use color_eyre::eyre::Result;
+
+use crate::config::Config;
+use crate::output::Output;
+use crate::toolset::ToolsetBuilder;
+
+/// Print a hash of the current toolset
+///
+/// The hash is derived from the plugin names and resolved versions of the
+/// current toolset along with the os and arch. It changes whenever a tool
+/// version changes so it works well as a CI cache key for the installs directory.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
+pub struct ToolsetHash {}
+
+impl ToolsetHash {
+    pub fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        let ts = ToolsetBuilder::new().build(&mut config)?;
+        rtxprintln!(out, "{}", ts.hash(&config));
+        Ok(())
+    }
+}
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  $ <bold>rtx toolset-hash</bold>
+  6d2c3a9f1b0e4c57
+"#
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_cli;
+
+    #[test]
+    fn test_toolset_hash() {
+        let a = assert_cli!("toolset-hash");
+        let b = assert_cli!("toolset-hash");
+        assert_eq!(a, b);
+        assert!(!a.trim().is_empty());
+    }
+}
@@ -5,6 +5,7 @@ use color_eyre::eyre::Result;
 
 use crate::cli::local;
 use crate::config::{config_file, Config};
+use crate::file::display_path;
 use crate::output::Output;
 
 /// Marks a config file as trusted
@@ -16,6 +17,10 @@ use crate::output::Output;
 /// - environment variables
 /// - templates
 /// - `path:` plugin versions
+///
+/// If those parts of a trusted config change it needs to be trusted again,
+/// running `rtx trust` shows what changed.
+/// Changes to `[tools]` versions don't require this.
 #[derive(Debug, clap::Args)]
 #[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
 pub struct Trust {
@@ -26,10 +31,27 @@ pub struct Trust {
     /// No longer trust this config
     #[clap(long)]
     pub untrust: bool,
+
+    /// Show all trusted config files
+    #[clap(long, conflicts_with_all = ["config_file", "untrust", "prune"])]
+    pub list: bool,
+
+    /// Forget trusted config files that no longer exist
+    #[clap(long, conflicts_with_all = ["config_file", "untrust"])]
+    pub prune: bool,
 }
 
 impl Trust {
     pub fn run(self, _config: Config, out: &mut Output) -> Result<()> {
+        if self.list {
+            return self.list(out);
+        }
+        if self.prune {
+            for path in config_file::prune_trusted_configs()? {
+                rtxprintln!(out, "pruned {}", display_path(&path));
+            }
+            return Ok(());
+        }
         let path = match &self.config_file {
             Some(filename) => PathBuf::from(filename),
             None => local::get_parent_path()?,
@@ -38,11 +60,28 @@ impl Trust {
             config_file::untrust(&path)?;
             rtxprintln!(out, "untrusted {}", &path.canonicalize()?.display());
         } else {
+            if let Some(diff) = config_file::trust_diff(&path) {
+                rtxprintln!(out, "{}", diff.trim_end());
+            }
             config_file::trust(&path)?;
             rtxprintln!(out, "trusted {}", &path.canonicalize()?.display());
         }
         Ok(())
     }
+
+    fn list(&self, out: &mut Output) -> Result<()> {
+        for path in config_file::trusted_configs()? {
+            let status = if !path.exists() {
+                " (missing)"
+            } else if config_file::trust_diff(&path).is_some() {
+                " (changed)"
+            } else {
+                ""
+            };
+            rtxprintln!(out, "{}{status}", display_path(&path));
+        }
+        Ok(())
+    }
 }
 
 static AFTER_LONG_HELP: &str = color_print::cstr!(
@@ -52,6 +91,12 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
 
   # trusts .rtx.toml in the current or parent directory
   $ <bold>rtx trust</bold>
+
+  # shows trusted config files and if they've changed or been removed
+  $ <bold>rtx trust --list</bold>
+
+  # forgets trusted config files that have been removed
+  $ <bold>rtx trust --prune</bold>
 "#
 );
 
@@ -1,22 +1,28 @@
 use std::collections::HashSet;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use eyre::Result;
 use eyre::WrapErr;
+use itertools::Itertools;
 
 use crate::cli::args::tool::{ToolArg, ToolArgParser};
-use crate::config::Config;
+use crate::config::{config_file, Config};
+use crate::file::display_path;
 use crate::output::Output;
 use crate::plugins::Plugin;
-use crate::runtime_symlinks;
-use crate::shims;
-use crate::toolset::{ToolVersion, ToolsetBuilder};
+use crate::toolset::{ToolSource, ToolVersion, ToolVersionRequest, Toolset, ToolsetBuilder};
 use crate::ui::multi_progress_report::MultiProgressReport;
 use crate::ui::progress_report::ProgressReport;
+use crate::ui::prompt;
+use crate::{runtime_symlinks, shims};
 
 /// Upgrades outdated tool versions
+///
+/// With --bump, the versions in config files are rewritten to the new
+/// version keeping the same precision, e.g.: `20.9.0` -> `20.11.1`
 #[derive(Debug, clap::Args)]
-#[clap(verbatim_doc_comment)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
 pub struct Upgrade {
     /// Tool(s) to upgrade
     /// e.g.: node@20 python@3.10
@@ -27,6 +33,19 @@ pub struct Upgrade {
     /// Just print what would be done, don't actually do it
     #[clap(long, short = 'n', verbatim_doc_comment)]
     dry_run: bool,
+
+    /// Rewrite the versions in config files to the upgraded versions
+    #[clap(long, verbatim_doc_comment)]
+    bump: bool,
+
+    /// With --bump, upgrade to the latest version even across major versions
+    /// e.g.: `node = "20"` -> `node = "21"`
+    #[clap(long, requires = "bump", verbatim_doc_comment)]
+    latest: bool,
+
+    /// With --bump, confirm each change to a config file
+    #[clap(long, short, requires = "bump", verbatim_doc_comment)]
+    interactive: bool,
 }
 
 impl Upgrade {
@@ -41,6 +60,9 @@ impl Upgrade {
             .collect::<HashSet<_>>();
         ts.versions
             .retain(|_, tvl| tool_set.is_empty() || tool_set.contains(&tvl.plugin_name));
+        if self.bump {
+            return self.bump(&mut config, &ts, out);
+        }
         let outdated = ts.list_outdated_versions(&config);
         if outdated.is_empty() {
             info!("All tools are up to date");
@@ -51,6 +73,130 @@ impl Upgrade {
         Ok(())
     }
 
+    fn bump(&self, config: &mut Config, ts: &Toolset, out: &mut Output) -> Result<()> {
+        let mut bumps = vec![];
+        for (tool, tv) in ts.list_current_versions(config) {
+            if tool.symlink_path(&tv).is_some() {
+                // do not consider symlinked versions to be outdated
+                continue;
+            }
+            let source = &ts.versions[tool.name()].source;
+            match self.get_bump(config, source, tool.clone(), tv) {
+                Ok(Some(bump)) if bump.rewrite.is_some() || bump.is_outdated() => bumps.push(bump),
+                Ok(_) => {}
+                Err(err) => warn!("Error getting latest version for {tool}: {err:#}"),
+            }
+        }
+        if self.interactive {
+            let mut confirmed = vec![];
+            for b in bumps {
+                let confirm = match &b.rewrite {
+                    Some((path, request)) => prompt::confirm(&format!(
+                        "bump {} {} -> {request} in {}?",
+                        b.tool,
+                        b.tv.request.version(),
+                        display_path(path)
+                    ))?,
+                    None => true,
+                };
+                if confirm {
+                    confirmed.push(b);
+                }
+            }
+            bumps = confirmed;
+        }
+        if bumps.is_empty() {
+            info!("All tools are up to date");
+            return Ok(());
+        }
+
+        let rewrites = bumps
+            .iter()
+            .filter_map(|b| b.rewrite.as_ref().map(|(path, request)| (path, b, request)))
+            .into_group_map_by(|(path, _, _)| *path);
+        for (path, rewrites) in rewrites.into_iter().sorted_by_key(|(path, _)| *path) {
+            let mut cf = config_file::parse(&config.settings, path)?;
+            for (_, b, request) in rewrites {
+                let from = b.tv.request.version();
+                if self.dry_run {
+                    rtxprintln!(
+                        out,
+                        "Would bump {} {from} -> {request} in {}",
+                        b.tool,
+                        display_path(path)
+                    );
+                    continue;
+                }
+                cf.bump_version(&b.tool.name().to_string(), &from, request);
+                rtxprintln!(
+                    out,
+                    "bumped {} {from} -> {request} in {}",
+                    b.tool,
+                    display_path(path)
+                );
+            }
+            if !self.dry_run {
+                cf.save()?;
+            }
+        }
+
+        let outdated = bumps
+            .into_iter()
+            .filter(|b| b.is_outdated())
+            .map(|mut b| {
+                // versions are installed from their request so it needs to be the bumped one
+                if let Some((_, request)) = &b.rewrite {
+                    b.tv.request = ToolVersionRequest::new(b.tool.name().to_string(), request);
+                }
+                (b.tool, b.tv, b.version)
+            })
+            .collect_vec();
+        if !outdated.is_empty() {
+            self.upgrade(config, outdated, out)?;
+        }
+        Ok(())
+    }
+
+    /// what `tv` should be upgraded to, and the new request if it's a version
+    /// or prefix from a config file that changes
+    fn get_bump(
+        &self,
+        config: &Config,
+        source: &ToolSource,
+        tool: Arc<dyn Plugin>,
+        tv: ToolVersion,
+    ) -> Result<Option<Bump>> {
+        let path = match source {
+            ToolSource::RtxToml(path) | ToolSource::ToolVersions(path) => Some(path),
+            _ => None,
+        };
+        let requested = match &tv.request {
+            ToolVersionRequest::Version(_, v) => Some(("", v)),
+            ToolVersionRequest::Prefix(_, p) => Some(("prefix:", p)),
+            _ => None,
+        }
+        .filter(|(_, v)| *v != "latest");
+        let (Some(path), Some((prefix, requested))) = (path, requested) else {
+            let version = tv.latest_version(config, tool.clone())?;
+            return Ok(Some(Bump::new(tool, tv, version, None)));
+        };
+        if config.resolve_alias(tool.name(), requested)? != *requested {
+            // aliases like "lts" already move on their own
+            let version = tv.latest_version(config, tool.clone())?;
+            return Ok(Some(Bump::new(tool, tv, version, None)));
+        }
+        let query = match self.latest {
+            true => None,
+            false => requested.split('.').next().map(|major| major.to_string()),
+        };
+        let Some(version) = tool.latest_version(&config.settings, query)? else {
+            return Ok(None);
+        };
+        let request = format!("{prefix}{}", bump_request(requested, &version));
+        let rewrite = (request != tv.request.version()).then(|| (path.clone(), request));
+        Ok(Some(Bump::new(tool, tv, version, rewrite)))
+    }
+
     fn upgrade(&self, config: &mut Config, outdated: OutputVec, out: &mut Output) -> Result<()> {
         let mpr = MultiProgressReport::new(config.show_progress_bars());
         let mut ts = ToolsetBuilder::new().with_args(&self.tool).build(config)?;
@@ -112,12 +258,68 @@ impl Upgrade {
     }
 }
 
+/// `version` with the precision of `requested`, e.g.: "20" -> "21", "20.9.0" -> "21.2.0"
+fn bump_request(requested: &str, version: &str) -> String {
+    let precision = requested.split('.').count();
+    version.split('.').take(precision).join(".")
+}
+
 type OutputVec = Vec<(Arc<dyn Plugin>, ToolVersion, String)>;
 
+/// a tool version and the version it's upgraded to, along with the config
+/// file and request to rewrite when bumping
+struct Bump {
+    tool: Arc<dyn Plugin>,
+    tv: ToolVersion,
+    version: String,
+    rewrite: Option<(PathBuf, String)>,
+}
+
+impl Bump {
+    fn new(
+        tool: Arc<dyn Plugin>,
+        tv: ToolVersion,
+        version: String,
+        rewrite: Option<(PathBuf, String)>,
+    ) -> Self {
+        Self {
+            tool,
+            tv,
+            version,
+            rewrite,
+        }
+    }
+
+    fn is_outdated(&self) -> bool {
+        !self.tool.is_version_installed(&self.tv) || self.tv.version != self.version
+    }
+}
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  # upgrades node to the latest version matching the config
+  $ <bold>rtx upgrade node</bold>
+
+  # also rewrites `node = "20.9.0"` to `node = "20.11.1"`
+  $ <bold>rtx upgrade --bump node</bold>
+
+  # rewrites `node = "20"` to `node = "21"`
+  $ <bold>rtx upgrade --bump --latest node</bold>
+
+  # shows what would change
+  $ <bold>rtx upgrade --bump --latest --dry-run</bold>
+"#
+);
+
 #[cfg(test)]
 pub mod tests {
+    use indoc::indoc;
+    use insta::assert_snapshot;
+
     use crate::test::reset_config;
-    use crate::{assert_cli_snapshot, dirs, file};
+    use crate::{assert_cli, assert_cli_snapshot, dirs, file};
+
+    use super::bump_request;
 
     #[test]
     fn test_upgrade() {
@@ -131,4 +333,72 @@ pub mod tests {
         assert_cli_snapshot!("upgrade");
         assert!(dirs::INSTALLS.join("tiny").join("3.1.0").exists());
     }
+
+    #[test]
+    fn test_upgrade_bump() {
+        reset_config();
+        file::rename(
+            dirs::INSTALLS.join("tiny").join("3.1.0"),
+            dirs::INSTALLS.join("tiny").join("3.0.0"),
+        )
+        .unwrap();
+        // tiny is requested as "3" which doesn't need to be rewritten
+        let output = assert_cli!("upgrade", "--bump", "--latest", "--dry-run");
+        file::rename(
+            dirs::INSTALLS.join("tiny").join("3.0.0"),
+            dirs::INSTALLS.join("tiny").join("3.1.0"),
+        )
+        .unwrap();
+        assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_upgrade_bump_rewrite() {
+        reset_config();
+        let cf_path = dirs::CURRENT.join(".test.rtx.toml");
+        file::write(
+            &cf_path,
+            indoc! {r#"
+            [tools]
+            tiny = [
+              { version = "2.0.0", foo = "bar" },
+              { version = "1.0.0", os = "plan9" },
+            ]
+            "#},
+        )
+        .unwrap();
+        assert_cli!("upgrade", "--bump", "tiny");
+        assert_snapshot!(file::read_to_string(&cf_path).unwrap(), @r###"
+        [tools]
+        tiny = [
+          { version = "2.1.0", foo = "bar" },
+          { version = "1.0.0", os = "plan9" },
+        ]
+        "###);
+
+        file::write(&cf_path, "[tools]\ntiny = \"2.0\" # pinned\n").unwrap();
+        assert_cli!("upgrade", "--bump", "--latest", "--dry-run", "tiny");
+        assert_snapshot!(file::read_to_string(&cf_path).unwrap(), @r###"
+        [tools]
+        tiny = "2.0" # pinned
+        "###);
+        assert_cli!("upgrade", "--bump", "--latest", "tiny");
+        assert_snapshot!(file::read_to_string(&cf_path).unwrap(), @r###"
+        [tools]
+        tiny = "3.1" # pinned
+        "###);
+
+        file::remove_file(&cf_path).unwrap();
+    }
+
+    #[test]
+    fn test_bump_request() {
+        assert_eq!(bump_request("20", "21.2.0"), "21");
+        assert_eq!(bump_request("20.9", "21.2.0"), "21.2");
+        assert_eq!(bump_request("20.9.0", "21.2.0"), "21.2.0");
+        assert_eq!(
+            bump_request("temurin-17.0.2", "temurin-21.0.1"),
+            "temurin-21.0.1"
+        );
+    }
 }
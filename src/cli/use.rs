@@ -54,7 +54,8 @@ pub struct Use {
     global: bool,
 
     /// [experimental] Modify an environment-specific config file like .rtx.<env>.toml
-    #[clap(long, short, overrides_with_all = &["global", "path"])]
+    /// It is used when <env> is one of the profiles in RTX_ENV, e.g.: RTX_ENV=ci,<env>
+    #[clap(long, short, overrides_with_all = &["global", "path"], verbatim_doc_comment)]
     env: Option<String>,
 
     /// Specify a path to a config file or directory
@@ -157,7 +158,7 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   # sets .rtx.local.toml (which is intended not to be committed to a project)
   $ <bold>rtx use --env local node@20</bold>
 
-  # sets .rtx.staging.toml (which is used if RTX_ENV=staging)
+  # sets .rtx.staging.toml (which is used if RTX_ENV=staging or RTX_ENV=ci,staging)
   $ <bold>rtx use --env staging node@20</bold>
 "#
 );
@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::string::ToString;
 use std::time::Duration;
 
@@ -27,6 +28,55 @@ pub static ARCH: Lazy<String> = Lazy::new(|| {
     }
     .to_string()
 });
+/// "gnu" or "musl" on linux, detected from the host rather than how rtx was built
+pub static LIBC: Lazy<Option<String>> = Lazy::new(|| {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    let libc = host_libc(Path::new("/")).unwrap_or(match cfg!(target_env = "musl") {
+        true => "musl",
+        false => "gnu",
+    });
+    Some(libc.into())
+});
+
+/// glibc wins if both are installed since musl can be added to glibc systems but not the other way
+/// around, e.g.: /lib/x86_64-linux-gnu/libc.so.6 or /lib/ld-musl-x86_64.so.1
+fn host_libc(root: &Path) -> Option<&'static str> {
+    let lib_dirs = ["lib", "lib64", "usr/lib", "usr/lib64"]
+        .iter()
+        .map(|d| root.join(d))
+        .collect::<Vec<_>>();
+    let entries = |dir: &Path| {
+        std::fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|e| e.path())
+            .collect::<Vec<_>>()
+    };
+    let has_glibc = lib_dirs.iter().any(|dir| {
+        dir.join("libc.so.6").exists()
+            || entries(dir)
+                .iter()
+                .any(|p| p.is_dir() && p.join("libc.so.6").exists())
+    });
+    let has_musl = || {
+        lib_dirs.iter().any(|dir| {
+            entries(dir).iter().any(|p| {
+                let name = p.file_name().unwrap_or_default().to_string_lossy();
+                name.starts_with("ld-musl-") || name.starts_with("libc.musl-")
+            })
+        })
+    };
+    if has_glibc {
+        Some("gnu")
+    } else if has_musl() {
+        Some("musl")
+    } else {
+        None
+    }
+}
 
 pub static VERSION: Lazy<String> = Lazy::new(|| {
     let mut version = RAW_VERSION.clone();
@@ -139,4 +189,17 @@ mod tests {
         let stdout = assert_cli!("version");
         assert_str_eq!(stdout, VERSION.to_string() + "\n");
     }
+
+    #[test]
+    fn test_host_libc() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+        assert_eq!(host_libc(root), None);
+        file::create_dir_all(root.join("lib")).unwrap();
+        file::write(root.join("lib/ld-musl-x86_64.so.1"), "").unwrap();
+        assert_eq!(host_libc(root), Some("musl"));
+        file::create_dir_all(root.join("lib/x86_64-linux-gnu")).unwrap();
+        file::write(root.join("lib/x86_64-linux-gnu/libc.so.6"), "").unwrap();
+        assert_eq!(host_libc(root), Some("gnu"));
+    }
 }
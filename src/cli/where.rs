@@ -119,6 +119,16 @@ mod tests {
         assert_cli!("uninstall", "tiny@my/alias");
     }
 
+    #[test]
+    fn test_where_range() {
+        assert_cli!("install", "tiny@2.1.0");
+        let stdout = assert_cli!("where", "tiny@^2");
+        assert_str_eq!(
+            stdout.trim(),
+            dirs::DATA.join("installs/tiny/2.1.0").to_string_lossy()
+        );
+    }
+
     #[test]
     fn test_where_not_found() {
         let err = assert_cli_err!("where", "tiny@1111");
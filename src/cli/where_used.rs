@@ -0,0 +1,113 @@
+use color_eyre::eyre::Result;
+use console::{pad_str, style, Alignment};
+use itertools::Itertools;
+
+use crate::cli::args::tool::{ToolArg, ToolArgParser};
+use crate::config::Config;
+use crate::file::display_path;
+use crate::output::Output;
+use crate::toolset::ToolVersion;
+
+/// Show which tracked config files use a tool version
+///
+/// rtx tracks which config files have been used in ~/.local/share/rtx/tracked_config_files
+/// Each of those is resolved to find the versions it uses.
+/// A version matches if it is the same as the one given, starts with it (e.g.: 18 matches 18.19.0)
+/// or if the config file requests it exactly.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
+pub struct WhereUsed {
+    /// Tool(s) to look up
+    /// e.g.: node@18
+    /// If no version is specified, all versions of the tool are shown
+    #[clap(required = true, value_name = "TOOL@VERSION", value_parser = ToolArgParser, verbatim_doc_comment)]
+    tool: Vec<ToolArg>,
+}
+
+impl WhereUsed {
+    pub fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        let used = config
+            .get_tracked_versions()?
+            .into_iter()
+            .filter(|(_, tv)| self.tool.iter().any(|t| matches(t, tv)))
+            .sorted_by_cached_key(|(path, tv)| (tv.to_string(), path.clone()))
+            .collect_vec();
+        if used.is_empty() {
+            let tools = self.tool.iter().join(" ");
+            info!("no tracked config files use {tools}");
+            return Ok(());
+        }
+        let width = used
+            .iter()
+            .map(|(_, tv)| tv.to_string().len())
+            .max()
+            .unwrap_or_default();
+        for (path, tv) in used {
+            let tv_str = tv.to_string();
+            rtxprintln!(
+                out,
+                "{} {} {}",
+                style(pad_str(&tv_str, width, Alignment::Left, None)).cyan(),
+                display_path(&path),
+                style(tv.request.version()).dim()
+            );
+        }
+        Ok(())
+    }
+}
+
+fn matches(tool: &ToolArg, tv: &ToolVersion) -> bool {
+    if tool.plugin != tv.plugin_name {
+        return false;
+    }
+    match &tool.tvr {
+        Some(tvr) => {
+            let v = tvr.version();
+            tv.version == v || tv.version.starts_with(&format!("{v}.")) || tv.request.version() == v
+        }
+        None => true,
+    }
+}
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  # shows which projects still use node 18 and the version they request
+  $ <bold>rtx where-used node@18</bold>
+  node@18.19.0 ~/src/legacy/.rtx.toml       18
+  node@18.19.0 ~/src/other/.tool-versions   18.19.0
+
+  # shows every project using node
+  $ <bold>rtx where-used node</bold>
+"#
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_cli;
+    use crate::toolset::ToolVersionRequest;
+
+    use super::*;
+
+    #[test]
+    fn test_where_used() {
+        let stdout = assert_cli!("where-used", "tiny@3");
+        assert!(stdout.contains("tiny@3.1.0"));
+        assert!(stdout.contains(".test-tool-versions"));
+    }
+
+    #[test]
+    fn test_matches() {
+        let tv = ToolVersion {
+            request: ToolVersionRequest::new("node".into(), "18"),
+            plugin_name: "node".into(),
+            version: "18.19.0".into(),
+            opts: Default::default(),
+        };
+        assert!(matches(&ToolArg::parse("node"), &tv));
+        assert!(matches(&ToolArg::parse("node@18"), &tv));
+        assert!(matches(&ToolArg::parse("node@18.19"), &tv));
+        assert!(!matches(&ToolArg::parse("node@18.1"), &tv));
+        assert!(!matches(&ToolArg::parse("node@1"), &tv));
+        assert!(!matches(&ToolArg::parse("python@18"), &tv));
+    }
+}
@@ -51,6 +51,10 @@ impl ConfigFile for LegacyVersionFile {
         unimplemented!()
     }
 
+    fn bump_version(&mut self, _plugin_name: &PluginName, _from: &str, _to: &str) {
+        unimplemented!()
+    }
+
     fn save(&self) -> Result<()> {
         unimplemented!()
     }
@@ -1,9 +1,12 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::{Debug, Display};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 use color_eyre::eyre::{eyre, Result};
 use confique::Partial;
+use once_cell::sync::Lazy;
+use similar::TextDiff;
 
 use tool_versions::ToolVersions;
 
@@ -42,17 +45,31 @@ pub trait ConfigFile: Debug + Display + Send + Sync {
     fn env_remove(&self) -> Vec<String> {
         Default::default()
     }
+    /// env vars whose values were rendered from a template
+    fn env_templated(&self) -> HashSet<String> {
+        Default::default()
+    }
     fn path_dirs(&self) -> Vec<PathBuf> {
         Default::default()
     }
     fn remove_plugin(&mut self, _plugin_name: &PluginName);
+    /// removes the ref or sha a plugin is pinned to in `[plugins]`, returns true if it was pinned
+    fn unpin_plugin(&mut self, _plugin_name: &PluginName) -> bool {
+        false
+    }
     fn replace_versions(&mut self, plugin_name: &PluginName, versions: &[String]);
+    /// replaces the `from` version of `plugin_name` with `to`, leaving the rest of the entry alone
+    fn bump_version(&mut self, plugin_name: &PluginName, from: &str, to: &str);
     fn save(&self) -> Result<()>;
     fn dump(&self) -> String;
     fn to_toolset(&self) -> &Toolset;
     fn settings(&self) -> Result<SettingsPartial> {
         Ok(SettingsPartial::empty())
     }
+    /// settings that must have these values for the config file to be used
+    fn required_settings(&self) -> BTreeMap<String, String> {
+        Default::default()
+    }
     fn aliases(&self) -> AliasMap {
         Default::default()
     }
@@ -176,7 +193,11 @@ pub fn parse_or_init(settings: &Settings, path: &Path) -> Result<Box<dyn ConfigF
 pub fn parse(settings: &Settings, path: &Path) -> Result<Box<dyn ConfigFile>> {
     let is_trusted = is_trusted(settings, path);
     match detect_config_file_type(path) {
-        Some(ConfigFileType::RtxToml) => Ok(Box::new(RtxToml::from_file(path, is_trusted)?)),
+        Some(ConfigFileType::RtxToml) => Ok(Box::new(RtxToml::from_file_with_profiles(
+            path,
+            is_trusted,
+            settings.active_profiles(),
+        )?)),
         Some(ConfigFileType::ToolVersions) => {
             Ok(Box::new(ToolVersions::from_file(path, is_trusted)?))
         }
@@ -185,6 +206,8 @@ pub fn parse(settings: &Settings, path: &Path) -> Result<Box<dyn ConfigFile>> {
     }
 }
 
+/// configs are trusted by path along with a snapshot of the sections that need trust,
+/// if those sections change the config needs to be trusted again
 pub fn is_trusted(settings: &Settings, path: &Path) -> bool {
     if settings
         .trusted_config_paths
@@ -193,9 +216,48 @@ pub fn is_trusted(settings: &Settings, path: &Path) -> bool {
     {
         return true;
     }
-    match path.canonicalize() {
-        Ok(path) => trust_path(&path).exists(),
-        Err(_) => false,
+    let Ok(path) = path.canonicalize() else {
+        return false;
+    };
+    if !trust_path(&path).exists() {
+        return false;
+    }
+    // this runs on every load, so configs that haven't changed since their snapshot was
+    // written aren't parsed again. Equal mtimes are rechecked since they can't be ordered.
+    let snapshot_path = trust_snapshot_path(&path);
+    let modified = |p: &Path| p.metadata().and_then(|m| m.modified()).ok();
+    if let (Some(config), Some(snapshot)) = (modified(&path), modified(&snapshot_path)) {
+        if config < snapshot {
+            return true;
+        }
+    }
+    match read_trust_snapshot(&path) {
+        Some(snapshot) if trust_sections(&path).is_ok_and(|current| current == snapshot) => {
+            // the config changed but not in a part that needs trust, rewriting the snapshot
+            // updates its mtime so the next check is skipped
+            if let Err(err) = file::write(&snapshot_path, snapshot) {
+                debug!("failed to update trust snapshot: {err:#}");
+            }
+            true
+        }
+        Some(_) => false,
+        // trusted before snapshots were recorded, so what was trusted is unknown
+        None => {
+            warn_missing_trust_snapshot(&path);
+            false
+        }
+    }
+}
+
+/// once per config since `is_trusted` is called several times per load
+fn warn_missing_trust_snapshot(path: &Path) {
+    static WARNED: Lazy<Mutex<HashSet<PathBuf>>> = Lazy::new(Default::default);
+    if WARNED.lock().unwrap().insert(path.to_path_buf()) {
+        warn!(
+            "{} was trusted by an older version of rtx and needs to be trusted again, run `rtx trust {}`",
+            display_path(path),
+            display_path(path)
+        );
     }
 }
 
@@ -206,14 +268,69 @@ pub fn trust(path: &Path) -> Result<()> {
         file::create_dir_all(hashed_path.parent().unwrap())?;
         file::make_symlink(&path, &hashed_path)?;
     }
-    Ok(())
+    write_trust_snapshot(&path)
 }
 
 pub fn untrust(path: &Path) -> Result<()> {
-    let path = path.canonicalize()?;
-    let hashed_path = trust_path(&path);
-    if hashed_path.exists() {
-        file::remove_file(hashed_path)?;
+    remove_trust(&path.canonicalize()?)
+}
+
+/// what changed in the sections that need trust since `path` was trusted
+/// configs trusted before snapshots were recorded show all of those sections
+pub fn trust_diff(path: &Path) -> Option<String> {
+    let path = path.canonicalize().ok()?;
+    if !trust_path(&path).exists() {
+        return None;
+    }
+    let (header, snapshot) = match read_trust_snapshot(&path) {
+        Some(snapshot) => ("trusted", snapshot),
+        None => ("trusted (unknown)", String::new()),
+    };
+    let current = trust_sections(&path).ok()?;
+    if snapshot == current {
+        return None;
+    }
+    let diff = TextDiff::from_lines(&snapshot, &current)
+        .unified_diff()
+        .header(header, &display_path(&path))
+        .to_string();
+    Some(diff)
+}
+
+/// paths of every trusted config, including ones that no longer exist
+pub fn trusted_configs() -> Result<Vec<PathBuf>> {
+    let dir = dirs::DATA.join("trusted-configs");
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+    let mut paths = vec![];
+    for entry in dir.read_dir()? {
+        let link = entry?.path();
+        if link.is_symlink() {
+            paths.push(link.read_link()?);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// forgets trusted configs that no longer exist, returns their paths
+pub fn prune_trusted_configs() -> Result<Vec<PathBuf>> {
+    let mut pruned = vec![];
+    for path in trusted_configs()? {
+        if !path.exists() {
+            remove_trust(&path)?;
+            pruned.push(path);
+        }
+    }
+    Ok(pruned)
+}
+
+fn remove_trust(path: &Path) -> Result<()> {
+    for p in [trust_path(path), trust_snapshot_path(path)] {
+        if p.exists() || p.is_symlink() {
+            file::remove_file(p)?;
+        }
     }
     Ok(())
 }
@@ -222,6 +339,47 @@ fn trust_path(path: &Path) -> PathBuf {
     dirs::DATA.join("trusted-configs").join(hash_to_str(&path))
 }
 
+fn trust_snapshot_path(path: &Path) -> PathBuf {
+    dirs::DATA
+        .join("trusted-config-snapshots")
+        .join(hash_to_str(&path))
+}
+
+fn read_trust_snapshot(path: &Path) -> Option<String> {
+    file::read_to_string(trust_snapshot_path(path)).ok()
+}
+
+fn write_trust_snapshot(path: &Path) -> Result<()> {
+    let snapshot_path = trust_snapshot_path(path);
+    file::create_dir_all(snapshot_path.parent().unwrap())?;
+    file::write(snapshot_path, trust_sections(path)?)
+}
+
+/// the parts of a config that can run code or change the environment,
+/// `[tools]` entries are left out unless they use templates or `path:` versions
+fn trust_sections(path: &Path) -> Result<String> {
+    let body = file::read_to_string(path)?;
+    let needs_trust = |s: &str| ["{{", "{%", "{#", "path:"].iter().any(|m| s.contains(m));
+    match detect_config_file_type(path) {
+        Some(ConfigFileType::RtxToml) => {
+            let mut doc: toml::Table = body.parse()?;
+            if let Some(toml::Value::Table(tools)) = doc.get_mut("tools") {
+                tools.retain(|_, v| needs_trust(&v.to_string()));
+                if tools.is_empty() {
+                    doc.remove("tools");
+                }
+            }
+            Ok(toml::to_string_pretty(&doc)?)
+        }
+        Some(ConfigFileType::ToolVersions) => Ok(body
+            .lines()
+            .filter(|l| needs_trust(l))
+            .map(|l| format!("{l}\n"))
+            .collect()),
+        _ => Ok(body),
+    }
+}
+
 fn detect_config_file_type(path: &Path) -> Option<ConfigFileType> {
     match path.file_name().unwrap().to_str().unwrap() {
         f if f.ends_with(".toml") => Some(ConfigFileType::RtxToml),
@@ -235,6 +393,9 @@ fn detect_config_file_type(path: &Path) -> Option<ConfigFileType> {
 
 #[cfg(test)]
 mod tests {
+    use filetime::{set_file_mtime, FileTime};
+    use insta::assert_snapshot;
+
     use super::*;
 
     #[test]
@@ -248,4 +409,59 @@ mod tests {
             Some(ConfigFileType::RtxToml)
         );
     }
+
+    #[test]
+    fn test_trust() {
+        let settings = Settings::default();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".rtx.toml");
+        file::write(&path, "[env]\nFOO = \"bar\"\n[tools]\ntiny = \"1\"\n").unwrap();
+        assert!(!is_trusted(&settings, &path));
+        trust(&path).unwrap();
+        assert!(is_trusted(&settings, &path));
+
+        // version bumps don't need to be trusted again
+        file::write(&path, "[env]\nFOO = \"bar\"\n[tools]\ntiny = \"2\"\n").unwrap();
+        assert!(is_trusted(&settings, &path));
+        assert!(trust_diff(&path).is_none());
+
+        file::write(&path, "[env]\nFOO = \"{{exec(command='echo bar')}}\"\n").unwrap();
+        assert!(!is_trusted(&settings, &path));
+        let diff = trust_diff(&path).unwrap();
+        assert_snapshot!(diff.lines().skip(2).collect::<Vec<_>>().join("\n"), @r###"
+        @@ -1,2 +1,2 @@
+         [env]
+        -FOO = "bar"
+        +FOO = "{{exec(command='echo bar')}}"
+        "###);
+        trust(&path).unwrap();
+        assert!(is_trusted(&settings, &path));
+
+        // trusted by an older version of rtx that didn't record what was trusted
+        let canonical = path.canonicalize().unwrap();
+        file::remove_file(trust_snapshot_path(&canonical)).unwrap();
+        assert!(!is_trusted(&settings, &path));
+        let diff = trust_diff(&path).unwrap();
+        assert_snapshot!(diff.lines().skip(2).collect::<Vec<_>>().join("\n"), @r###"
+        @@ -0,0 +1,2 @@
+        +[env]
+        +FOO = "{{exec(command='echo bar')}}"
+        "###);
+        trust(&path).unwrap();
+        assert!(is_trusted(&settings, &path));
+
+        // configs older than their snapshot aren't parsed again
+        let snapshot_path = trust_snapshot_path(&canonical);
+        file::write(&snapshot_path, "not what was trusted").unwrap();
+        let later = FileTime::from_unix_time(FileTime::now().unix_seconds() + 60, 0);
+        set_file_mtime(&snapshot_path, later).unwrap();
+        assert!(is_trusted(&settings, &path));
+        set_file_mtime(&path, later).unwrap();
+        assert!(!is_trusted(&settings, &path));
+        trust(&path).unwrap();
+        assert!(is_trusted(&settings, &path));
+
+        untrust(&path).unwrap();
+        assert!(!is_trusted(&settings, &path));
+    }
 }
@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::{Debug, Display, Formatter};
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
@@ -10,21 +10,27 @@ use confique::Partial;
 use console::style;
 use eyre::WrapErr;
 use tera::Context;
-use toml_edit::{table, value, Array, Document, Item, Table, Value};
+use toml_edit::{table, value, Array, Document, Item, Table, TableLike, Value};
+use versions::Versioning;
 
+use crate::cli::version::{ARCH, LIBC, OS, RAW_VERSION};
 use crate::config::config_file::{ConfigFile, ConfigFileType};
 use crate::config::settings::SettingsPartial;
 use crate::config::{config_file, AliasMap, Settings};
 use crate::errors::Error::UntrustedConfig;
 use crate::file::{create_dir_all, display_path};
+use crate::git::Git;
 use crate::plugins::{unalias_plugin, PluginName};
 use crate::tera::{get_tera, BASE_CONTEXT};
 use crate::toolset::{
-    ToolSource, ToolVersionList, ToolVersionOptions, ToolVersionRequest, Toolset,
+    ToolSource, ToolVersionList, ToolVersionOptions, ToolVersionRequest, Toolset, VersionRange,
 };
 use crate::ui::prompt;
 use crate::{dirs, env, file, parse_error};
 
+const UPGRADE_SUGGESTION: &str =
+    "this may need a newer version of rtx, upgrade with `rtx self-update` or your package manager";
+
 #[derive(Default)]
 pub struct RtxToml {
     context: Context,
@@ -33,11 +39,16 @@ pub struct RtxToml {
     env_file: Option<PathBuf>,
     env: HashMap<String, String>,
     env_remove: Vec<String>,
+    env_templated: HashSet<String>,
     path_dirs: Vec<PathBuf>,
     settings: Table,
+    required_settings: BTreeMap<String, String>,
     alias: AliasMap,
     doc: Document,
     plugins: HashMap<String, String>,
+    /// profiles used for [env.<profile>] and [tools.<profile>] sections,
+    /// None if those sections aren't enabled
+    profiles: Option<Vec<String>>,
     is_trusted: Mutex<RefCell<bool>>,
 }
 
@@ -58,8 +69,17 @@ impl RtxToml {
     }
 
     pub fn from_file(path: &Path, is_trusted: bool) -> Result<Self> {
+        Self::from_file_with_profiles(path, is_trusted, None)
+    }
+
+    pub fn from_file_with_profiles(
+        path: &Path,
+        is_trusted: bool,
+        profiles: Option<&[String]>,
+    ) -> Result<Self> {
         trace!("parsing: {}", path.display());
         let mut rf = Self::init(path, is_trusted);
+        rf.profiles = profiles.map(|p| p.to_vec());
         let body = file::read_to_string(path).suggestion("ensure file exists and can be read")?;
         rf.parse(&body)?;
         trace!("{rf}");
@@ -68,23 +88,74 @@ impl RtxToml {
 
     fn parse(&mut self, s: &str) -> Result<()> {
         let doc: Document = s.parse().suggestion("ensure file is valid TOML")?;
+        // checked first so older versions of rtx fail with this instead of an unknown key
+        if let Some(v) = doc.get("min_version") {
+            self.check_min_version(v)?;
+        }
+        // parsed before the rest so templates can use `tools.<name>.path`
+        if let Some(v) = doc.get("tools") {
+            self.toolset = self.parse_toolset("tools", v)?;
+            self.context.insert("tools", &tools_context(&self.toolset));
+        }
         for (k, v) in doc.iter() {
             match k {
+                "min_version" => {}
+                "required_settings" => {
+                    self.required_settings = self.parse_required_settings(k, v)?
+                }
                 "dotenv" => self.parse_env_file(k, v)?,
                 "env_file" => self.parse_env_file(k, v)?,
                 "env_path" => self.path_dirs = self.parse_path_env(k, v)?,
                 "env" => self.parse_env(k, v)?,
                 "alias" => self.alias = self.parse_alias(k, v)?,
-                "tools" => self.toolset = self.parse_toolset(k, v)?,
+                "tools" => {}
                 "settings" => self.settings = self.parse_settings(k, v)?,
                 "plugins" => self.plugins = self.parse_plugins(k, v)?,
-                _ => Err(eyre!("unknown key: {}", k))?,
+                _ => Err(eyre!("unknown key: {}", k)).suggestion(UPGRADE_SUGGESTION)?,
             }
         }
         self.doc = doc;
         Ok(())
     }
 
+    fn check_min_version(&self, v: &Item) -> Result<()> {
+        let min = match v.as_str() {
+            Some(min) => min,
+            None => return parse_error!("min_version", v, "string"),
+        };
+        let (Some(min_v), Some(current)) = (Versioning::new(min), Versioning::new(&RAW_VERSION))
+        else {
+            return Err(eyre!("invalid min_version: {min}"));
+        };
+        if current < min_v {
+            return Err(eyre!(
+                "{} requires rtx {min} or newer, this is rtx {}",
+                display_path(&self.path),
+                *RAW_VERSION
+            ))
+            .suggestion(UPGRADE_SUGGESTION);
+        }
+        Ok(())
+    }
+
+    fn parse_required_settings(&self, k: &str, v: &Item) -> Result<BTreeMap<String, String>> {
+        match v.as_table_like() {
+            Some(table) => table
+                .iter()
+                .map(|(key, v)| {
+                    let v = match v.as_value() {
+                        Some(Value::String(s)) => s.value().to_string(),
+                        Some(Value::Boolean(b)) => b.value().to_string(),
+                        Some(Value::Integer(i)) => i.value().to_string(),
+                        _ => parse_error!(format!("{k}.{key}"), v, "string, bool, or integer")?,
+                    };
+                    Ok((key.to_string(), v))
+                })
+                .collect(),
+            None => parse_error!(k, v, "table"),
+        }
+    }
+
     fn parse_env_file(&mut self, k: &str, v: &Item) -> Result<()> {
         self.trust_check()?;
         match v.as_str() {
@@ -111,30 +182,69 @@ impl RtxToml {
 
     fn parse_env(&mut self, key: &str, v: &Item) -> Result<()> {
         self.trust_check()?;
-        let mut v = v.clone();
-        if let Some(table) = v.as_table_like_mut() {
-            if table.contains_key("PATH") {
-                return Err(eyre!("use 'env_path' instead of 'env.PATH'"));
+        let table = match v.as_table_like() {
+            Some(table) => table,
+            None => return parse_error!(key, v, "table"),
+        };
+        self.parse_env_vars(key, table, true)?;
+        // [env.<profile>] sections override the base env in RTX_ENV order
+        for profile in self.profiles.clone().unwrap_or_default() {
+            if let Some(table) = table.get(&profile).and_then(|v| v.as_table()) {
+                self.parse_env_vars(&format!("{key}.{profile}"), table, false)?;
             }
         }
-        match v.as_table_like() {
-            Some(table) => {
-                for (k, v) in table.iter() {
-                    let key = format!("{}.{}", key, k);
-                    let k = self.parse_template(&key, k)?;
-                    if let Some(v) = v.as_str() {
-                        let v = self.parse_template(&key, v)?;
-                        self.env.insert(k, v);
-                    } else if let Some(v) = v.as_bool() {
-                        if !v {
-                            self.env_remove.push(k);
-                        }
-                    } else {
-                        parse_error!(key, v, "string or bool")?;
+        Ok(())
+    }
+
+    /// `[env.<profile>]` tables are skipped if `has_profiles`, they're parsed separately
+    fn parse_env_vars(
+        &mut self,
+        key: &str,
+        table: &dyn TableLike,
+        has_profiles: bool,
+    ) -> Result<()> {
+        if table.contains_key("PATH") {
+            return Err(eyre!("use 'env_path' instead of 'env.PATH'"));
+        }
+        for (k, v) in table.iter() {
+            let key = format!("{}.{}", key, k);
+            let v = match v.as_table_like() {
+                // e.g.: LDFLAGS = { value = "-L/opt/lib", os = "linux" }
+                Some(table) if table.contains_key("value") => {
+                    if let Some((k, _)) = table
+                        .iter()
+                        .find(|(k, _)| !matches!(*k, "value" | "os" | "arch" | "libc"))
+                    {
+                        return Err(eyre!(
+                            "unknown key {key}.{k}, expected value, os, arch, or libc"
+                        ));
+                    }
+                    if !self.parse_platform_conditions(&key, table)? {
+                        continue;
                     }
+                    table.get("value").unwrap()
+                }
+                // a profile section, only used if the profile is active
+                Some(_) if has_profiles && v.is_table() => continue,
+                _ => v,
+            };
+            let k = self.parse_template(&key, k)?;
+            if let Some(v) = v.as_str() {
+                match is_template(v) {
+                    true => self.env_templated.insert(k.clone()),
+                    false => self.env_templated.remove(&k),
+                };
+                let v = self.parse_template(&key, v)?;
+                self.env_remove.retain(|r| r != &k);
+                self.env.insert(k, v);
+            } else if let Some(v) = v.as_bool() {
+                if !v {
+                    self.env.remove(&k);
+                    self.env_remove.push(k);
                 }
+            } else {
+                parse_error!(key, v, "string or bool")?;
             }
-            _ => parse_error!(key, v, "table")?,
         }
         Ok(())
     }
@@ -198,44 +308,84 @@ impl RtxToml {
 
     fn parse_plugins(&self, key: &str, v: &Item) -> Result<HashMap<String, String>> {
         self.trust_check()?;
-        self.parse_hashmap(key, v)
+        let table = match v.as_table_like() {
+            Some(table) => table,
+            None => return parse_error!(key, v, "table"),
+        };
+        let mut plugins = HashMap::new();
+        for (plugin, v) in table.iter() {
+            let k = format!("{key}.{plugin}");
+            let url = match v.as_table_like() {
+                Some(table) => self.parse_plugin_table(&k, table)?,
+                None => match v.as_str() {
+                    Some(url) => self.parse_template(&k, url)?,
+                    None => parse_error!(k, v, "string or table")?,
+                },
+            };
+            plugins.insert(plugin.to_string(), url);
+        }
+        Ok(plugins)
     }
 
-    fn parse_hashmap(&self, key: &str, v: &Item) -> Result<HashMap<String, String>> {
-        match v.as_table_like() {
-            Some(table) => {
-                let mut env = HashMap::new();
-                for (k, v) in table.iter() {
-                    match v.as_str() {
-                        Some(s) => {
-                            let k = self.parse_template(key, k)?;
-                            let s = self.parse_template(key, s)?;
-                            env.insert(k, s);
-                        }
-                        _ => parse_error!(key, v, "string")?,
-                    }
+    /// `{ url = "...", ref = "v1.0.0" }` or `{ url = "...", sha = "<full commit sha>" }`
+    /// either way the pin is stored as `url#ref` like it would be from the cli
+    fn parse_plugin_table(&self, key: &str, table: &dyn TableLike) -> Result<String> {
+        let get = |k: &str| match table.get(k) {
+            Some(v) => match v.as_str() {
+                Some(s) => self.parse_template(key, s).map(Some),
+                None => parse_error!(format!("{key}.{k}"), v, "string"),
+            },
+            None => Ok(None),
+        };
+        for (k, _) in table.iter() {
+            if !matches!(k, "url" | "ref" | "sha") {
+                return Err(eyre!("unknown key {key}.{k}, expected url, ref, or sha"));
+            }
+        }
+        let url = get("url")?.ok_or_else(|| eyre!("{key}.url is required"))?;
+        match (get("ref")?, get("sha")?) {
+            (Some(_), Some(_)) => Err(eyre!("{key} can only have one of ref or sha")),
+            (Some(ref_), None) => Ok(format!("{url}#{ref_}")),
+            (None, Some(sha)) => {
+                if !Git::is_full_sha(&sha) {
+                    return Err(eyre!("{key}.sha must be a full 40 character commit sha"));
                 }
-                Ok(env)
+                Ok(format!("{url}#{sha}"))
             }
-            _ => parse_error!(key, v, "table"),
+            (None, None) => Ok(url),
         }
     }
 
     fn parse_toolset(&self, key: &str, v: &Item) -> Result<Toolset> {
         let mut toolset = Toolset::new(self.toolset.source.clone().unwrap());
 
-        match v.as_table_like() {
-            Some(table) => {
-                for (plugin, v) in table.iter() {
-                    let k = format!("{}.{}", key, plugin);
-                    let plugin_name = unalias_plugin(plugin).to_string();
-                    let tvl = self.parse_tool_version_list(&k, v, &plugin_name)?;
-                    toolset.versions.insert(plugin_name, tvl);
+        let table = match v.as_table_like() {
+            Some(table) => table,
+            None => return parse_error!(key, v, "table"),
+        };
+        let mut sections = vec![(key.to_string(), table)];
+        // [tools.<profile>] sections override the base tools in RTX_ENV order
+        for profile in self.profiles.iter().flatten() {
+            if let Some(table) = table.get(profile).filter(|v| is_profile_section(v)) {
+                sections.push((format!("{key}.{profile}"), table.as_table_like().unwrap()));
+            }
+        }
+        for (key, table) in sections {
+            for (plugin, v) in table.iter() {
+                if is_profile_section(v) {
+                    continue;
+                }
+                let k = format!("{}.{}", key, plugin);
+                let plugin_name = unalias_plugin(plugin).to_string();
+                let tvl = self.parse_tool_version_list(&k, v, &plugin_name)?;
+                if tvl.requests.is_empty() {
+                    // every version had conditions that don't match this machine
+                    continue;
                 }
-                Ok(toolset)
+                toolset.versions.insert(plugin_name, tvl);
             }
-            _ => parse_error!(key, v, "table")?,
         }
+        Ok(toolset)
     }
 
     fn parse_tool_version_list(
@@ -252,8 +402,9 @@ impl RtxToml {
                 for table in v.iter() {
                     for (tool, v) in table.iter() {
                         let k = format!("{}.{}", key, tool);
-                        let (tvr, opts) = self.parse_tool_version(&k, v, plugin_name)?;
-                        tool_version_list.requests.push((tvr, opts));
+                        if let Some(request) = self.parse_tool_version(&k, v, plugin_name)? {
+                            tool_version_list.requests.push(request);
+                        }
                     }
                 }
             }
@@ -261,16 +412,15 @@ impl RtxToml {
                 Some(v) => {
                     for v in v.iter() {
                         let item = Item::Value(v.clone());
-                        let (tvr, opts) = self.parse_tool_version(key, &item, plugin_name)?;
-                        tool_version_list.requests.push((tvr, opts));
+                        if let Some(request) = self.parse_tool_version(key, &item, plugin_name)? {
+                            tool_version_list.requests.push(request);
+                        }
                     }
                 }
                 _ => {
-                    tool_version_list.requests.push(self.parse_tool_version(
-                        key,
-                        v,
-                        plugin_name,
-                    )?);
+                    if let Some(request) = self.parse_tool_version(key, v, plugin_name)? {
+                        tool_version_list.requests.push(request);
+                    }
                 }
             },
         }
@@ -285,17 +435,21 @@ impl RtxToml {
         Ok(tool_version_list)
     }
 
+    /// None if the version has `os`, `arch`, or `libc` conditions that don't match this machine
     fn parse_tool_version(
         &self,
         key: &str,
         v: &Item,
         plugin_name: &PluginName,
-    ) -> Result<(ToolVersionRequest, ToolVersionOptions)> {
+    ) -> Result<Option<(ToolVersionRequest, ToolVersionOptions)>> {
         let mut tv = ToolVersionRequest::new(plugin_name.clone(), "system");
         let mut opts = ToolVersionOptions::default();
 
         match v.as_table_like() {
             Some(table) => {
+                if !self.parse_platform_conditions(key, table)? {
+                    return Ok(None);
+                }
                 if let Some(v) = table.get("version") {
                     match v {
                         Item::Value(v) => {
@@ -331,7 +485,10 @@ impl RtxToml {
                     parse_error!(key, v, "version, path, or prefix")?
                 }
                 for (k, v) in table.iter() {
-                    if k == "version" || k == "path" || k == "prefix" || k == "ref" {
+                    if matches!(
+                        k,
+                        "version" | "path" | "prefix" | "ref" | "os" | "arch" | "libc"
+                    ) {
                         continue;
                     }
                     let s = if let Some(s) = v.as_str() {
@@ -352,7 +509,35 @@ impl RtxToml {
             },
         }
 
-        Ok((tv, opts))
+        Ok(Some((tv, opts)))
+    }
+
+    /// checks `os`, `arch`, and `libc` conditions against this machine
+    /// e.g.: `{ version = "21", os = ["linux", "macos"], arch = "arm64", libc = "gnu" }`
+    fn parse_platform_conditions(&self, key: &str, table: &dyn TableLike) -> Result<bool> {
+        for (k, current) in [
+            ("os", Some(&*OS)),
+            ("arch", Some(&*ARCH)),
+            ("libc", LIBC.as_ref()),
+        ] {
+            let Some(v) = table.get(k) else {
+                continue;
+            };
+            let k = format!("{key}.{k}");
+            let allowed = match v.as_str() {
+                Some(s) => vec![s.to_string()],
+                None => self.parse_string_array(&k, v)?,
+            };
+            let matches = current.is_some_and(|current| {
+                allowed
+                    .iter()
+                    .any(|a| normalize_platform(a) == current.as_str())
+            });
+            if !matches {
+                return Ok(false);
+            }
+        }
+        Ok(true)
     }
 
     fn parse_tool_version_request(
@@ -539,7 +724,7 @@ impl RtxToml {
     }
 
     fn parse_template(&self, k: &str, input: &str) -> Result<String> {
-        if !input.contains("{{") && !input.contains("{%") && !input.contains("{#") {
+        if !is_template(input) {
             return Ok(input.to_string());
         }
         self.trust_check()?;
@@ -557,8 +742,15 @@ impl RtxToml {
             return Ok(());
         }
         if cmd != "hook-env" {
+            let reason = match config_file::trust_diff(&self.path) {
+                Some(diff) => {
+                    info!("{}", diff.trim_end());
+                    "needs to be trusted again"
+                }
+                None => "is not trusted",
+            };
             let ans = prompt::confirm(&format!(
-                "{} {} is not trusted. Trust it?",
+                "{} {} {reason}. Trust it?",
                 style("rtx").yellow().for_stderr(),
                 display_path(&self.path)
             ))?;
@@ -576,6 +768,116 @@ impl RtxToml {
     }
 }
 
+/// `tools.<name>.version` and `tools.<name>.path` for templates
+///
+/// config files are parsed before versions are resolved, so this uses what is installed
+/// and falls back to where the requested version would be installed. `path` is empty for
+/// ranges with no installed match. Only the tools in this file's `[tools]` are included,
+/// not the ones requested by parent config files.
+fn tools_context(toolset: &Toolset) -> BTreeMap<PluginName, BTreeMap<&'static str, String>> {
+    let mut tools = BTreeMap::new();
+    for (plugin_name, tvl) in &toolset.versions {
+        let Some((tvr, _)) = tvl.requests.first() else {
+            continue;
+        };
+        let installs = dirs::INSTALLS.join(plugin_name);
+        let path = match tvr {
+            ToolVersionRequest::System(_) => continue,
+            ToolVersionRequest::Path(_, p) => Some(p.clone()),
+            ToolVersionRequest::Ref(_, r) => Some(installs.join(format!("ref-{r}"))),
+            ToolVersionRequest::Range(_, r) => {
+                latest_installed_in_range(&installs, r).map(|v| installs.join(v))
+            }
+            // runtime symlinks like installs/node/20 point to the latest installed 20.x
+            _ => Some(installs.join(tvr.version())),
+        };
+        let path = path.map(|p| p.canonicalize().unwrap_or(p));
+        let version = match &path {
+            Some(p) if p.starts_with(&*dirs::INSTALLS) && p.exists() => {
+                p.file_name().unwrap().to_string_lossy().to_string()
+            }
+            _ => tvr.version(),
+        };
+        let path = path
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+        tools.insert(
+            plugin_name.clone(),
+            BTreeMap::from([("version", version), ("path", path)]),
+        );
+    }
+    tools
+}
+
+fn latest_installed_in_range(installs: &Path, range: &str) -> Option<String> {
+    let range = VersionRange::parse(range).ok()?;
+    file::dir_subdirs(installs)
+        .ok()?
+        .into_iter()
+        .filter(|v| range.matches(v))
+        .max_by_key(|v| Versioning::new(v))
+}
+
+/// accepts the names other tools use for the same os/arch
+fn normalize_platform(s: &str) -> &str {
+    match s {
+        "darwin" => "macos",
+        "x86_64" | "amd64" => "x64",
+        "aarch64" => "arm64",
+        s => s,
+    }
+}
+
+fn is_template(s: &str) -> bool {
+    s.contains("{{") || s.contains("{%") || s.contains("{#")
+}
+
+/// replaces `from` with `to` in a tool's string, array or table without touching its options
+fn bump_tool_version(item: &mut Item, from: &str, to: &str) {
+    fn bump_table(table: &mut dyn TableLike, from: &str, to: &str) {
+        let (key, from, to) = match from.strip_prefix("prefix:") {
+            Some(from) if table.contains_key("prefix") => {
+                ("prefix", from, to.trim_start_matches("prefix:"))
+            }
+            _ => ("version", from, to),
+        };
+        if let Some(v) = table.get_mut(key).and_then(|v| v.as_value_mut()) {
+            if v.as_str() == Some(from) {
+                replace_str(v, to);
+            }
+        }
+    }
+    fn replace_str(v: &mut Value, s: &str) {
+        let decor = v.decor().clone();
+        *v = s.into();
+        *v.decor_mut() = decor;
+    }
+    fn bump(v: &mut Value, from: &str, to: &str) {
+        match v {
+            Value::String(s) if s.value() == from => replace_str(v, to),
+            Value::Array(arr) => arr.iter_mut().for_each(|v| bump(v, from, to)),
+            Value::InlineTable(t) => bump_table(t, from, to),
+            _ => {}
+        }
+    }
+    match item {
+        Item::Value(v) => bump(v, from, to),
+        Item::Table(t) => bump_table(t, from, to),
+        Item::ArrayOfTables(arr) => arr.iter_mut().for_each(|t| bump_table(t, from, to)),
+        Item::None => {}
+    }
+}
+
+/// a table under [tools] that isn't a tool, e.g.: [tools.ci]
+fn is_profile_section(v: &Item) -> bool {
+    match v.as_table_like() {
+        Some(table) => !["version", "path", "prefix", "ref"]
+            .iter()
+            .any(|k| table.contains_key(k)),
+        None => false,
+    }
+}
+
 impl Display for RtxToml {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.dump())
@@ -603,6 +905,10 @@ impl ConfigFile for RtxToml {
         self.env_remove.clone()
     }
 
+    fn env_templated(&self) -> HashSet<String> {
+        self.env_templated.clone()
+    }
+
     fn path_dirs(&self) -> Vec<PathBuf> {
         self.path_dirs.clone()
     }
@@ -619,6 +925,34 @@ impl ConfigFile for RtxToml {
         }
     }
 
+    fn unpin_plugin(&mut self, plugin_name: &PluginName) -> bool {
+        let Some((url, Some(_))) = self
+            .plugins
+            .get(plugin_name)
+            .map(|u| Git::split_url_and_ref(u))
+        else {
+            return false;
+        };
+        let Some(plugin) = self
+            .doc
+            .get_mut("plugins")
+            .and_then(|p| p.as_table_like_mut())
+            .and_then(|p| p.get_mut(plugin_name))
+        else {
+            return false;
+        };
+        match plugin.as_table_like_mut() {
+            Some(table) => {
+                table.remove("ref");
+                table.remove("sha");
+                table.fmt();
+            }
+            None => *plugin = value(url.clone()),
+        }
+        self.plugins.insert(plugin_name.clone(), url);
+        true
+    }
+
     fn replace_versions(&mut self, plugin_name: &PluginName, versions: &[String]) {
         if let Some(plugin) = self.toolset.versions.get_mut(plugin_name) {
             plugin.requests = versions
@@ -649,6 +983,38 @@ impl ConfigFile for RtxToml {
         }
     }
 
+    fn bump_version(&mut self, plugin_name: &PluginName, from: &str, to: &str) {
+        let Some(tools) = self
+            .doc
+            .get_mut("tools")
+            .and_then(|t| t.as_table_like_mut())
+        else {
+            return;
+        };
+        let has_tool = |table: &dyn TableLike| {
+            table
+                .iter()
+                .any(|(k, v)| unalias_plugin(k) == plugin_name && !is_profile_section(v))
+        };
+        // the request comes from the last active [tools.<profile>] section with the tool
+        let profile = self.profiles.iter().flatten().rev().find(|p| {
+            tools
+                .get(p)
+                .filter(|v| is_profile_section(v))
+                .and_then(|v| v.as_table_like())
+                .is_some_and(has_tool)
+        });
+        let table = match profile {
+            Some(p) => tools.get_mut(p).unwrap().as_table_like_mut().unwrap(),
+            None => tools,
+        };
+        for (k, v) in table.iter_mut() {
+            if unalias_plugin(&k) == plugin_name && !is_profile_section(v) {
+                bump_tool_version(v, from, to);
+            }
+        }
+    }
+
     fn save(&self) -> Result<()> {
         let contents = self.dump();
         if let Some(parent) = self.path.parent() {
@@ -665,6 +1031,10 @@ impl ConfigFile for RtxToml {
         &self.toolset
     }
 
+    fn required_settings(&self) -> BTreeMap<String, String> {
+        self.required_settings.clone()
+    }
+
     fn settings(&self) -> Result<SettingsPartial> {
         let mut s = SettingsPartial::empty();
 
@@ -673,6 +1043,9 @@ impl ConfigFile for RtxToml {
             match config_key.to_lowercase().as_str() {
                 "experimental" => s.experimental = Some(self.parse_bool(&k, v)?),
                 "legacy_version_file" => s.legacy_version_file = Some(self.parse_bool(&k, v)?),
+                "legacy_version_file_manifests" => {
+                    s.legacy_version_file_manifests = Some(self.parse_bool(&k, v)?)
+                }
                 "legacy_version_file_disable_tools" => {
                     s.legacy_version_file_disable_tools =
                         Some(self.parse_string_array(&k, v)?.into_iter().collect())
@@ -695,12 +1068,20 @@ impl ConfigFile for RtxToml {
                 "disable_default_shorthands" => {
                     s.disable_default_shorthands = Some(self.parse_bool(&k, v)?)
                 }
+                "plugin_registry_url" => s.plugin_registry_url = Some(self.parse_string(&k, v)?),
                 "disable_tools" => {
                     s.disable_tools = Some(self.parse_string_array(&k, v)?.into_iter().collect());
                 }
                 "raw" => s.raw = Some(self.parse_bool(&k, v)?),
                 "yes" => s.yes = Some(self.parse_bool(&k, v)?),
-                _ => Err(eyre!("Unknown config setting: {}", k))?,
+                "missing_runtime_behavior" => {
+                    let v = self.parse_string(&k, v)?;
+                    let v = serde_json::from_value(serde_json::Value::String(v))
+                        .wrap_err_with(|| eyre!("invalid value for {k}"))?;
+                    s.missing_runtime_behavior = Some(v);
+                }
+                "plugin_sandbox" => s.plugin_sandbox = Some(self.parse_bool(&k, v)?),
+                _ => Err(eyre!("Unknown config setting: {}", k)).suggestion(UPGRADE_SUGGESTION)?,
             };
         }
 
@@ -755,6 +1136,7 @@ impl Debug for RtxToml {
 #[cfg(test)]
 mod tests {
     use insta::{assert_debug_snapshot, assert_display_snapshot, assert_snapshot};
+    use itertools::Itertools;
 
     use crate::dirs;
     use crate::test::replace_path;
@@ -796,6 +1178,181 @@ mod tests {
         assert_display_snapshot!(cf);
     }
 
+    #[test]
+    fn test_profiles() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.profiles = Some(vec!["ci".into(), "linux".into()]);
+        cf.parse(&formatdoc! {r#"
+        [env]
+        FOO = "base"
+        BAR = "base"
+        [env.ci]
+        FOO = "ci"
+        BAZ = false
+        [env.linux]
+        BAR = "linux"
+        [env.staging]
+        FOO = "staging"
+
+        [tools]
+        node = "18"
+        python = {{ version = "3.11" }}
+        [tools.ci]
+        node = "20"
+        [tools.staging]
+        python = "3.12"
+        "#})
+            .unwrap();
+
+        let env = cf.env();
+        assert_eq!(env.len(), 2);
+        assert_eq!(env["FOO"], "ci");
+        assert_eq!(env["BAR"], "linux");
+        assert_eq!(cf.env_remove(), vec!["BAZ"]);
+        let versions = cf
+            .to_toolset()
+            .versions
+            .values()
+            .map(|tvl| format!("{}@{}", tvl.plugin_name, tvl.requests[0].0.version()))
+            .collect::<Vec<_>>();
+        assert_eq!(versions, vec!["node@20", "python@3.11"]);
+    }
+
+    #[test]
+    fn test_bump_version() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.profiles = Some(vec!["ci".into()]);
+        cf.parse(&formatdoc! {r#"
+        [tools]
+        node = "20.9.0" # lts
+        python = {{ version = "3.11.1", virtualenv = ".venv" }}
+        java = [{{ version = "21.0.1", arch = "arm64" }}, "21.0.1"]
+        go = {{ prefix = "1.20" }}
+        [tools.ci]
+        node = ["20.9.0", "18"]
+        "#})
+            .unwrap();
+        cf.bump_version(&"node".into(), "20.9.0", "20.11.1");
+        cf.bump_version(&"python".into(), "3.11.1", "3.12.1");
+        cf.bump_version(&"java".into(), "21.0.1", "21.0.2");
+        cf.bump_version(&"go".into(), "prefix:1.20", "prefix:1.21");
+        assert_snapshot!(cf.dump(), @r###"
+        [tools]
+        node = "20.9.0" # lts
+        python = { version = "3.12.1", virtualenv = ".venv" }
+        java = [{ version = "21.0.2", arch = "arm64" }, "21.0.2"]
+        go = { prefix = "1.21" }
+        [tools.ci]
+        node = ["20.11.1", "18"]
+        "###);
+    }
+
+    #[test]
+    fn test_profiles_disabled() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [env]
+        FOO = "base"
+        [env.ci]
+        FOO = "ci"
+        [tools]
+        node = "18"
+        [tools.ci]
+        node = "20"
+        "#})
+            .unwrap();
+
+        assert_eq!(cf.env()["FOO"], "base");
+        let versions = cf
+            .to_toolset()
+            .versions
+            .values()
+            .map(|tvl| format!("{}@{}", tvl.plugin_name, tvl.requests[0].0.version()))
+            .collect::<Vec<_>>();
+        assert_eq!(versions, vec!["node@18"]);
+    }
+
+    #[test]
+    fn test_env_invalid_table() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        let err = cf
+            .parse(&formatdoc! {r#"
+            [env]
+            FOO = {{ vaule = "typo" }}
+            "#})
+            .unwrap_err();
+        assert!(err.to_string().contains("string or bool"), "{err}");
+
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.profiles = Some(vec!["ci".into()]);
+        let err = cf
+            .parse(&formatdoc! {r#"
+            [env.ci.nested]
+            FOO = "ci"
+            "#})
+            .unwrap_err();
+        assert!(err.to_string().contains("string or bool"), "{err}");
+    }
+
+    #[test]
+    fn test_platform_conditions() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [env]
+        CURRENT = {{ value = "yes", os = "{os}", arch = ["{arch}", "other"] }}
+        OTHER = {{ value = "yes", os = "plan9" }}
+        ARCH = "{{{{ os() }}}}-{{{{ arch() }}}}"
+
+        [tools]
+        node = [{{ version = "20", arch = "other" }}, {{ version = "18", os = "{os}" }}]
+        python = {{ version = "3.11", os = "plan9" }}
+        "#, os = *OS, arch = *ARCH})
+            .unwrap();
+
+        let env = cf.env();
+        assert_eq!(env["CURRENT"], "yes");
+        assert!(!env.contains_key("OTHER"));
+        assert_eq!(env["ARCH"], format!("{}-{}", *OS, *ARCH));
+        let ts = cf.to_toolset();
+        assert_eq!(ts.versions.len(), 1);
+        let requests = &ts.versions["node"].requests;
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].0.version(), "18");
+        assert!(requests[0].1.is_empty());
+    }
+
+    #[test]
+    fn test_tools_context() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [env]
+        TINY_PATH = "{{{{ tools.tiny.path }}}}"
+        TINY_VERSION = "{{{{ tools.tiny.version }}}}"
+        [tools]
+        tiny = "3"
+        "#})
+            .unwrap();
+
+        let env = cf.env();
+        let installs = dirs::INSTALLS.join("tiny/3").to_string_lossy().to_string();
+        assert!(env["TINY_PATH"].starts_with(&installs));
+        assert!(env["TINY_VERSION"].starts_with('3'));
+
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [env]
+        TINY_PATH = "{{{{ tools.tiny.path }}}}"
+        TINY_VERSION = "{{{{ tools.tiny.version }}}}"
+        [tools]
+        tiny = ">=9 <10"
+        "#})
+            .unwrap();
+        // nothing installed matches the range
+        let env = cf.env();
+        assert_eq!(env["TINY_PATH"], "");
+        assert_eq!(env["TINY_VERSION"], ">=9 <10");
+    }
+
     #[test]
     fn test_path_dirs() {
         let p = dirs::HOME.join("fixtures/.rtx.toml");
@@ -926,4 +1483,103 @@ mod tests {
             .unwrap_err();
         assert_snapshot!(err.to_string(), @"unknown key: invalid_key");
     }
+
+    #[test]
+    fn test_plugins() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [plugins]
+        node = "https://github.com/rtx-plugins/rtx-nodejs"
+        tiny = {{ url = "https://github.com/rtx-plugins/rtx-tiny", ref = "v1.0.0" }}
+        [plugins.python]
+        url = "https://github.com/rtx-plugins/rtx-python"
+        sha = "0123456789abcdef0123456789abcdef01234567"
+        "#})
+            .unwrap();
+        assert_debug_snapshot!(cf.plugins().into_iter().sorted().collect_vec(), @r###"
+        [
+            (
+                "node",
+                "https://github.com/rtx-plugins/rtx-nodejs",
+            ),
+            (
+                "python",
+                "https://github.com/rtx-plugins/rtx-python#0123456789abcdef0123456789abcdef01234567",
+            ),
+            (
+                "tiny",
+                "https://github.com/rtx-plugins/rtx-tiny#v1.0.0",
+            ),
+        ]
+        "###);
+
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        let err = cf
+            .parse(&formatdoc! {r#"
+        [plugins]
+        tiny = {{ url = "https://github.com/rtx-plugins/rtx-tiny", sha = "abc123" }}
+        "#})
+            .unwrap_err();
+        assert_snapshot!(err.to_string(), @"plugins.tiny.sha must be a full 40 character commit sha");
+    }
+
+    #[test]
+    fn test_unpin_plugin() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [plugins]
+        node = "https://github.com/rtx-plugins/rtx-nodejs#v1.0.0"
+        tiny = {{ url = "https://github.com/rtx-plugins/rtx-tiny", ref = "v1.0.0" }}
+        python = "https://github.com/rtx-plugins/rtx-python"
+        "#})
+            .unwrap();
+        assert!(cf.unpin_plugin(&"node".into()));
+        assert!(cf.unpin_plugin(&"tiny".into()));
+        assert!(!cf.unpin_plugin(&"python".into()));
+        assert_eq!(
+            cf.plugins()["tiny"],
+            "https://github.com/rtx-plugins/rtx-tiny"
+        );
+        assert_snapshot!(cf.dump(), @r###"
+        [plugins]
+        node = "https://github.com/rtx-plugins/rtx-nodejs"
+        tiny = { url = "https://github.com/rtx-plugins/rtx-tiny" }
+        python = "https://github.com/rtx-plugins/rtx-python"
+        "###);
+    }
+
+    #[test]
+    fn test_min_version() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        min_version = "2023.1.0"
+        "#})
+            .unwrap();
+        let err = cf
+            .parse(&formatdoc! {r#"
+        min_version = "9999.1.0"
+        invalid_key = true
+        "#})
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("/tmp/.rtx.toml requires rtx 9999.1.0 or newer"));
+    }
+
+    #[test]
+    fn test_required_settings() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [required_settings]
+        experimental = true
+        jobs = 4
+        "#})
+            .unwrap();
+        assert_debug_snapshot!(cf.required_settings(), @r###"
+        {
+            "experimental": "true",
+            "jobs": "4",
+        }
+        "###);
+    }
 }
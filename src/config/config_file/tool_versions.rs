@@ -172,6 +172,14 @@ impl ConfigFile for ToolVersions {
         }
     }
 
+    fn bump_version(&mut self, plugin_name: &PluginName, from: &str, to: &str) {
+        if let Some(tvp) = self.plugins.get_mut(plugin_name) {
+            for v in tvp.versions.iter_mut().filter(|v| *v == from) {
+                *v = to.to_string();
+            }
+        }
+    }
+
     fn save(&self) -> Result<()> {
         let s = self.dump();
         file::write(&self.path, s)
@@ -4,14 +4,14 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::thread;
 
-use eyre::Context;
-use eyre::Result;
+use color_eyre::Section;
+use eyre::{eyre, Context, Result};
 use indexmap::IndexMap;
 use itertools::Itertools;
 use once_cell::sync::OnceCell;
 use rayon::prelude::*;
 
-pub use settings::Settings;
+pub use settings::{MissingRuntimeBehavior, Settings};
 
 use crate::config::config_file::legacy_version::LegacyVersionFile;
 use crate::config::config_file::rtx_toml::RtxToml;
@@ -19,14 +19,16 @@ use crate::config::config_file::{ConfigFile, ConfigFileType};
 
 use crate::config::tracking::Tracker;
 use crate::file::display_path;
+use crate::git::Git;
 use crate::plugins::core::{PluginMap, CORE_PLUGINS, EXPERIMENTAL_CORE_PLUGINS};
 use crate::plugins::{ExternalPlugin, Plugin, PluginName, PluginType};
-use crate::shorthands::{get_shorthands, Shorthands};
+use crate::shorthands::{get_registry, get_shorthands, Registry, Shorthands};
+use crate::toolset::ToolVersion;
 use crate::{dirs, env, file, hook_env};
 
 pub mod config_file;
 mod settings;
-mod tracking;
+pub mod tracking;
 
 type AliasMap = BTreeMap<PluginName, BTreeMap<String, String>>;
 type ConfigMap = IndexMap<PathBuf, Box<dyn ConfigFile>>;
@@ -45,18 +47,28 @@ pub struct Config {
     pub all_aliases: OnceCell<AliasMap>,
     pub should_exit_early: bool,
     pub project_root: Option<PathBuf>,
+    registry: OnceCell<Registry>,
     shorthands: OnceCell<HashMap<String, String>>,
     repo_urls: HashMap<PluginName, String>,
 }
 
 impl Config {
     pub fn load() -> Result<Self> {
+        Self::load_with_profiles(None)
+    }
+
+    /// loads config with `profiles` instead of the ones in RTX_ENV
+    pub fn load_with_profiles(profiles: Option<Vec<String>>) -> Result<Self> {
         let global_config = load_rtxrc()?;
-        let settings = Settings::default_builder()
+        let mut settings = Settings::default_builder()
             .preloaded(global_config.settings()?)
             .load()?;
-        let config_filenames = load_config_filenames(&settings, &BTreeMap::new());
+        if let Some(profiles) = &profiles {
+            settings.profiles = profiles.clone();
+        }
         let plugins = load_plugins(&settings)?;
+        let config_filenames = load_config_filenames(&settings, &plugins, &BTreeMap::new());
+        let parsed_profiles = settings.active_profiles().map(|p| p.to_vec());
         let config_files = load_all_config_files(
             &settings,
             &config_filenames,
@@ -68,13 +80,22 @@ impl Config {
         for cf in config_files.values() {
             settings = settings.preloaded(cf.settings()?);
         }
-        let settings = settings.load()?;
+        let mut settings = settings.load()?;
+        if let Some(profiles) = profiles {
+            settings.profiles = profiles;
+        }
         trace!("Settings: {:#?}", settings);
+        check_required_settings(&settings, &config_files)?;
 
         let legacy_files = load_legacy_files(&settings, &plugins);
-        let config_filenames = load_config_filenames(&settings, &legacy_files);
+        let config_filenames = load_config_filenames(&settings, &plugins, &legacy_files);
         let config_track = track_config_files(&config_filenames);
 
+        // experimental may have been enabled by a config file which changes how profiles are parsed
+        let config_files = match settings.active_profiles() == parsed_profiles.as_deref() {
+            true => config_files,
+            false => ConfigMap::new(),
+        };
         let config_files = load_all_config_files(
             &settings,
             &config_filenames,
@@ -95,12 +116,17 @@ impl Config {
                 repo_urls.insert(plugin_name, repo_url);
             }
         }
+        for (plugin_name, plugin) in &plugins {
+            if let Some(url) = repo_urls.get(plugin_name) {
+                plugin.set_pinned_ref(Git::split_url_and_ref(url).1);
+            }
+        }
         config_track.join().unwrap();
 
         let (env, env_sources) = load_env(&config_files);
 
-        if !settings.experimental && env::RTX_ENV.is_some() {
-            warn!("RTX_ENV is set but RTX_EXPERIMENTAL is not. Ignoring RTX_ENV.");
+        if !settings.experimental && !settings.profiles.is_empty() {
+            warn!("RTX_ENV is set but RTX_EXPERIMENTAL is not. Ignoring .rtx.<env>.toml files and [env.<env>] and [tools.<env>] sections.");
         }
 
         let config = Self {
@@ -109,6 +135,7 @@ impl Config {
             path_dirs: load_path_dirs(&config_files),
             aliases: load_aliases(&config_files),
             all_aliases: OnceCell::new(),
+            registry: OnceCell::new(),
             shorthands: OnceCell::new(),
             project_root: get_project_root(&config_files),
             config_files,
@@ -124,9 +151,13 @@ impl Config {
         Ok(config)
     }
 
+    pub fn get_registry(&self) -> &Registry {
+        self.registry.get_or_init(|| get_registry(&self.settings))
+    }
+
     pub fn get_shorthands(&self) -> &Shorthands {
         self.shorthands
-            .get_or_init(|| get_shorthands(&self.settings))
+            .get_or_init(|| get_shorthands(self.get_registry()))
     }
 
     pub fn get_repo_url(&self, plugin_name: &PluginName) -> Option<String> {
@@ -172,9 +203,16 @@ impl Config {
     }
 
     pub fn get_or_create_plugin(&mut self, plugin_name: &PluginName) -> Arc<dyn Plugin> {
+        let repo_urls = &self.repo_urls;
         self.plugins
             .entry(plugin_name.clone())
-            .or_insert_with(|| ExternalPlugin::newa(plugin_name.clone()))
+            .or_insert_with(|| {
+                let plugin = ExternalPlugin::newa(plugin_name.clone());
+                if let Some(url) = repo_urls.get(plugin_name) {
+                    plugin.set_pinned_ref(Git::split_url_and_ref(url).1);
+                }
+                plugin
+            })
             .clone()
     }
 
@@ -235,6 +273,54 @@ impl Config {
         Ok(config_files)
     }
 
+    /// the versions used by each tracked config file
+    pub fn get_tracked_versions(&mut self) -> Result<Vec<(PathBuf, ToolVersion)>> {
+        let config_files = self.get_tracked_config_files()?;
+        Ok(self.resolve_config_versions(config_files))
+    }
+
+    /// names of files that are read as config if they're in the current directory or above it
+    pub fn config_filenames(&self) -> Vec<String> {
+        let legacy_files = load_legacy_files(&self.settings, &self.plugins);
+        config_filenames(&self.settings, &self.plugins, &legacy_files)
+    }
+
+    /// config files in the project root and every directory under it
+    /// directories ignored by .gitignore are skipped
+    pub fn get_project_config_files(&self) -> Result<ConfigMap> {
+        let root = self
+            .project_root
+            .clone()
+            .unwrap_or_else(|| dirs::CURRENT.clone());
+        let legacy_files = load_legacy_files(&self.settings, &self.plugins);
+        let filenames = config_filenames(&self.settings, &self.plugins, &legacy_files);
+        load_all_config_files(
+            &self.settings,
+            &file::find_down(&root, &filenames),
+            &self.plugins,
+            &legacy_files,
+            ConfigMap::new(),
+        )
+    }
+
+    /// the versions used by each config file under the project root
+    pub fn get_project_versions(&mut self) -> Result<Vec<(PathBuf, ToolVersion)>> {
+        let config_files = self.get_project_config_files()?;
+        Ok(self.resolve_config_versions(config_files))
+    }
+
+    fn resolve_config_versions(&mut self, config_files: ConfigMap) -> Vec<(PathBuf, ToolVersion)> {
+        let mut versions = vec![];
+        for (path, cf) in config_files {
+            let mut ts = cf.to_toolset().clone();
+            ts.resolve(self);
+            for (_, tv) in ts.list_current_versions(self) {
+                versions.push((path.clone(), tv));
+            }
+        }
+        versions
+    }
+
     pub fn rebuild_shims_and_runtime_symlinks(&mut self) -> Result<()> {
         let ts = crate::toolset::ToolsetBuilder::new().build(self)?;
         crate::shims::reshim(self, &ts)?;
@@ -243,6 +329,26 @@ impl Config {
     }
 }
 
+fn check_required_settings(settings: &Settings, config_files: &ConfigMap) -> Result<()> {
+    let current = settings.to_index_map();
+    for (path, cf) in config_files {
+        for (k, expected) in cf.required_settings() {
+            match current.get(&k) {
+                Some(actual) if actual == &expected => {}
+                Some(actual) => {
+                    return Err(eyre!(
+                        "{} requires setting {k} = {expected}, but it is {actual}",
+                        display_path(path)
+                    ))
+                    .suggestion(format!("run `rtx settings set {k} {expected}`"));
+                }
+                None => return Err(eyre!("{} requires unknown setting {k}", display_path(path))),
+            }
+        }
+    }
+    Ok(())
+}
+
 fn get_project_root(config_files: &ConfigMap) -> Option<PathBuf> {
     for (p, cf) in config_files.into_iter() {
         if p == &get_global_rtx_toml() {
@@ -304,12 +410,17 @@ fn load_legacy_files(settings: &Settings, tools: &PluginMap) -> BTreeMap<String,
                 .contains(tool.name())
         })
         .filter_map(|tool| match tool.legacy_filenames(settings) {
-            Ok(filenames) => Some(
-                filenames
-                    .iter()
-                    .map(|f| (f.to_string(), tool.name().to_string()))
-                    .collect_vec(),
-            ),
+            Ok(mut filenames) => {
+                if settings.legacy_version_file_manifests {
+                    filenames.extend(tool.manifest_filenames());
+                }
+                Some(
+                    filenames
+                        .iter()
+                        .map(|f| (f.to_string(), tool.name().to_string()))
+                        .collect_vec(),
+                )
+            }
             Err(err) => {
                 eprintln!("Error: {err}");
                 None
@@ -332,19 +443,10 @@ fn load_legacy_files(settings: &Settings, tools: &PluginMap) -> BTreeMap<String,
 
 fn load_config_filenames(
     settings: &Settings,
+    plugins: &PluginMap,
     legacy_filenames: &BTreeMap<String, Vec<PluginName>>,
 ) -> Vec<PathBuf> {
-    let mut filenames = legacy_filenames.keys().cloned().collect_vec();
-    filenames.push(env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.clone());
-    filenames.push(env::RTX_DEFAULT_CONFIG_FILENAME.clone());
-    if settings.experimental && *env::RTX_DEFAULT_CONFIG_FILENAME == ".rtx.toml" {
-        filenames.push(".rtx.local.toml".to_string());
-        if let Some(env) = &*env::RTX_ENV {
-            filenames.push(format!(".rtx.{}.toml", env));
-            filenames.push(format!(".rtx.{}.local.toml", env));
-        }
-    }
-
+    let filenames = config_filenames(settings, plugins, legacy_filenames);
     let mut config_files = file::FindUp::new(&dirs::CURRENT, &filenames).collect::<Vec<_>>();
 
     for cf in global_config_files() {
@@ -354,6 +456,33 @@ fn load_config_filenames(
     config_files.into_iter().unique().collect()
 }
 
+fn config_filenames(
+    settings: &Settings,
+    plugins: &PluginMap,
+    legacy_filenames: &BTreeMap<String, Vec<PluginName>>,
+) -> Vec<String> {
+    let is_manifest = |filename: &String| {
+        legacy_filenames[filename]
+            .iter()
+            .filter_map(|p| plugins.get(p))
+            .any(|p| p.manifest_filenames().contains(filename))
+    };
+    let mut filenames = legacy_filenames.keys().cloned().collect_vec();
+    // later filenames take precedence so manifests like package.json or go.mod
+    // go first to be overridden by files like .nvmrc or .go-version
+    filenames.sort_by_key(|f| !is_manifest(f));
+    filenames.push(env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.clone());
+    filenames.push(env::RTX_DEFAULT_CONFIG_FILENAME.clone());
+    if settings.experimental && *env::RTX_DEFAULT_CONFIG_FILENAME == ".rtx.toml" {
+        filenames.push(".rtx.local.toml".to_string());
+        for profile in &settings.profiles {
+            filenames.push(format!(".rtx.{}.toml", profile));
+            filenames.push(format!(".rtx.{}.local.toml", profile));
+        }
+    }
+    filenames
+}
+
 fn get_global_rtx_toml() -> PathBuf {
     match env::RTX_CONFIG_FILE.clone() {
         Some(global) => global,
@@ -511,4 +640,42 @@ mod tests {
         let config = Config::load().unwrap();
         assert_display_snapshot!(config);
     }
+
+    #[test]
+    fn test_config_filenames() {
+        let mut settings = Settings::default_builder().load().unwrap();
+        let plugins = load_plugins(&settings).unwrap();
+        let filenames =
+            config_filenames(&settings, &plugins, &load_legacy_files(&settings, &plugins));
+        assert!(!filenames.contains(&"package.json".to_string()));
+
+        settings.legacy_version_file_manifests = true;
+        let filenames =
+            config_filenames(&settings, &plugins, &load_legacy_files(&settings, &plugins));
+        let filenames = filenames
+            .iter()
+            .filter(|f| {
+                [
+                    ".nvmrc",
+                    ".ruby-version",
+                    "Gemfile",
+                    "Gemfile.lock",
+                    "go.mod",
+                    "package.json",
+                ]
+                .contains(&f.as_str())
+            })
+            .collect_vec();
+        assert_eq!(
+            filenames,
+            [
+                "Gemfile.lock",
+                "go.mod",
+                "package.json",
+                ".nvmrc",
+                ".ruby-version",
+                "Gemfile"
+            ]
+        );
+    }
 }
@@ -21,6 +21,8 @@ pub struct Settings {
     pub legacy_version_file: bool,
     #[config(env = "RTX_LEGACY_VERSION_FILE_DISABLE_TOOLS", default = [], parse_env = list_by_comma)]
     pub legacy_version_file_disable_tools: BTreeSet<String>,
+    #[config(env = "RTX_LEGACY_VERSION_FILE_MANIFESTS", default = false)]
+    pub legacy_version_file_manifests: bool,
     #[config(env = "RTX_PLUGIN_AUTOUPDATE_LAST_CHECK_DURATION", default = "7d")]
     pub plugin_autoupdate_last_check_duration: String,
     #[config(env = "RTX_TRUSTED_CONFIG_PATHS", default = [], parse_env = list_by_colon)]
@@ -35,12 +37,21 @@ pub struct Settings {
     pub shorthands_file: Option<PathBuf>,
     #[config(env = "RTX_DISABLE_DEFAULT_SHORTHANDS", default = false)]
     pub disable_default_shorthands: bool,
+    #[config(env = "RTX_PLUGIN_REGISTRY_URL")]
+    pub plugin_registry_url: Option<String>,
     #[config(env = "RTX_DISABLE_TOOLS", default = [], parse_env = list_by_comma)]
     pub disable_tools: BTreeSet<String>,
     #[config(env = "RTX_RAW", default = false)]
     pub raw: bool,
     #[config(env = "RTX_YES", default = false)]
     pub yes: bool,
+    #[config(env = "RTX_MISSING_RUNTIME_BEHAVIOR", default = "warn")]
+    pub missing_runtime_behavior: MissingRuntimeBehavior,
+    #[config(env = "RTX_PLUGIN_SANDBOX", default = false)]
+    pub plugin_sandbox: bool,
+    /// profiles from RTX_ENV, see `Settings::active_profiles`
+    #[config(default = [])]
+    pub profiles: Vec<String>,
 }
 
 pub type SettingsPartial = <Settings as Config>::Partial;
@@ -59,9 +70,16 @@ impl Settings {
         if *env::RTX_LOG_LEVEL < LevelFilter::Info {
             p.verbose = Some(true);
         }
+        p.profiles = Some(env::profiles());
         Self::builder().preloaded(p).env()
     }
 
+    /// profiles used for .rtx.<profile>.toml files and [env.<profile>] and [tools.<profile>]
+    /// sections, None unless experimental is enabled
+    pub fn active_profiles(&self) -> Option<&[String]> {
+        self.experimental.then_some(self.profiles.as_slice())
+    }
+
     pub fn to_index_map(&self) -> BTreeMap<String, String> {
         let mut map = BTreeMap::new();
         map.insert("experimental".to_string(), self.experimental.to_string());
@@ -86,6 +104,10 @@ impl Settings {
                     .collect::<Vec<_>>()
             ),
         );
+        map.insert(
+            "legacy_version_file_manifests".to_string(),
+            self.legacy_version_file_manifests.to_string(),
+        );
         map.insert(
             "plugin_autoupdate_last_check_duration".to_string(),
             self.plugin_autoupdate_last_check_duration.to_string(),
@@ -107,12 +129,20 @@ impl Settings {
             "disable_default_shorthands".into(),
             self.disable_default_shorthands.to_string(),
         );
+        if let Some(plugin_registry_url) = &self.plugin_registry_url {
+            map.insert("plugin_registry_url".into(), plugin_registry_url.clone());
+        }
         map.insert(
             "disable_tools".into(),
             format!("{:?}", self.disable_tools.iter().collect::<Vec<_>>()),
         );
         map.insert("raw".into(), self.raw.to_string());
         map.insert("yes".into(), self.yes.to_string());
+        map.insert(
+            "missing_runtime_behavior".into(),
+            self.missing_runtime_behavior.to_string(),
+        );
+        map.insert("plugin_sandbox".into(), self.plugin_sandbox.to_string());
         map
     }
 }
@@ -126,8 +156,11 @@ impl Display for Settings {
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum MissingRuntimeBehavior {
+    /// install missing versions without asking
     AutoInstall,
+    /// ask before installing missing versions
     Prompt,
+    /// show a warning in hook-env, shims still fail
     Warn,
     Ignore,
 }
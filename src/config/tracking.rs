@@ -36,7 +36,6 @@ impl Tracker {
     }
 
     pub fn list_all(&self) -> Result<Vec<PathBuf>> {
-        self.clean()?;
         let mut output = vec![];
         for path in read_dir(&self.tracking_dir)? {
             let path = path?.path();
@@ -51,13 +50,37 @@ impl Tracker {
         Ok(output)
     }
 
-    pub fn clean(&self) -> Result<()> {
+    /// tracked config files that no longer exist
+    pub fn list_missing(&self) -> Result<Vec<PathBuf>> {
+        Ok(self
+            .missing()?
+            .into_iter()
+            .filter_map(|link| fs::read_link(link).ok())
+            .collect())
+    }
+
+    /// removes tracked config files that no longer exist, returns their paths
+    pub fn clean(&self) -> Result<Vec<PathBuf>> {
+        let mut removed = vec![];
+        for link in self.missing()? {
+            removed.extend(fs::read_link(&link).ok());
+            remove_file(&link)?;
+        }
+        Ok(removed)
+    }
+
+    /// links in the tracking dir whose config file no longer exists
+    fn missing(&self) -> Result<Vec<PathBuf>> {
+        let mut missing = vec![];
+        if !self.tracking_dir.exists() {
+            return Ok(missing);
+        }
         for path in read_dir(&self.tracking_dir)? {
             let path = path?.path();
             if !path.exists() {
-                remove_file(&path)?;
+                missing.push(path);
             }
         }
-        Ok(())
+        Ok(missing)
     }
 }
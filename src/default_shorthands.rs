@@ -1,743 +0,0 @@
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// !GENERATED FILE!
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// !GENERATED FILE!
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// !GENERATED FILE!
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// This file is generated by scripts/update-shorthand-repo.sh
-// DO NOT EDIT THIS FILE MANUALLY. YOUR PR WILL BE REJECTED.
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// !GENERATED FILE!
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// !GENERATED FILE!
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// !GENERATED FILE!
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-
-use once_cell::sync::Lazy;
-use std::collections::HashMap;
-
-pub static DEFAULT_SHORTHANDS: Lazy<HashMap<&'static str, &'static str>> =
-    Lazy::new(|| HashMap::from(DEFAULT_SHORTHAND_LIST));
-
-#[rustfmt::skip]
-#[cfg_attr(coverage_nightly, no_coverage)]
-const DEFAULT_SHORTHAND_LIST: [(&str, &str); 714] = [
-    // asdf original shorthands from https://github.com/asdf-vm/asdf-plugins
-    ("1password-cli", "https://github.com/NeoHsu/asdf-1password-cli.git"),
-    ("R", "https://github.com/asdf-community/asdf-r.git"),
-    ("act", "https://github.com/grimoh/asdf-act.git"),
-    ("action-validator", "https://github.com/mpalmer/action-validator.git"),
-    ("actionlint", "https://github.com/crazy-matt/asdf-actionlint.git"),
-    ("adr-tools", "https://gitlab.com/td7x/asdf/adr-tools.git"),
-    ("ag", "https://github.com/koketani/asdf-ag.git"),
-    ("age", "https://github.com/threkk/asdf-age"),
-    ("age-plugin-yubikey", "https://github.com/joke/asdf-age-plugin-yubikey"),
-    ("agebox", "https://github.com/slok/asdf-agebox.git"),
-    ("air", "https://github.com/pdemagny/asdf-air"),
-    ("aks-engine", "https://github.com/robsonpeixoto/asdf-aks-engine.git"),
-    ("alias", "https://github.com/andrewthauer/asdf-alias.git"),
-    ("allure", "https://github.com/comdotlinux/asdf-allure.git"),
-    ("alp", "https://github.com/asdf-community/asdf-alp.git"),
-    ("amass", "https://github.com/dhoeric/asdf-amass.git"),
-    ("amazon-ecr-credential-helper", "https://github.com/dex4er/asdf-amazon-ecr-credential-helper.git"),
-    ("ambient", "https://github.com/jtakakura/asdf-ambient.git"),
-    ("ansible-base", "https://github.com/amrox/asdf-pyapp.git"),
-    ("ant", "https://github.com/jackboespflug/asdf-ant.git"),
-    ("apollo-router", "https://github.com/safx/asdf-apollo-router.git"),
-    ("arc", "https://github.com/ORCID/asdf-arc.git"),
-    ("argo", "https://github.com/sudermanjr/asdf-argo.git"),
-    ("argo-rollouts", "https://github.com/abatilo/asdf-argo-rollouts.git"),
-    ("argocd", "https://github.com/beardix/asdf-argocd.git"),
-    ("aria2", "https://github.com/asdf-community/asdf-aria2.git"),
-    ("asciidoctorj", "https://github.com/gliwka/asdf-asciidoctorj.git"),
-    ("asdf-plugin-manager", "https://github.com/asdf-community/asdf-plugin-manager"),
-    ("assh", "https://github.com/zekker6/asdf-assh.git"),
-    ("atlas", "https://github.com/pbr0ck3r/asdf-atlas.git"),
-    ("aws-amplify-cli", "https://github.com/LozanoMatheus/asdf-aws-amplify-cli.git"),
-    ("aws-copilot", "https://github.com/NeoHsu/asdf-copilot"),
-    ("aws-iam-authenticator", "https://github.com/zekker6/asdf-aws-iam-authenticator"),
-    ("aws-nuke", "https://github.com/bersalazar/asdf-aws-nuke.git"),
-    ("aws-sam-cli", "https://github.com/amrox/asdf-pyapp.git"),
-    ("aws-sso-cli", "https://github.com/adamcrews/asdf-aws-sso-cli.git"),
-    ("aws-vault", "https://github.com/karancode/asdf-aws-vault.git"),
-    ("awscli", "https://github.com/MetricMike/asdf-awscli.git"),
-    ("awscli-local", "https://github.com/paulo-ferraz-oliveira/asdf-awscli-local"),
-    ("awsebcli", "https://github.com/amrox/asdf-pyapp.git"),
-    ("awsls", "https://github.com/chessmango/asdf-awsls.git"),
-    ("awsrm", "https://github.com/chessmango/asdf-awsrm.git"),
-    ("awsweeper", "https://github.com/chessmango/asdf-awsweeper.git"),
-    ("azure-cli", "https://github.com/EcoMind/asdf-azure-cli.git"),
-    ("azure-functions-core-tools", "https://github.com/daveneeley/asdf-azure-functions-core-tools.git"),
-    ("babashka", "https://github.com/pitch-io/asdf-babashka.git"),
-    ("balena-cli", "https://github.com/boatkit-io/asdf-balena-cli"),
-    ("bashbot", "https://github.com/mathew-fleisch/asdf-bashbot.git"),
-    ("bat", "https://gitlab.com/wt0f/asdf-bat.git"),
-    ("bat-extras", "https://github.com/vhdirk/asdf-bat-extras.git"),
-    ("batect", "https://github.com/johnlayton/asdf-batect.git"),
-    ("bats", "https://github.com/timgluz/asdf-bats.git"),
-    ("bazel", "https://github.com/rajatvig/asdf-bazel.git"),
-    ("bbr", "https://github.com/vmware-tanzu/tanzu-plug-in-for-asdf.git"),
-    ("bbr-s3-config-validator", "https://github.com/vmware-tanzu/tanzu-plug-in-for-asdf.git"),
-    ("benthos", "https://github.com/benthosdev/benthos-asdf.git"),
-    ("bfs", "https://github.com/virtualroot/asdf-bfs.git"),
-    ("bin", "https://github.com/yozachar/asdf-bin.git"),
-    ("binnacle", "https://github.com/Traackr/asdf-binnacle.git"),
-    ("bitwarden", "https://github.com/vixus0/asdf-bitwarden.git"),
-    ("bitwarden-secrets-manager", "https://github.com/asdf-community/asdf-bitwarden-secrets-manager"),
-    ("bombardier", "https://github.com/NeoHsu/asdf-bombardier.git"),
-    ("borg", "https://github.com/lwiechec/asdf-borg"),
-    ("bosh", "https://github.com/vmware-tanzu/tanzu-plug-in-for-asdf.git"),
-    ("bottom", "https://github.com/carbonteq/asdf-btm.git"),
-    ("boundary", "https://github.com/asdf-community/asdf-hashicorp.git"),
-    ("bpkg", "https://github.com/bpkg/asdf-bpkg.git"),
-    ("brig", "https://github.com/Ibotta/asdf-brig.git"),
-    ("btrace", "https://github.com/joschi/asdf-btrace.git"),
-    ("buf", "https://github.com/truepay/asdf-buf.git"),
-    ("buildpack", "https://github.com/johnlayton/asdf-buildpack.git"),
-    ("bun", "https://github.com/cometkim/asdf-bun.git"),
-    ("bundler", "https://github.com/jonathanmorley/asdf-bundler.git"),
-    ("caddy", "https://github.com/salasrod/asdf-caddy.git"),
-    ("calicoctl", "https://github.com/TheCubicleJockey/asdf-calicoctl.git"),
-    ("camunda-modeler", "https://github.com/barmac/asdf-camunda-modeler.git"),
-    ("cargo-make", "https://github.com/kachick/asdf-cargo-make.git"),
-    ("carp", "https://github.com/susurri/asdf-carp.git"),
-    ("carthage", "https://github.com/younke/asdf-carthage.git"),
-    ("ccache", "https://github.com/asdf-community/asdf-ccache.git"),
-    ("certstrap", "https://github.com/carnei-ro/asdf-certstrap.git"),
-    ("cf", "https://github.com/mattysweeps/asdf-cf.git"),
-    ("cfssl", "https://github.com/mathew-fleisch/asdf-cfssl.git"),
-    ("chamber", "https://github.com/mintel/asdf-chamber"),
-    ("changie", "https://github.com/pdemagny/asdf-changie"),
-    ("cheat", "https://github.com/jmoratilla/asdf-cheat-plugin"),
-    ("checkov", "https://github.com/bosmak/asdf-checkov.git"),
-    ("chezmoi", "https://github.com/joke/asdf-chezmoi.git"),
-    ("chezscheme", "https://github.com/asdf-community/asdf-chezscheme.git"),
-    ("chicken", "https://github.com/evhan/asdf-chicken.git"),
-    ("choose", "https://github.com/carbonteq/asdf-choose.git"),
-    ("chromedriver", "https://github.com/schinckel/asdf-chromedriver.git"),
-    ("cidr-merger", "https://github.com/ORCID/asdf-cidr-merger.git"),
-    ("cidrchk", "https://github.com/ORCID/asdf-cidrchk.git"),
-    ("cilium-cli", "https://github.com/carnei-ro/asdf-cilium-cli.git"),
-    ("cilium-hubble", "https://github.com/NitriKx/asdf-cilium-hubble.git"),
-    ("circleci-cli", "https://github.com/ucpr/asdf-circleci-cli.git"),
-    ("clarinet", "https://github.com/alexgo-io/asdf-clarinet.git"),
-    ("clojure", "https://github.com/asdf-community/asdf-clojure.git"),
-    ("cloud-sql-proxy", "https://github.com/pbr0ck3r/asdf-cloud-sql-proxy.git"),
-    ("cloudflared", "https://github.com/threkk/asdf-cloudflared"),
-    ("clusterawsadm", "https://github.com/kahun/asdf-clusterawsadm.git"),
-    ("clusterctl", "https://github.com/pfnet-research/asdf-clusterctl.git"),
-    ("cmake", "https://github.com/asdf-community/asdf-cmake.git"),
-    ("cmctl", "https://github.com/asdf-community/asdf-cmctl.git"),
-    ("cockroach", "https://github.com/salasrod/asdf-cockroach.git"),
-    ("cocoapods", "https://github.com/ronnnnn/asdf-cocoapods.git"),
-    ("codefresh", "https://github.com/gurukulkarni/asdf-codefresh.git"),
-    ("codeql", "https://github.com/bored-engineer/asdf-codeql.git"),
-    ("colima", "https://github.com/CrouchingMuppet/asdf-colima.git"),
-    ("conan", "https://github.com/amrox/asdf-pyapp.git"),
-    ("concourse", "https://github.com/mattysweeps/asdf-concourse.git"),
-    ("conduit", "https://github.com/gmcabrita/asdf-conduit.git"),
-    ("conform", "https://github.com/skyzyx/asdf-conform.git"),
-    ("conftest", "https://github.com/looztra/asdf-conftest.git"),
-    ("consul", "https://github.com/asdf-community/asdf-hashicorp.git"),
-    ("container-diff", "https://github.com/cgroschupp/asdf-container-diff.git"),
-    ("container-structure-test", "https://github.com/jonathanmorley/asdf-container-structure-test.git"),
-    ("cookiecutter", "https://github.com/shawon-crosen/asdf-cookiecutter.git"),
-    ("copper", "https://github.com/vladlosev/asdf-copper.git"),
-    ("coq", "https://github.com/gingerhot/asdf-coq.git"),
-    ("cosign", "https://gitlab.com/wt0f/asdf-cosign.git"),
-    ("coursier", "https://github.com/jiahuili430/asdf-coursier.git"),
-    ("crane", "https://github.com/dmpe/asdf-crane"),
-    ("crc", "https://github.com/sqtran/asdf-crc.git"),
-    ("credhub", "https://github.com/vmware-tanzu/tanzu-plug-in-for-asdf.git"),
-    ("crictl", "https://github.com/FairwindsOps/asdf-crictl.git"),
-    ("crossplane-cli", "https://github.com/joke/asdf-crossplane-cli.git"),
-    ("crystal", "https://github.com/asdf-community/asdf-crystal.git"),
-    ("ctlptl", "https://github.com/ezcater/asdf-ctlptl.git"),
-    ("ctop", "https://github.com/NeoHsu/asdf-ctop.git"),
-    ("cue", "https://github.com/asdf-community/asdf-cue.git"),
-    ("cyclonedx", "https://github.com/xeedio/asdf-cyclonedx.git"),
-    ("dagger", "https://github.com/virtualstaticvoid/asdf-dagger.git"),
-    ("dart", "https://github.com/PatOConnor43/asdf-dart.git"),
-    ("dasel", "https://github.com/asdf-community/asdf-dasel.git"),
-    ("datree", "https://github.com/lukeab/asdf-datree.git"),
-    ("dbmate", "https://github.com/juusujanar/asdf-dbmate.git"),
-    ("deck", "https://github.com/nutellinoit/asdf-deck.git"),
-    ("delta", "https://github.com/andweeb/asdf-delta.git"),
-    ("deno", "https://github.com/asdf-community/asdf-deno.git"),
-    ("dep", "https://github.com/paxosglobal/asdf-dep.git"),
-    ("depot", "https://github.com/depot/asdf-depot.git"),
-    ("desk", "https://github.com/endorama/asdf-desk.git"),
-    ("devspace", "https://github.com/NeoHsu/asdf-devspace.git"),
-    ("dhall", "https://github.com/aaaaninja/asdf-dhall.git"),
-    ("difftastic", "https://github.com/volf52/asdf-difftastic.git"),
-    ("digdag", "https://github.com/jtakakura/asdf-digdag.git"),
-    ("direnv", "https://github.com/asdf-community/asdf-direnv.git"),
-    ("dive", "https://github.com/looztra/asdf-dive.git"),
-    ("djinni", "https://github.com/cross-language-cpp/asdf-djinni.git"),
-    ("dmd", "https://github.com/sylph01/asdf-dmd.git"),
-    ("docker-compose-v1", "https://github.com/yilas/asdf-docker-compose-v1"),
-    ("docker-slim", "https://github.com/xataz/asdf-docker-slim.git"),
-    ("dockle", "https://github.com/mathew-fleisch/asdf-dockle.git"),
-    ("doctl", "https://github.com/maristgeek/asdf-doctl.git"),
-    ("doctoolchain", "https://github.com/joschi/asdf-doctoolchain"),
-    ("docuum", "https://github.com/bradym/asdf-docuum.git"),
-    ("dome", "https://github.com/jtakakura/asdf-dome.git"),
-    ("doppler", "https://github.com/takutakahashi/asdf-doppler.git"),
-    ("dotenv-linter", "https://github.com/wesleimp/asdf-dotenv-linter.git"),
-    ("dotnet", "https://github.com/hensou/asdf-dotnet"),
-    ("dotnet-core", "https://github.com/emersonsoares/asdf-dotnet-core.git"),
-    ("dotty", "https://github.com/asdf-community/asdf-dotty.git"),
-    ("dprint", "https://github.com/asdf-community/asdf-dprint"),
-    ("draft", "https://github.com/kristoflemmens/asdf-draft.git"),
-    ("driftctl", "https://github.com/nlamirault/asdf-driftctl.git"),
-    ("drone", "https://github.com/virtualstaticvoid/asdf-drone.git"),
-    ("dt", "https://github.com/so-dang-cool/asdf-dt.git"),
-    ("dtm", "https://github.com/zhenyuanlau/asdf-dtm.git"),
-    ("duf", "https://github.com/NeoHsu/asdf-duf.git"),
-    ("dust", "https://github.com/looztra/asdf-dust.git"),
-    ("dvc", "https://github.com/fwfurtado/asdf-dvc.git"),
-    ("dyff", "https://gitlab.com/wt0f/asdf-dyff.git"),
-    ("ecspresso", "https://github.com/kayac/asdf-ecspresso.git"),
-    ("editorconfig-checker", "https://github.com/gabitchov/asdf-editorconfig-checker.git"),
-    ("ejson", "https://github.com/cipherstash/asdf-ejson.git"),
-    ("eksctl", "https://github.com/elementalvoid/asdf-eksctl.git"),
-    ("elasticsearch", "https://github.com/asdf-community/asdf-elasticsearch.git"),
-    ("elixir", "https://github.com/asdf-vm/asdf-elixir.git"),
-    ("elixir-ls", "https://github.com/juantascon/asdf-elixir-ls"),
-    ("elm", "https://github.com/asdf-community/asdf-elm.git"),
-    ("embulk", "https://github.com/yuokada/asdf-embulk.git"),
-    ("emsdk", "https://github.com/RobLoach/asdf-emsdk.git"),
-    ("envcli", "https://github.com/zekker6/asdf-envcli.git"),
-    ("envsubst", "https://github.com/dex4er/asdf-envsubst.git"),
-    ("ephemeral-postgres", "https://github.com/smashedtoatoms/asdf-ephemeral-postgres.git"),
-    ("erlang", "https://github.com/asdf-vm/asdf-erlang.git"),
-    ("esy", "https://github.com/asdf-community/asdf-esy.git"),
-    ("etcd", "https://github.com/particledecay/asdf-etcd.git"),
-    ("evans", "https://github.com/goki90210/asdf-evans.git"),
-    ("exa", "https://github.com/nyrst/asdf-exa.git"),
-    ("eza", "https://github.com/lwiechec/asdf-eza.git"),
-    ("fd", "https://gitlab.com/wt0f/asdf-fd.git"),
-    ("ffmpeg", "https://github.com/acj/asdf-ffmpeg"),
-    ("figma-export", "https://github.com/younke/asdf-figma-export.git"),
-    ("fillin", "https://github.com/ouest/asdf-fillin"),
-    ("firebase", "https://github.com/jthegedus/asdf-firebase.git"),
-    ("fission", "https://github.com/virtualstaticvoid/asdf-fission.git"),
-    ("flarectl", "https://github.com/ORCID/asdf-flarectl.git"),
-    ("flatc", "https://github.com/TheOpenDictionary/asdf-flatc.git"),
-    ("flutter", "https://github.com/oae/asdf-flutter.git"),
-    ("flux2", "https://github.com/tablexi/asdf-flux2.git"),
-    ("fluxctl", "https://github.com/stefansedich/asdf-fluxctl.git"),
-    ("fly", "https://github.com/vmware-tanzu/tanzu-plug-in-for-asdf.git"),
-    ("flyctl", "https://github.com/chessmango/asdf-flyctl.git"),
-    ("flyway", "https://github.com/junminahn/asdf-flyway.git"),
-    ("func-e", "https://github.com/carnei-ro/asdf-func-e.git"),
-    ("furyctl", "https://github.com/sighupio/asdf-furyctl.git"),
-    ("fx", "https://gitlab.com/wt0f/asdf-fx.git"),
-    ("fzf", "https://github.com/kompiro/asdf-fzf.git"),
-    ("gallery-dl", "https://github.com/iul1an/asdf-gallery-dl"),
-    ("gam", "https://github.com/offbyone/asdf-gam.git"),
-    ("gator", "https://github.com/MxNxPx/asdf-gator.git"),
-    ("gauche", "https://github.com/sakuro/asdf-gauche.git"),
-    ("gcc-arm-none-eabi", "https://github.com/dlech/asdf-gcc-arm-none-eabi.git"),
-    ("gcloud", "https://github.com/jthegedus/asdf-gcloud.git"),
-    ("getenvoy", "https://github.com/asdf-community/asdf-getenvoy.git"),
-    ("ghidra", "https://github.com/Honeypot95/asdf-ghidra.git"),
-    ("ghorg", "https://github.com/gbloquel/asdf-ghorg.git"),
-    ("ghq", "https://github.com/kajisha/asdf-ghq.git"),
-    ("ginkgo", "https://github.com/jimmidyson/asdf-ginkgo.git"),
-    ("git", "https://gitlab.com/jcaigitlab/asdf-git.git"),
-    ("git-chglog", "https://github.com/GoodwayGroup/asdf-git-chglog.git"),
-    ("gitconfig", "https://github.com/0ghny/asdf-gitconfig.git"),
-    ("github-cli", "https://github.com/bartlomiejdanek/asdf-github-cli.git"),
-    ("github-markdown-toc", "https://github.com/skyzyx/asdf-github-markdown-toc.git"),
-    ("gitleaks", "https://github.com/jmcvetta/asdf-gitleaks.git"),
-    ("gitsign", "https://github.com/spencergilbert/asdf-gitsign.git"),
-    ("gitui", "https://github.com/looztra/asdf-gitui.git"),
-    ("glab", "https://github.com/particledecay/asdf-glab.git"),
-    ("gleam", "https://github.com/vic/asdf-gleam.git"),
-    ("glen", "https://github.com/bradym/asdf-glen"),
-    ("glooctl", "https://github.com/halilkaya/asdf-glooctl.git"),
-    ("glow", "https://github.com/chessmango/asdf-glow.git"),
-    ("go-containerregistry", "https://github.com/dex4er/asdf-go-containerregistry.git"),
-    ("go-getter", "https://github.com/ryodocx/asdf-go-getter.git"),
-    ("go-jira", "https://github.com/dguihal/asdf-go-jira.git"),
-    ("go-jsonnet", "https://gitlab.com/craigfurman/asdf-go-jsonnet.git"),
-    ("go-junit-report", "https://github.com/jwillker/asdf-go-junit-report.git"),
-    ("go-sdk", "https://github.com/yacchi/asdf-go-sdk.git"),
-    ("go-swagger", "https://github.com/jfreeland/asdf-go-swagger.git"),
-    ("goconvey", "https://github.com/therounds-contrib/asdf-goconvey.git"),
-    ("gofumpt", "https://github.com/looztra/asdf-gofumpt.git"),
-    ("gohugo", "https://github.com/nklmilojevic/asdf-hugo.git"),
-    ("gojq", "https://github.com/jimmidyson/asdf-gojq.git"),
-    ("golangci-lint", "https://github.com/hypnoglow/asdf-golangci-lint.git"),
-    ("gomigrate", "https://github.com/joschi/asdf-gomigrate.git"),
-    ("gomplate", "https://github.com/sneakybeaky/asdf-gomplate.git"),
-    ("gopass", "https://github.com/trallnag/asdf-gopass.git"),
-    ("goreleaser", "https://github.com/kforsthoevel/asdf-goreleaser.git"),
-    ("goss", "https://github.com/raimon49/asdf-goss.git"),
-    ("graalvm", "https://github.com/asdf-community/asdf-graalvm.git"),
-    ("gradle", "https://github.com/rfrancis/asdf-gradle.git"),
-    ("gradle-profiler", "https://github.com/joschi/asdf-gradle-profiler.git"),
-    ("grails", "https://github.com/weibemoura/asdf-grails.git"),
-    ("grain", "https://github.com/cometkim/asdf-grain.git"),
-    ("granted", "https://github.com/dex4er/asdf-granted.git"),
-    ("grex", "https://github.com/ouest/asdf-grex"),
-    ("groovy", "https://github.com/weibemoura/asdf-groovy.git"),
-    ("grpc-health-probe", "https://github.com/zufardhiyaulhaq/asdf-grpc-health-probe.git"),
-    ("grpcurl", "https://github.com/asdf-community/asdf-grpcurl.git"),
-    ("grype", "https://github.com/poikilotherm/asdf-grype.git"),
-    ("guile", "https://github.com/indiebrain/asdf-guile.git"),
-    ("gum", "https://github.com/lwiechec/asdf-gum"),
-    ("gwvault", "https://github.com/GoodwayGroup/asdf-gwvault.git"),
-    ("hadolint", "https://github.com/devlincashman/asdf-hadolint.git"),
-    ("hamler", "https://github.com/scudelletti/asdf-hamler.git"),
-    ("has", "https://github.com/sylvainmetayer/asdf-has"),
-    ("haskell", "https://github.com/asdf-community/asdf-haskell.git"),
-    ("hasura-cli", "https://github.com/gurukulkarni/asdf-hasura.git"),
-    ("haxe", "https://github.com/asdf-community/asdf-haxe.git"),
-    ("hcl2json", "https://github.com/dex4er/asdf-hcl2json.git"),
-    ("hcloud", "https://github.com/chessmango/asdf-hcloud.git"),
-    ("helm", "https://github.com/Antiarchitect/asdf-helm.git"),
-    ("helm-cr", "https://github.com/Antiarchitect/asdf-helm-cr.git"),
-    ("helm-ct", "https://github.com/tablexi/asdf-helm-ct.git"),
-    ("helm-diff", "https://github.com/dex4er/asdf-helm-diff.git"),
-    ("helm-docs", "https://github.com/sudermanjr/asdf-helm-docs.git"),
-    ("helmfile", "https://github.com/feniix/asdf-helmfile.git"),
-    ("helmsman", "https://github.com/luisdavim/asdf-helmsman"),
-    ("heroku-cli", "https://github.com/treilly94/asdf-heroku-cli.git"),
-    ("hey", "https://github.com/raimon49/asdf-hey.git"),
-    ("hostctl", "https://github.com/svenluijten/asdf-hostctl.git"),
-    ("httpie-go", "https://github.com/abatilo/asdf-httpie-go.git"),
-    ("hub", "https://github.com/vixus0/asdf-hub.git"),
-    ("hugo", "https://github.com/NeoHsu/asdf-hugo.git"),
-    ("hurl", "https://github.com/raimon49/asdf-hurl.git"),
-    ("hwatch", "https://github.com/chessmango/asdf-hwatch.git"),
-    ("hygen", "https://github.com/brentjanderson/asdf-hygen.git"),
-    ("hyperfine", "https://github.com/volf52/asdf-hyperfine.git"),
-    ("iam-policy-json-to-terraform", "https://github.com/carlduevel/asdf-iam-policy-json-to-terraform.git"),
-    ("iamlive", "https://github.com/chessmango/asdf-iamlive.git"),
-    ("ibmcloud", "https://github.com/triangletodd/asdf-ibmcloud.git"),
-    ("idris", "https://github.com/asdf-community/asdf-idris.git"),
-    ("idris2", "https://github.com/asdf-community/asdf-idris2.git"),
-    ("imagemagick", "https://github.com/mangalakader/asdf-imagemagick.git"),
-    ("imgpkg", "https://github.com/vmware-tanzu/asdf-carvel.git"),
-    ("infracost", "https://github.com/dex4er/asdf-infracost.git"),
-    ("inlets", "https://github.com/nlamirault/asdf-inlets.git"),
-    ("io", "https://github.com/mracos/asdf-io.git"),
-    ("istioctl", "https://github.com/virtualstaticvoid/asdf-istioctl.git"),
-    ("janet", "https://github.com/Jakski/asdf-janet.git"),
-    ("jb", "https://github.com/beardix/asdf-jb.git"),
-    ("jbang", "https://github.com/jbangdev/jbang-asdf.git"),
-    ("jfrog-cli", "https://github.com/LozanoMatheus/asdf-jfrog-cli.git"),
-    ("jib", "https://github.com/joschi/asdf-jib.git"),
-    ("jiq", "https://github.com/chessmango/asdf-jiq.git"),
-    ("jless", "https://github.com/jc00ke/asdf-jless.git"),
-    ("jmespath", "https://github.com/skyzyx/asdf-jmespath.git"),
-    ("jmeter", "https://github.com/comdotlinux/asdf-jmeter"),
-    ("jq", "https://github.com/lsanwick/asdf-jq.git"),
-    ("jqp", "https://gitlab.com/wt0f/asdf-jqp.git"),
-    ("jreleaser", "https://github.com/joschi/asdf-jreleaser.git"),
-    ("jsonnet", "https://github.com/Banno/asdf-jsonnet.git"),
-    ("julia", "https://github.com/rkyleg/asdf-julia.git"),
-    ("just", "https://github.com/olofvndrhr/asdf-just.git"),
-    ("jx", "https://github.com/vbehar/asdf-jx.git"),
-    ("k0sctl", "https://github.com/Its-Alex/asdf-plugin-k0sctl.git"),
-    ("k14s", "https://github.com/k14s/asdf-k14s.git"),
-    ("k2tf", "https://github.com/carlduevel/asdf-k2tf.git"),
-    ("k3d", "https://github.com/spencergilbert/asdf-k3d.git"),
-    ("k3sup", "https://github.com/cgroschupp/asdf-k3sup.git"),
-    ("k6", "https://github.com/grimoh/asdf-k6.git"),
-    ("k9s", "https://github.com/looztra/asdf-k9s.git"),
-    ("kafka", "https://github.com/ueisele/asdf-kafka.git"),
-    ("kafkactl", "https://github.com/anweber/asdf-kafkactl.git"),
-    ("kapp", "https://github.com/vmware-tanzu/asdf-carvel.git"),
-    ("kbld", "https://github.com/vmware-tanzu/asdf-carvel.git"),
-    ("kcat", "https://github.com/douglasdgoulart/asdf-kcat.git"),
-    ("kcctl", "https://github.com/joschi/asdf-kcctl.git"),
-    ("kcl", "https://github.com/starkers/asdf-kcl"),
-    ("kconf", "https://github.com/particledecay/asdf-kconf.git"),
-    ("ki", "https://github.com/comdotlinux/asdf-ki"),
-    ("kind", "https://github.com/johnlayton/asdf-kind.git"),
-    ("kiota", "https://github.com/asdf-community/asdf-kiota.git"),
-    ("kn", "https://github.com/joke/asdf-kn.git"),
-    ("ko", "https://github.com/zasdaym/asdf-ko.git"),
-    ("koka", "https://github.com/susurri/asdf-koka.git"),
-    ("kompose", "https://github.com/technikhil314/asdf-kompose.git"),
-    ("kops", "https://github.com/Antiarchitect/asdf-kops.git"),
-    ("kotlin", "https://github.com/asdf-community/asdf-kotlin.git"),
-    ("kp", "https://github.com/vmware-tanzu/tanzu-plug-in-for-asdf.git"),
-    ("kpt", "https://github.com/nlamirault/asdf-kpt.git"),
-    ("krab", "https://github.com/ohkrab/asdf-krab.git"),
-    ("krew", "https://github.com/bjw-s/asdf-krew.git"),
-    ("kscript", "https://github.com/edgelevel/asdf-kscript.git"),
-    ("ksonnet", "https://github.com/Banno/asdf-ksonnet.git"),
-    ("ktlint", "https://github.com/esensar/asdf-ktlint.git"),
-    ("kube-capacity", "https://github.com/looztra/asdf-kube-capacity.git"),
-    ("kube-code-generator", "https://github.com/jimmidyson/asdf-kube-code-generator.git"),
-    ("kube-controller-tools", "https://github.com/jimmidyson/asdf-kube-controller-tools.git"),
-    ("kube-credential-cache", "https://github.com/ryodocx/kube-credential-cache.git"),
-    ("kube-linter", "https://github.com/devlincashman/asdf-kube-linter.git"),
-    ("kube-score", "https://github.com/bageljp/asdf-kube-score.git"),
-    ("kubebuilder", "https://github.com/virtualstaticvoid/asdf-kubebuilder.git"),
-    ("kubecm", "https://github.com/samhvw8/asdf-kubecm"),
-    ("kubecolor", "https://github.com/dex4er/asdf-kubecolor.git"),
-    ("kubeconform", "https://github.com/lirlia/asdf-kubeconform.git"),
-    ("kubectl", "https://github.com/asdf-community/asdf-kubectl.git"),
-    ("kubectl-bindrole", "https://github.com/looztra/asdf-kubectl-bindrole.git"),
-    ("kubectl-buildkit", "https://github.com/ezcater/asdf-kubectl-buildkit.git"),
-    ("kubectl-convert", "https://github.com/iul1an/asdf-kubectl-convert.git"),
-    ("kubectl-kots", "https://github.com/ganta/asdf-kubectl-kots.git"),
-    ("kubectx", "https://gitlab.com/wt0f/asdf-kubectx.git"),
-    ("kubefedctl", "https://github.com/kvokka/asdf-kubefedctl.git"),
-    ("kubefirst", "https://github.com/Claywd/asdf-kubefirst"),
-    ("kubelogin", "https://github.com/sechmann/asdf-kubelogin.git"),
-    ("kubemqctl", "https://github.com/johnlayton/asdf-kubemqctl.git"),
-    ("kubent", "https://github.com/virtualstaticvoid/asdf-kubent.git"),
-    ("kubergrunt", "https://github.com/NeoHsu/asdf-kubergrunt.git"),
-    ("kubeseal", "https://github.com/stefansedich/asdf-kubeseal.git"),
-    ("kubesec", "https://github.com/vitalis/asdf-kubesec.git"),
-    ("kubeshark", "https://github.com/carnei-ro/asdf-kubeshark.git"),
-    ("kubespy", "https://github.com/jfreeland/asdf-kubespy.git"),
-    ("kubeval", "https://github.com/stefansedich/asdf-kubeval.git"),
-    ("kubevela", "https://github.com/gustavclausen/asdf-kubevela.git"),
-    ("kubie", "https://github.com/johnhamelink/asdf-kubie.git"),
-    ("kustomize", "https://github.com/Banno/asdf-kustomize.git"),
-    ("kuttl", "https://github.com/jimmidyson/asdf-kuttl.git"),
-    ("kwt", "https://github.com/vmware-tanzu/asdf-carvel.git"),
-    ("lab", "https://github.com/particledecay/asdf-lab.git"),
-    ("lane", "https://github.com/CodeReaper/asdf-lane.git"),
-    ("lazygit", "https://github.com/nklmilojevic/asdf-lazygit.git"),
-    ("lean", "https://github.com/asdf-community/asdf-lean.git"),
-    ("lefthook", "https://github.com/jtzero/asdf-lefthook.git"),
-    ("leiningen", "https://github.com/miorimmax/asdf-lein.git"),
-    ("levant", "https://github.com/asdf-community/asdf-hashicorp.git"),
-    ("lfe", "https://github.com/asdf-community/asdf-lfe.git"),
-    ("lima", "https://github.com/CrouchingMuppet/asdf-lima.git"),
-    ("link", "https://github.com/asdf-community/asdf-link.git"),
-    ("linkerd", "https://github.com/kforsthoevel/asdf-linkerd.git"),
-    ("liqoctl", "https://github.com/pdemagny/asdf-liqoctl"),
-    ("litestream", "https://github.com/threkk/asdf-litestream"),
-    ("logtalk", "https://github.com/LogtalkDotOrg/asdf-logtalk.git"),
-    ("loki-logcli", "https://github.com/comdotlinux/asdf-loki-logcli.git"),
-    ("lua", "https://github.com/Stratus3D/asdf-lua.git"),
-    ("lua-language-server", "https://github.com/bellini666/asdf-lua-language-server"),
-    ("luaJIT", "https://github.com/smashedtoatoms/asdf-luaJIT.git"),
-    ("lucy", "https://github.com/cometkim/asdf-lucy.git"),
-    ("maestro", "https://github.com/dotanuki-labs/asdf-maestro.git"),
-    ("mage", "https://github.com/mathew-fleisch/asdf-mage.git"),
-    ("make", "https://github.com/yacchi/asdf-make.git"),
-    ("mani", "https://github.com/anweber/asdf-mani.git"),
-    ("mark", "https://github.com/jfreeland/asdf-mark.git"),
-    ("markdownlint-cli2", "https://github.com/paulo-ferraz-oliveira/asdf-markdownlint-cli2"),
-    ("marp-cli", "https://github.com/xataz/asdf-marp-cli"),
-    ("mask", "https://github.com/aaaaninja/asdf-mask.git"),
-    ("maven", "https://github.com/halcyon/asdf-maven.git"),
-    ("mc", "https://github.com/penpyt/asdf-mc.git"),
-    ("mdbook", "https://github.com/cipherstash/asdf-mdbook.git"),
-    ("mdbook-linkcheck", "https://github.com/cipherstash/asdf-mdbook-linkcheck.git"),
-    ("melt", "https://github.com/chessmango/asdf-melt.git"),
-    ("memcached", "https://github.com/furkanural/asdf-memcached"),
-    ("mercury", "https://github.com/susurri/asdf-mercury.git"),
-    ("meson", "https://github.com/asdf-community/asdf-meson.git"),
-    ("micronaut", "https://github.com/weibemoura/asdf-micronaut.git"),
-    ("mill", "https://github.com/asdf-community/asdf-mill.git"),
-    ("mimirtool", "https://github.com/asdf-community/asdf-mimirtool.git"),
-    ("minify", "https://github.com/axilleas/asdf-minify"),
-    ("minikube", "https://github.com/alvarobp/asdf-minikube.git"),
-    ("minio", "https://github.com/aeons/asdf-minio.git"),
-    ("minishift", "https://github.com/sqtran/asdf-minishift.git"),
-    ("mint", "https://github.com/mint-lang/asdf-mint"),
-    ("mitmproxy", "https://github.com/NeoHsu/asdf-mitmproxy.git"),
-    ("mkcert", "https://github.com/salasrod/asdf-mkcert.git"),
-    ("mlton", "https://github.com/asdf-community/asdf-mlton.git"),
-    ("mockery", "https://github.com/cabify/asdf-mockery.git"),
-    ("monarch", "https://github.com/nyuyuyu/asdf-monarch.git"),
-    ("mongo-tools", "https://github.com/itspngu/asdf-mongo-tools.git"),
-    ("mongodb", "https://github.com/sylph01/asdf-mongodb.git"),
-    ("mongosh", "https://github.com/itspngu/asdf-mongosh.git"),
-    ("mutanus", "https://github.com/SoriUR/asdf-mutanus.git"),
-    ("mvnd", "https://github.com/joschi/asdf-mvnd.git"),
-    ("mysql", "https://github.com/iroddis/asdf-mysql.git"),
-    ("nancy", "https://github.com/iilyak/asdf-nancy.git"),
-    ("nano", "https://github.com/mfakane/asdf-nano.git"),
-    ("nasm", "https://github.com/Dpbm/asdf-nasm.git"),
-    ("neko", "https://github.com/asdf-community/asdf-neko.git"),
-    ("neovim", "https://github.com/richin13/asdf-neovim.git"),
-    ("nerdctl", "https://github.com/dmpe/asdf-nerdctl"),
-    ("newrelic-cli", "https://github.com/NeoHsu/asdf-newrelic-cli.git"),
-    ("nfpm", "https://github.com/ORCID/asdf-nfpm"),
-    ("nim", "https://github.com/asdf-community/asdf-nim.git"),
-    ("ninja", "https://github.com/asdf-community/asdf-ninja.git"),
-    ("nomad", "https://github.com/asdf-community/asdf-hashicorp.git"),
-    ("notation", "https://github.com/bodgit/asdf-notation.git"),
-    ("nova", "https://github.com/elementalvoid/asdf-nova.git"),
-    ("nsc", "https://github.com/dex4er/asdf-nsc.git"),
-    ("oapi-codegen", "https://github.com/dylanrayboss/asdf-oapi-codegen.git"),
-    ("oc", "https://github.com/sqtran/asdf-oc.git"),
-    ("ocaml", "https://github.com/asdf-community/asdf-ocaml.git"),
-    ("oci", "https://github.com/yasn77/asdf-oci.git"),
-    ("odin", "https://github.com/jtakakura/asdf-odin"),
-    ("odo", "https://github.com/rm3l/asdf-odo.git"),
-    ("okta-aws-cli", "https://github.com/bennythejudge/asdf-plugin-okta-aws-cli.git"),
-    ("okteto", "https://github.com/BradenM/asdf-okteto"),
-    ("om", "https://github.com/vmware-tanzu/tanzu-plug-in-for-asdf.git"),
-    ("opa", "https://github.com/tochukwuvictor/asdf-opa.git"),
-    ("opam", "https://github.com/asdf-community/asdf-opam.git"),
-    ("openfaas-faas-cli", "https://github.com/zekker6/asdf-faas-cli.git"),
-    ("openresty", "https://github.com/smashedtoatoms/asdf-openresty.git"),
-    ("opensearch", "https://github.com/randikabanura/asdf-opensearch.git"),
-    ("opensearch-cli", "https://github.com/iul1an/asdf-opensearch-cli.git"),
-    ("openshift-install", "https://github.com/hhemied/asdf-openshift-install.git"),
-    ("opentofu", "https://github.com/virtualroot/asdf-opentofu.git"),
-    ("operator-sdk", "https://github.com/Medium/asdf-operator-sdk.git"),
-    ("opsgenie-lamp", "https://github.com/ORCID/asdf-opsgenie-lamp"),
-    ("oras", "https://github.com/bodgit/asdf-oras.git"),
-    ("osm", "https://github.com/nlamirault/asdf-osm.git"),
-    ("osqueryi", "https://github.com/davidecavestro/asdf-osqueryi.git"),
-    ("pachctl", "https://github.com/abatilo/asdf-pachctl.git"),
-    ("packer", "https://github.com/asdf-community/asdf-hashicorp.git"),
-    ("patat", "https://github.com/airtonix/asdf-patat.git"),
-    ("pdm", "https://github.com/1oglop1/asdf-pdm"),
-    ("peco", "https://github.com/asdf-community/asdf-peco.git"),
-    ("perl", "https://github.com/ouest/asdf-perl.git"),
-    ("php", "https://github.com/asdf-community/asdf-php.git"),
-    ("pint", "https://github.com/sam-burrell/asdf-pint.git"),
-    ("pipectl", "https://github.com/pipe-cd/asdf-pipectl.git"),
-    ("pipx", "https://github.com/yozachar/asdf-pipx.git"),
-    ("pivnet", "https://github.com/vmware-tanzu/tanzu-plug-in-for-asdf.git"),
-    ("please", "https://github.com/asdf-community/asdf-please.git"),
-    ("pluto", "https://github.com/FairwindsOps/asdf-pluto.git"),
-    ("pnpm", "https://github.com/jonathanmorley/asdf-pnpm.git"),
-    ("poetry", "https://github.com/asdf-community/asdf-poetry.git"),
-    ("polaris", "https://github.com/particledecay/asdf-polaris.git"),
-    ("popeye", "https://github.com/nlamirault/asdf-popeye.git"),
-    ("postgres", "https://github.com/smashedtoatoms/asdf-postgres.git"),
-    ("powerline-go", "https://github.com/dex4er/asdf-powerline-go.git"),
-    ("powershell-core", "https://github.com/daveneeley/asdf-powershell-core.git"),
-    ("pre-commit", "https://github.com/jonathanmorley/asdf-pre-commit.git"),
-    ("promtool", "https://github.com/asdf-community/asdf-promtool"),
-    ("protoc", "https://github.com/paxosglobal/asdf-protoc.git"),
-    ("protoc-gen-connect-go", "https://github.com/dylanrayboss/asdf-protoc-gen-connect-go.git"),
-    ("protoc-gen-go", "https://github.com/pbr0ck3r/asdf-protoc-gen-go.git"),
-    ("protoc-gen-go-grpc", "https://github.com/pbr0ck3r/asdf-protoc-gen-go-grpc.git"),
-    ("protoc-gen-grpc-web", "https://github.com/pbr0ck3r/asdf-protoc-gen-grpc-web.git"),
-    ("protoc-gen-js", "https://github.com/pbr0ck3r/asdf-protoc-gen-js.git"),
-    ("protolint", "https://github.com/spencergilbert/asdf-protolint.git"),
-    ("protonge", "https://github.com/augustobmoura/asdf-protonge.git"),
-    ("psc-package", "https://github.com/nsaunders/asdf-psc-package.git"),
-    ("pulumi", "https://github.com/canha/asdf-pulumi.git"),
-    ("purerl", "https://github.com/GoNZooo/asdf-purerl.git"),
-    ("purescript", "https://github.com/jrrom/asdf-purescript.git"),
-    ("purty", "https://github.com/nsaunders/asdf-purty.git"),
-    ("qdns", "https://github.com/moritz-makandra/asdf-plugin-qdns.git"),
-    ("quarkus", "https://github.com/asdf-community/asdf-quarkus.git"),
-    ("rabbitmq", "https://github.com/w-sanches/asdf-rabbitmq.git"),
-    ("racket", "https://github.com/asdf-community/asdf-racket.git"),
-    ("raku", "https://github.com/m-dango/asdf-raku.git"),
-    ("rancher", "https://github.com/abinet/asdf-rancher.git"),
-    ("rbac-lookup", "https://github.com/looztra/asdf-rbac-lookup.git"),
-    ("rclone", "https://github.com/johnlayton/asdf-rclone.git"),
-    ("rebar", "https://github.com/Stratus3D/asdf-rebar.git"),
-    ("reckoner", "https://github.com/FairwindsOps/asdf-reckoner.git"),
-    ("redis", "https://github.com/smashedtoatoms/asdf-redis.git"),
-    ("redis-cli", "https://github.com/NeoHsu/asdf-redis-cli.git"),
-    ("redo", "https://github.com/chessmango/asdf-redo.git"),
-    ("redskyctl", "https://github.com/sudermanjr/asdf-redskyctl.git"),
-    ("reg", "https://github.com/looztra/asdf-reg.git"),
-    ("regctl", "https://github.com/ORCID/asdf-regctl.git"),
-    ("regsync", "https://github.com/rsrchboy/asdf-regsync.git"),
-    ("restic", "https://github.com/xataz/asdf-restic"),
-    ("revive", "https://github.com/bjw-s/asdf-revive.git"),
-    ("richgo", "https://github.com/paxosglobal/asdf-richgo.git"),
-    ("riff", "https://github.com/abinet/asdf-riff.git"),
-    ("ripgrep", "https://gitlab.com/wt0f/asdf-ripgrep.git"),
-    ("rke", "https://github.com/particledecay/asdf-rke.git"),
-    ("rlwrap", "https://github.com/asdf-community/asdf-rlwrap.git"),
-    ("rome", "https://github.com/kichiemon/asdf-rome.git"),
-    ("rstash", "https://github.com/carlduevel/asdf-rstash.git"),
-    ("rust", "https://github.com/code-lever/asdf-rust.git"),
-    ("rust-analyzer", "https://github.com/Xyven1/asdf-rust-analyzer"),
-    ("rye", "https://github.com/Azuki-bar/asdf-rye"),
-    ("saml2aws", "https://github.com/elementalvoid/asdf-saml2aws.git"),
-    ("sbcl", "https://github.com/smashedtoatoms/asdf-sbcl.git"),
-    ("sbt", "https://github.com/bram2000/asdf-sbt.git"),
-    ("scala", "https://github.com/asdf-community/asdf-scala.git"),
-    ("scala-cli", "https://github.com/asdf-community/asdf-scala-cli.git"),
-    ("scaleway-cli", "https://github.com/albarralnunez/asdf-plugin-scaleway-cli"),
-    ("scalingo-cli", "https://github.com/brandon-welsch/asdf-scalingo-cli.git"),
-    ("scarb", "https://github.com/software-mansion/asdf-scarb.git"),
-    ("sccache", "https://github.com/emersonmx/asdf-sccache.git"),
-    ("scenery", "https://github.com/skyzyx/asdf-scenery.git"),
-    ("schemacrawler", "https://github.com/davidecavestro/asdf-schemacrawler.git"),
-    ("seed7", "https://github.com/susurri/asdf-seed7.git"),
-    ("semgrep", "https://github.com/brentjanderson/asdf-semgrep.git"),
-    ("semtag", "https://github.com/junminahn/asdf-semtag"),
-    ("semver", "https://github.com/mathew-fleisch/asdf-semver.git"),
-    ("sentinel", "https://github.com/asdf-community/asdf-hashicorp.git"),
-    ("serf", "https://github.com/asdf-community/asdf-hashicorp.git"),
-    ("serverless", "https://github.com/pdemagny/asdf-serverless.git"),
-    ("shell2http", "https://github.com/ORCID/asdf-shell2http.git"),
-    ("shellcheck", "https://github.com/luizm/asdf-shellcheck.git"),
-    ("shellspec", "https://github.com/poikilotherm/asdf-shellspec.git"),
-    ("shfmt", "https://github.com/luizm/asdf-shfmt.git"),
-    ("sinker", "https://github.com/elementalvoid/asdf-sinker.git"),
-    ("skaffold", "https://github.com/nklmilojevic/asdf-skaffold.git"),
-    ("skate", "https://github.com/chessmango/asdf-skate.git"),
-    ("sloth", "https://github.com/slok/asdf-sloth.git"),
-    ("smithy", "https://github.com/aws/asdf-smithy.git"),
-    ("smlnj", "https://github.com/samontea/asdf-smlnj.git"),
-    ("snyk", "https://github.com/nirfuchs/asdf-snyk.git"),
-    ("soft-serve", "https://github.com/chessmango/asdf-soft-serve.git"),
-    ("solidity", "https://github.com/diegodorado/asdf-solidity.git"),
-    ("sonobuoy", "https://github.com/Nick-Triller/asdf-sonobuoy.git"),
-    ("sops", "https://github.com/feniix/asdf-sops.git"),
-    ("sopstool", "https://github.com/elementalvoid/asdf-sopstool.git"),
-    ("soracom", "https://github.com/grimoh/asdf-soracom.git"),
-    ("sourcery", "https://github.com/younke/asdf-sourcery.git"),
-    ("spacectl", "https://github.com/bodgit/asdf-spacectl.git"),
-    ("spago", "https://github.com/jrrom/asdf-spago.git"),
-    ("spark", "https://github.com/joshuaballoch/asdf-spark.git"),
-    ("spectral", "https://github.com/vbyrd/asdf-spectral.git"),
-    ("spin", "https://github.com/pavloos/asdf-spin.git"),
-    ("spring-boot", "https://github.com/joschi/asdf-spring-boot.git"),
-    ("spruce", "https://github.com/woneill/asdf-spruce.git"),
-    ("sqldef", "https://github.com/cometkim/asdf-sqldef.git"),
-    ("sqlite", "https://github.com/cLupus/asdf-sqlite.git"),
-    ("sshuttle", "https://github.com/xanmanning/asdf-sshuttle.git"),
-    ("stack", "https://github.com/sestrella/asdf-ghcup.git"),
-    ("starboard", "https://github.com/zufardhiyaulhaq/asdf-starboard.git"),
-    ("starknet-foundry", "https://github.com/foundry-rs/asdf-starknet-foundry.git"),
-    ("starport", "https://github.com/nikever/asdf-starport.git"),
-    ("starship", "https://github.com/grimoh/asdf-starship.git"),
-    ("staticcheck", "https://github.com/pbr0ck3r/asdf-staticcheck.git"),
-    ("steampipe", "https://github.com/carnei-ro/asdf-steampipe.git"),
-    ("step", "https://github.com/log2/asdf-step.git"),
-    ("stern", "https://github.com/looztra/asdf-stern.git"),
-    ("stripe-cli", "https://github.com/offbyone/asdf-stripe.git"),
-    ("stylua", "https://github.com/jc00ke/asdf-stylua.git"),
-    ("svu", "https://github.com/asdf-community/asdf-svu"),
-    ("swag", "https://github.com/behoof4mind/asdf-swag.git"),
-    ("swift", "https://github.com/fcrespo82/asdf-swift.git"),
-    ("swiftformat", "https://github.com/younke/asdf-swiftformat.git"),
-    ("swiftgen", "https://github.com/younke/asdf-swiftgen.git"),
-    ("swiftlint", "https://github.com/klundberg/asdf-swiftlint.git"),
-    ("swiprolog", "https://github.com/mracos/asdf-swiprolog.git"),
-    ("syft", "https://github.com/davidgp1701/asdf-syft.git"),
-    ("syncher", "https://github.com/nwillc/syncher.git"),
-    ("talhelper", "https://github.com/bjw-s/asdf-talhelper"),
-    ("talos", "https://github.com/particledecay/asdf-talos.git"),
-    ("talosctl", "https://github.com/bjw-s/asdf-talosctl"),
-    ("tanka", "https://github.com/trotttrotttrott/asdf-tanka.git"),
-    ("tanzu", "https://github.com/vmware-tanzu/tanzu-plug-in-for-asdf.git"),
-    ("task", "https://github.com/particledecay/asdf-task.git"),
-    ("tctl", "https://github.com/eko/asdf-tctl.git"),
-    ("tekton-cli", "https://github.com/johnhamelink/asdf-tekton-cli.git"),
-    ("teleport-community", "https://github.com/MaloPolese/asdf-teleport-community"),
-    ("teleport-ent", "https://github.com/highb/asdf-teleport-ent"),
-    ("telepresence", "https://github.com/pirackr/asdf-telepresence.git"),
-    ("teller", "https://github.com/pdemagny/asdf-teller"),
-    ("temporalite", "https://github.com/eko/asdf-temporalite.git"),
-    ("terradozer", "https://github.com/chessmango/asdf-terradozer.git"),
-    ("terraform", "https://github.com/asdf-community/asdf-hashicorp.git"),
-    ("terraform-docs", "https://github.com/looztra/asdf-terraform-docs.git"),
-    ("terraform-ls", "https://github.com/asdf-community/asdf-hashicorp.git"),
-    ("terraform-lsp", "https://github.com/bartlomiejdanek/asdf-terraform-lsp.git"),
-    ("terraform-validator", "https://github.com/looztra/asdf-terraform-validator.git"),
-    ("terraformer", "https://github.com/grimoh/asdf-terraformer.git"),
-    ("terragrunt", "https://github.com/ohmer/asdf-terragrunt.git"),
-    ("terramate", "https://github.com/martinlindner/asdf-terramate.git"),
-    ("terrascan", "https://github.com/hpdobrica/asdf-terrascan.git"),
-    ("tf-summarize", "https://github.com/adamcrews/asdf-tf-summarize.git"),
-    ("tfc-agent", "https://github.com/asdf-community/asdf-hashicorp.git"),
-    ("tfctl", "https://github.com/deas/asdf-tfctl"),
-    ("tfenv", "https://github.com/carlduevel/asdf-tfenv.git"),
-    ("tflint", "https://github.com/skyzyx/asdf-tflint.git"),
-    ("tfmigrate", "https://github.com/dex4er/asdf-tfmigrate.git"),
-    ("tfnotify", "https://github.com/jnavarrof/asdf-tfnotify.git"),
-    ("tfsec", "https://github.com/woneill/asdf-tfsec.git"),
-    ("tfstate-lookup", "https://github.com/carnei-ro/asdf-tfstate-lookup.git"),
-    ("tfswitch", "https://github.com/iul1an/asdf-tfswitch.git"),
-    ("tfupdate", "https://github.com/yuokada/asdf-tfupdate.git"),
-    ("thrift", "https://github.com/alisaifee/asdf-thrift.git"),
-    ("tilt", "https://github.com/eaceaser/asdf-tilt.git"),
-    ("timoni", "https://github.com/Smana/asdf-timoni.git"),
-    ("titan", "https://github.com/gabitchov/asdf-titan.git"),
-    ("tlsg-cli", "https://github.com/0ghny/asdf-tlsgcli.git"),
-    ("tmux", "https://github.com/aphecetche/asdf-tmux.git"),
-    ("tokei", "https://github.com/gasuketsu/asdf-tokei.git"),
-    ("tomcat", "https://github.com/mbutov/asdf-tomcat"),
-    ("tonnage", "https://github.com/elementalvoid/asdf-tonnage.git"),
-    ("tool-versions-to-env", "https://github.com/smartcontractkit/tool-versions-to-env-action.git"),
-    ("traefik", "https://github.com/Dabolus/asdf-traefik.git"),
-    ("trdsql", "https://github.com/johnlayton/asdf-trdsql.git"),
-    ("tree-sitter", "https://github.com/ivanvc/asdf-tree-sitter.git"),
-    ("tridentctl", "https://github.com/asdf-community/asdf-tridentctl.git"),
-    ("trivy", "https://github.com/zufardhiyaulhaq/asdf-trivy.git"),
-    ("tsuru", "https://github.com/virtualstaticvoid/asdf-tsuru.git"),
-    ("ttyd", "https://github.com/ivanvc/asdf-ttyd.git"),
-    ("tuist", "https://github.com/cprecioso/asdf-tuist.git"),
-    ("tx", "https://github.com/ORCID/asdf-transifex.git"),
-    ("typos", "https://github.com/aschiavon91/asdf-typos.git"),
-    ("uaa-cli", "https://github.com/vmware-tanzu/tanzu-plug-in-for-asdf.git"),
-    ("unison", "https://github.com/susurri/asdf-unison.git"),
-    ("updatecli", "https://github.com/updatecli/asdf-updatecli.git"),
-    ("upt", "https://github.com/ORCID/asdf-upt.git"),
-    ("upx", "https://github.com/jimmidyson/asdf-upx.git"),
-    ("usql", "https://github.com/itspngu/asdf-usql.git"),
-    ("v", "https://github.com/jthegedus/asdf-v.git"),
-    ("vale", "https://github.com/pdemagny/asdf-vale"),
-    ("vals", "https://github.com/dex4er/asdf-vals.git"),
-    ("vault", "https://github.com/asdf-community/asdf-hashicorp.git"),
-    ("vcluster", "https://gitlab.com/wt0f/asdf-vcluster.git"),
-    ("vela", "https://github.com/pdemagny/asdf-vela"),
-    ("velad", "https://github.com/pdemagny/asdf-velad"),
-    ("velero", "https://github.com/looztra/asdf-velero.git"),
-    ("vendir", "https://github.com/vmware-tanzu/asdf-carvel.git"),
-    ("venom", "https://github.com/aabouzaid/asdf-venom.git"),
-    ("vhs", "https://github.com/chessmango/asdf-vhs.git"),
-    ("viddy", "https://github.com/ryodocx/asdf-viddy.git"),
-    ("vim", "https://github.com/tsuyoshicho/asdf-vim.git"),
-    ("vultr-cli", "https://github.com/ikuradon/asdf-vultr-cli.git"),
-    ("wasi-sdk", "https://github.com/coolreader18/asdf-wasi-sdk.git"),
-    ("wasm3", "https://github.com/tachyonicbytes/asdf-wasm3"),
-    ("wasm4", "https://github.com/jtakakura/asdf-wasm4"),
-    ("wasmer", "https://github.com/tachyonicbytes/asdf-wasmer"),
-    ("wasmtime", "https://github.com/tachyonicbytes/asdf-wasmtime"),
-    ("watchexec", "https://github.com/nyrst/asdf-watchexec.git"),
-    ("waypoint", "https://github.com/asdf-community/asdf-hashicorp.git"),
-    ("weave-gitops", "https://github.com/deas/asdf-weave-gitops"),
-    ("websocat", "https://github.com/bdellegrazie/asdf-websocat.git"),
-    ("wren-cli", "https://github.com/jtakakura/asdf-wren-cli.git"),
-    ("wrk", "https://github.com/ivanvc/asdf-wrk.git"),
-    ("wtfutil", "https://github.com/NeoHsu/asdf-wtfutil.git"),
-    ("xc", "https://github.com/airtonix/asdf-xc"),
-    ("xchtmlreport", "https://github.com/younke/asdf-xchtmlreport.git"),
-    ("xcodegen", "https://github.com/younke/asdf-xcodegen.git"),
-    ("xcodes", "https://github.com/younke/asdf-xcodes.git"),
-    ("xh", "https://github.com/NeoHsu/asdf-xh"),
-    ("yadm", "https://github.com/particledecay/asdf-yadm.git"),
-    ("yamlfmt", "https://github.com/kachick/asdf-yamlfmt.git"),
-    ("yamllint", "https://github.com/ericcornelissen/asdf-yamllint.git"),
-    ("yarn", "https://github.com/twuni/asdf-yarn.git"),
-    ("yay", "https://github.com/aaaaninja/asdf-yay.git"),
-    ("yj", "https://github.com/ryodocx/asdf-yj.git"),
-    ("yor", "https://github.com/ordinaryexperts/asdf-yor"),
-    ("youtube-dl", "https://github.com/iul1an/asdf-youtube-dl"),
-    ("yq", "https://github.com/sudermanjr/asdf-yq.git"),
-    ("yt-dlp", "https://github.com/duhow/asdf-yt-dlp"),
-    ("ytt", "https://github.com/vmware-tanzu/asdf-carvel.git"),
-    ("zbctl", "https://github.com/camunda-community-hub/asdf-zbctl.git"),
-    ("zellij", "https://github.com/chessmango/asdf-zellij.git"),
-    ("zephyr", "https://github.com/nsaunders/asdf-zephyr.git"),
-    ("zig", "https://github.com/cheetah/asdf-zig.git"),
-    ("zigmod", "https://github.com/kachick/asdf-zigmod.git"),
-    ("zola", "https://github.com/salasrod/asdf-zola.git"),
-    ("zoxide", "https://github.com/nyrst/asdf-zoxide"),
-    ("zprint", "https://github.com/carlduevel/asdf-zprint.git"),
-    // rtx custom shorthands
-    ("pipenv", "https://github.com/rtx-plugins/rtx-pipenv.git"),
-    ("poetry", "https://github.com/rtx-plugins/rtx-poetry.git"),
-    ("tiny",   "https://github.com/rtx-plugins/rtx-tiny.git"),
-];
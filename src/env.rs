@@ -39,8 +39,6 @@ pub static RTX_DEFAULT_TOOL_VERSIONS_FILENAME: Lazy<String> = Lazy::new(|| {
 });
 pub static RTX_DEFAULT_CONFIG_FILENAME: Lazy<String> =
     Lazy::new(|| var("RTX_DEFAULT_CONFIG_FILENAME").unwrap_or_else(|_| ".rtx.toml".into()));
-pub static RTX_ENV: Lazy<Option<String>> =
-    Lazy::new(|| var("RTX_ENV").or_else(|_| var("RTX_ENVIRONMENT")).ok());
 pub static RTX_CONFIG_FILE: Lazy<Option<PathBuf>> = Lazy::new(|| var_path("RTX_CONFIG_FILE"));
 pub static RTX_USE_TOML: Lazy<bool> = Lazy::new(|| var_is_true("RTX_USE_TOML"));
 pub static RTX_EXE: Lazy<PathBuf> = Lazy::new(|| current_exe().unwrap_or_else(|_| "rtx".into()));
@@ -315,6 +313,23 @@ fn apply_patches(
     new_env
 }
 
+/// profiles from RTX_ENV, e.g.: `RTX_ENV=ci,linux`
+///
+/// these are the default for `Settings::profiles`, `rtx env --profile` overrides them.
+/// Later profiles take precedence over earlier ones.
+pub fn profiles() -> Vec<String> {
+    let profiles = var("RTX_ENV").or_else(|_| var("RTX_ENVIRONMENT"));
+    split_profiles(&profiles.unwrap_or_default())
+}
+
+pub fn split_profiles(profiles: &str) -> Vec<String> {
+    profiles
+        .split(',')
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
 /// returns true if new runtime versions should not be fetched
 fn prefer_stale(args: &[String]) -> bool {
     let binding = String::new();
@@ -421,6 +436,12 @@ mod tests {
         assert_eq!(new_env.get("baz").unwrap(), "qux");
     }
 
+    #[test]
+    fn test_profiles() {
+        assert_eq!(split_profiles("ci, linux,,"), vec!["ci", "linux"]);
+        assert!(split_profiles("").is_empty());
+    }
+
     #[test]
     fn test_var_path() {
         set_var("RTX_TEST_PATH", "/foo/bar");
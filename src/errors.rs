@@ -11,7 +11,6 @@ pub enum Error {
     #[error("{0}@{1} not installed")]
     VersionNotInstalled(PluginName, String),
     #[error("{0}@{1} not found")]
-    #[allow(dead_code)]
     VersionNotFound(PluginName, String),
     #[error("{} exited with non-zero status: {}", .0, render_exit_status(.1))]
     ScriptFailed(String, Option<ExitStatus>),
@@ -8,6 +8,8 @@ use std::time::Duration;
 use color_eyre::eyre::{Context, Result};
 use filetime::{set_file_times, FileTime};
 use flate2::read::GzDecoder;
+use ignore::WalkBuilder;
+use itertools::Itertools;
 use tar::Archive;
 
 use crate::{cmd, dirs, env};
@@ -166,6 +168,34 @@ pub fn dir_files(dir: &Path) -> Result<Vec<String>> {
     Ok(output)
 }
 
+/// recursively lists all files (not directories) under `dir`, symlinks are not followed
+pub fn recursive_ls(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut output = vec![];
+    if !dir.is_dir() {
+        return Ok(output);
+    }
+    for entry in dir.read_dir()? {
+        let entry = entry?;
+        let ft = entry.file_type()?;
+        if ft.is_dir() {
+            output.extend(recursive_ls(&entry.path())?);
+        } else {
+            output.push(entry.path());
+        }
+    }
+    output.sort();
+    Ok(output)
+}
+
+/// total size of the files under `dir`, symlinks are not followed
+pub fn dir_size(dir: &Path) -> Result<u64> {
+    let mut size = 0;
+    for path in recursive_ls(dir)? {
+        size += path.symlink_metadata()?.len();
+    }
+    Ok(size)
+}
+
 pub fn make_symlink(target: &Path, link: &Path) -> Result<()> {
     trace!("ln -sf {} {}", target.display(), link.display());
     if link.is_file() || link.is_symlink() {
@@ -245,6 +275,28 @@ impl Iterator for FindUp {
     }
 }
 
+/// files named one of `filenames` in `from` or any directory under it
+///
+/// directories ignored by .gitignore (and .git itself) are skipped
+pub fn find_down(from: &Path, filenames: &[String]) -> Vec<PathBuf> {
+    WalkBuilder::new(from)
+        .hidden(false)
+        .filter_entry(|e| e.file_name() != ".git")
+        .build()
+        .filter_map(|e| match e {
+            Ok(e) => Some(e),
+            Err(err) => {
+                debug!("error walking {}: {err:#}", display_path(from));
+                None
+            }
+        })
+        .filter(|e| e.file_type().is_some_and(|ft| ft.is_file()))
+        .filter(|e| filenames.iter().any(|f| e.file_name() == f.as_str()))
+        .map(|e| e.into_path())
+        .sorted()
+        .collect()
+}
+
 pub fn which(name: &str) -> Option<PathBuf> {
     for path in &*env::PATH {
         let bin = path.join(name);
@@ -275,8 +327,6 @@ pub fn unzip(archive: &Path, dest: &Path) -> Result<()> {
 mod tests {
     use std::ops::Deref;
 
-    use itertools::Itertools;
-
     use crate::dirs;
 
     use super::*;
@@ -298,6 +348,19 @@ mod tests {
         assert_eq!(find_up.next(), Some(dirs::HOME.join(".test-tool-versions")));
     }
 
+    #[test]
+    fn test_find_down() {
+        let filenames = vec![".test-tool-versions".to_string()];
+        // test/data is in test/.gitignore
+        assert_eq!(
+            find_down(&dirs::HOME, &filenames),
+            vec![
+                dirs::HOME.join(".test-tool-versions"),
+                dirs::HOME.join("cwd/.test-tool-versions"),
+            ]
+        );
+    }
+
     #[test]
     fn test_find_up_2() {
         let path = &dirs::HOME.join("fixtures");
@@ -3,6 +3,7 @@ use std::path::PathBuf;
 
 use color_eyre::eyre::{eyre, Result};
 use duct::Expression;
+use serde_derive::Serialize;
 
 use crate::cmd;
 use crate::file::touch_dir;
@@ -11,6 +12,21 @@ pub struct Git {
     pub dir: PathBuf,
 }
 
+/// what `Git::update` moved a repo from and to
+#[derive(Debug, Serialize)]
+pub struct GitUpdate {
+    pub from: String,
+    pub to: String,
+    /// commits between `from` and `to`, newest first
+    pub commits: Vec<GitCommit>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GitCommit {
+    pub sha: String,
+    pub subject: String,
+}
+
 macro_rules! git_cmd {
     ( $dir:expr $(, $arg:expr )* $(,)? ) => {
         {
@@ -29,22 +45,25 @@ impl Git {
         self.dir.join(".git").is_dir()
     }
 
+    /// the ref to update to if none is given
+    fn resolve_gitref(&self, gitref: Option<String>) -> Result<String> {
+        match gitref {
+            Some(gitref) => Ok(gitref),
+            None => match self.current_branch()? {
+                // detached from a pinned ref
+                branch if branch.is_empty() => self.default_branch(),
+                branch => Ok(branch),
+            },
+        }
+    }
+
     pub fn update(&self, gitref: Option<String>) -> Result<(String, String)> {
-        let gitref = gitref.map_or_else(|| self.current_branch(), Ok)?;
+        let gitref = self.resolve_gitref(gitref)?;
         debug!("updating {} to {}", self.dir.display(), gitref);
-        let exec = |cmd: Expression| match cmd.stderr_to_stdout().stdout_capture().unchecked().run()
-        {
-            Ok(res) => {
-                if res.status.success() {
-                    Ok(())
-                } else {
-                    Err(eyre!(
-                        "git failed: {cmd:?} {}",
-                        String::from_utf8(res.stdout).unwrap()
-                    ))
-                }
-            }
-            Err(err) => Err(eyre!("git failed: {cmd:?} {err:#}")),
+        // a branch named after a sha would shadow the commit, so those are checked out detached
+        let refspec = match Self::is_full_sha(&gitref) {
+            true => gitref.clone(),
+            false => format!("{}:{}", gitref, gitref),
         };
         exec(git_cmd!(
             &self.dir,
@@ -52,7 +71,7 @@ impl Git {
             "--prune",
             "--update-head-ok",
             "origin",
-            &format!("{}:{}", gitref, gitref),
+            &refspec,
         ))?;
         let prev_rev = self.current_sha()?;
         exec(git_cmd!(
@@ -71,6 +90,30 @@ impl Git {
         Ok((prev_rev, post_rev))
     }
 
+    /// fetches `gitref` without checking it out, returns the sha it points to
+    pub fn fetch(&self, gitref: Option<String>) -> Result<String> {
+        let gitref = self.resolve_gitref(gitref)?;
+        debug!("fetching {} for {}", gitref, self.dir.display());
+        exec(git_cmd!(&self.dir, "fetch", "origin", &gitref))?;
+        let sha = git_cmd!(&self.dir, "rev-parse", "FETCH_HEAD").read()?;
+        Ok(sha)
+    }
+
+    /// commits reachable from `to` but not `from`
+    pub fn log(&self, from: &str, to: &str) -> Result<Vec<GitCommit>> {
+        let range = format!("{from}..{to}");
+        let output = git_cmd!(&self.dir, "log", "--format=%h %s", &range).read()?;
+        let commits = output
+            .lines()
+            .filter_map(|line| line.split_once(' '))
+            .map(|(sha, subject)| GitCommit {
+                sha: sha.to_string(),
+                subject: subject.to_string(),
+            })
+            .collect();
+        Ok(commits)
+    }
+
     pub fn clone(&self, url: &str) -> Result<()> {
         debug!("cloning {} to {}", url, self.dir.display());
         if let Some(parent) = self.dir.parent() {
@@ -92,12 +135,27 @@ impl Git {
         debug!("current branch for {}: {}", self.dir.display(), &branch);
         Ok(branch)
     }
+    /// the branch the remote's HEAD points to, e.g.: main
+    pub fn default_branch(&self) -> Result<String> {
+        let branch = git_cmd!(&self.dir, "rev-parse", "--abbrev-ref", "origin/HEAD").read()?;
+        Ok(branch.trim_start_matches("origin/").to_string())
+    }
+
     pub fn current_sha(&self) -> Result<String> {
         let sha = git_cmd!(&self.dir, "rev-parse", "HEAD").read()?;
         debug!("current sha for {}: {}", self.dir.display(), &sha);
         Ok(sha)
     }
 
+    /// the commit `gitref` points to in the local repo, if it's there
+    pub fn resolve_ref(&self, gitref: &str) -> Option<String> {
+        let commit = format!("{gitref}^{{commit}}");
+        git_cmd!(&self.dir, "rev-parse", "--verify", "--quiet", commit)
+            .stderr_null()
+            .read()
+            .ok()
+    }
+
     pub fn current_sha_short(&self) -> Result<String> {
         let sha = git_cmd!(&self.dir, "rev-parse", "--short", "HEAD").read()?;
         debug!("current sha for {}: {}", self.dir.display(), &sha);
@@ -137,6 +195,27 @@ impl Git {
             None => (url.to_string(), None),
         }
     }
+
+    /// refs like this are treated as a pin to an exact commit
+    pub fn is_full_sha(gitref: &str) -> bool {
+        gitref.len() == 40 && gitref.chars().all(|c| c.is_ascii_hexdigit())
+    }
+}
+
+fn exec(cmd: Expression) -> Result<()> {
+    match cmd.stderr_to_stdout().stdout_capture().unchecked().run() {
+        Ok(res) => {
+            if res.status.success() {
+                Ok(())
+            } else {
+                Err(eyre!(
+                    "git failed: {cmd:?} {}",
+                    String::from_utf8(res.stdout).unwrap()
+                ))
+            }
+        }
+        Err(err) => Err(eyre!("git failed: {cmd:?} {err:#}")),
+    }
 }
 
 fn get_git_version() -> Result<String> {
@@ -31,6 +31,9 @@ pub fn should_exit_early(watch_files: &[PathBuf]) -> bool {
                     if have_config_files_been_modified(&watches, watch_files) {
                         return false;
                     }
+                    if have_bin_paths_been_modified(&watches) {
+                        return false;
+                    }
                     if have_rtx_env_vars_been_modified(&watches) {
                         return false;
                     }
@@ -50,29 +53,35 @@ pub fn should_exit_early(watch_files: &[PathBuf]) -> bool {
     true
 }
 
+/// true if the config files differ from the ones watched by the previous hook-env run,
+/// i.e.: the directory changed or a config file was edited
+pub fn have_config_files_changed(watch_files: &[PathBuf]) -> bool {
+    match env::var("__RTX_WATCH").map(deserialize_watches) {
+        Ok(Ok(watches)) => have_config_files_been_modified(&watches, get_watch_files(watch_files)),
+        _ => true,
+    }
+}
+
 fn have_config_files_been_modified(
     watches: &HookEnvWatches,
     watch_files: BTreeSet<PathBuf>,
 ) -> bool {
-    // make sure they have exactly the same config filenames
+    // make sure that the config files are the same, added or removed ones mean the directory changed
     let watch_keys = watches.files.keys().cloned().collect::<BTreeSet<_>>();
     if watch_keys != watch_files {
         trace!(
             "config files do not match {:?}",
-            watch_keys.symmetric_difference(&watch_files)
+            watch_files.symmetric_difference(&watch_keys)
         );
         return true;
     }
 
     // check the files to see if they've been altered
     for (fp, prev_modtime) in &watches.files {
-        if let Ok(modtime) = fp
-            .metadata()
-            .expect("accessing config file modtime")
-            .modified()
-        {
-            if &modtime != prev_modtime {
-                trace!("config file modified: {:?}", fp);
+        match fp.metadata().and_then(|m| m.modified()) {
+            Ok(modtime) if &modtime == prev_modtime => {}
+            _ => {
+                trace!("watched file modified: {:?}", fp);
                 return true;
             }
         }
@@ -81,6 +90,20 @@ fn have_config_files_been_modified(
     false
 }
 
+/// true if a tool's bin path changed, e.g.: `go install` added a bin that needs a shim
+fn have_bin_paths_been_modified(watches: &HookEnvWatches) -> bool {
+    for (p, prev_modtime) in &watches.bin_paths {
+        match p.metadata().and_then(|m| m.modified()) {
+            Ok(modtime) if &modtime == prev_modtime => {}
+            _ => {
+                trace!("bin path modified: {:?}", p);
+                return true;
+            }
+        }
+    }
+    false
+}
+
 fn have_rtx_env_vars_been_modified(watches: &HookEnvWatches) -> bool {
     if get_rtx_env_vars_hashed() != watches.env_var_hash {
         return true;
@@ -92,6 +115,8 @@ fn have_rtx_env_vars_been_modified(watches: &HookEnvWatches) -> bool {
 pub struct HookEnvWatches {
     files: BTreeMap<PathBuf, SystemTime>,
     env_var_hash: String,
+    #[serde(default)]
+    bin_paths: BTreeMap<PathBuf, SystemTime>,
 }
 
 pub fn serialize_watches(watches: &HookEnvWatches) -> Result<String> {
@@ -109,15 +134,20 @@ pub fn deserialize_watches(raw: String) -> Result<HookEnvWatches> {
     Ok(rmp_serde::from_slice(&writer[..])?)
 }
 
-pub fn build_watches(watch_files: &[PathBuf]) -> Result<HookEnvWatches> {
+pub fn build_watches(watch_files: &[PathBuf], bin_paths: &[PathBuf]) -> Result<HookEnvWatches> {
     let mut watches = BTreeMap::new();
     for cf in get_watch_files(watch_files) {
         watches.insert(cf.clone(), cf.metadata()?.modified()?);
     }
+    let bin_paths = bin_paths
+        .iter()
+        .filter_map(|p| Some((p.clone(), p.metadata().ok()?.modified().ok()?)))
+        .collect();
 
     Ok(HookEnvWatches {
         files: watches,
         env_var_hash: get_rtx_env_vars_hashed(),
+        bin_paths,
     })
 }
 
@@ -134,7 +164,7 @@ pub fn get_watch_files(watch_files: &[PathBuf]) -> BTreeSet<PathBuf> {
 }
 
 /// gets a hash of all RTX_ environment variables
-fn get_rtx_env_vars_hashed() -> String {
+pub fn get_rtx_env_vars_hashed() -> String {
     let env_vars: Vec<(&String, &String)> = env::PRISTINE_ENV
         .deref()
         .iter()
@@ -185,6 +215,7 @@ mod tests {
         let watches = HookEnvWatches {
             files: BTreeMap::new(),
             env_var_hash: "".into(),
+            bin_paths: BTreeMap::new(),
         };
         assert!(!have_config_files_been_modified(&watches, files));
 
@@ -192,6 +223,7 @@ mod tests {
         let watches = HookEnvWatches {
             files: BTreeMap::from([(fp.clone(), UNIX_EPOCH)]),
             env_var_hash: "".into(),
+            bin_paths: BTreeMap::new(),
         };
         let files = BTreeSet::from([fp.clone()]);
         assert!(have_config_files_been_modified(&watches, files));
@@ -200,9 +232,47 @@ mod tests {
         let watches = HookEnvWatches {
             files: BTreeMap::from([(fp.clone(), modtime)]),
             env_var_hash: "".into(),
+            bin_paths: BTreeMap::new(),
+        };
+        let files = BTreeSet::from([fp.clone()]);
+        assert!(!have_config_files_been_modified(&watches, files));
+
+        // leaving a directory removes its config files from the watch list
+        let parent = dirs::HOME.join(".test-tool-versions");
+        let parent_modtime = parent.metadata().unwrap().modified().unwrap();
+        let watches = HookEnvWatches {
+            files: BTreeMap::from([(fp.clone(), modtime), (parent.clone(), parent_modtime)]),
+            env_var_hash: "".into(),
+            bin_paths: BTreeMap::new(),
+        };
+        let files = BTreeSet::from([parent]);
+        assert!(have_config_files_been_modified(&watches, files));
+
+        // a watched path that no longer exists counts as modified
+        let watches = HookEnvWatches {
+            files: BTreeMap::from([(fp.clone(), modtime), ("/missing".into(), modtime)]),
+            env_var_hash: "".into(),
+            bin_paths: BTreeMap::new(),
+        };
+        let files = BTreeSet::from([fp.clone(), "/missing".into()]);
+        assert!(have_config_files_been_modified(&watches, files));
+
+        // bin paths are tracked separately from config files
+        let bin = dirs::HOME.join("fixtures");
+        let bin_modtime = bin.metadata().unwrap().modified().unwrap();
+        let watches = HookEnvWatches {
+            files: BTreeMap::from([(fp.clone(), modtime)]),
+            env_var_hash: "".into(),
+            bin_paths: BTreeMap::from([(bin.clone(), bin_modtime)]),
         };
         let files = BTreeSet::from([fp]);
         assert!(!have_config_files_been_modified(&watches, files));
+        assert!(!have_bin_paths_been_modified(&watches));
+        let watches = HookEnvWatches {
+            bin_paths: BTreeMap::from([(bin, UNIX_EPOCH)]),
+            ..watches
+        };
+        assert!(have_bin_paths_been_modified(&watches));
     }
 
     #[test]
@@ -210,6 +280,7 @@ mod tests {
         let watches = HookEnvWatches {
             files: BTreeMap::new(),
             env_var_hash: "".into(),
+            bin_paths: BTreeMap::new(),
         };
         let serialized = serialize_watches(&watches).unwrap();
         let deserialized = deserialize_watches(serialized).unwrap();
@@ -221,6 +292,7 @@ mod tests {
         let serialized = serialize_watches(&HookEnvWatches {
             files: BTreeMap::from([("foo".into(), UNIX_EPOCH)]),
             env_var_hash: "testing-123".into(),
+            bin_paths: BTreeMap::new(),
         })
         .unwrap();
         let deserialized = deserialize_watches(serialized).unwrap();
@@ -0,0 +1,100 @@
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use color_eyre::eyre::Result;
+
+use crate::toolset::ToolVersion;
+use crate::{dirs, file};
+
+/// when installed versions were last used by a shim or `rtx exec`
+///
+/// Each version has an empty file in ~/.local/share/rtx/last-used/<PLUGIN>/<VERSION>
+/// which is touched on every use. `rtx prune --used-within` uses these to keep
+/// versions that are still in use.
+pub fn touch(tv: &ToolVersion) {
+    if let Some(path) = last_used_path(&tv.install_path()) {
+        touch_path(&path);
+    }
+}
+
+/// records a use of the version `bin` is installed in
+pub fn touch_bin(bin: &Path) {
+    let Ok(bin) = bin.canonicalize() else {
+        return;
+    };
+    let Ok(installs) = dirs::INSTALLS.canonicalize() else {
+        return;
+    };
+    if let Some(path) = bin
+        .strip_prefix(installs)
+        .ok()
+        .and_then(|p| last_used_path(&dirs::INSTALLS.join(p)))
+    {
+        touch_path(&path);
+    }
+}
+
+/// when `tv` was last used, or when it was installed if it hasn't been used since
+pub fn get(tv: &ToolVersion) -> Option<SystemTime> {
+    let install_path = tv.install_path();
+    last_used_path(&install_path)
+        .into_iter()
+        .chain([install_path])
+        .find_map(|p| p.metadata().and_then(|m| m.modified()).ok())
+}
+
+pub fn remove(tv: &ToolVersion) -> Result<()> {
+    match last_used_path(&tv.install_path()) {
+        Some(path) => file::remove_all(path),
+        None => Ok(()),
+    }
+}
+
+/// maps an install path (or anything under it) to its last-used file
+fn last_used_path(install_path: &Path) -> Option<PathBuf> {
+    let mut components = install_path
+        .strip_prefix(&*dirs::INSTALLS)
+        .ok()?
+        .components();
+    let (plugin, version) = (components.next()?, components.next()?);
+    Some(dirs::DATA.join("last-used").join(plugin).join(version))
+}
+
+fn touch_path(path: &Path) {
+    let touch = || -> Result<()> {
+        if path.exists() {
+            return file::touch_dir(path);
+        }
+        file::create_dir_all(path.parent().unwrap())?;
+        file::write(path, "")
+    };
+    if let Err(err) = touch() {
+        debug!("failed to record last use: {err:#}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::plugins::PluginName;
+    use crate::toolset::ToolVersionRequest;
+
+    use super::*;
+
+    #[test]
+    fn test_last_used() {
+        let plugin_name: PluginName = "tiny".into();
+        let tv = ToolVersion {
+            request: ToolVersionRequest::new(plugin_name.clone(), "3.1.0"),
+            plugin_name,
+            version: "3.1.0".into(),
+            opts: Default::default(),
+        };
+        remove(&tv).unwrap();
+        let installed = get(&tv).unwrap();
+        touch_bin(&tv.install_path().join("bin").join("rtx-tiny"));
+        assert!(dirs::DATA.join("last-used/tiny/3.1.0").exists());
+        assert!(get(&tv).unwrap() >= installed);
+        remove(&tv).unwrap();
+        assert!(!dirs::DATA.join("last-used/tiny/3.1.0").exists());
+    }
+}
@@ -30,7 +30,6 @@ pub mod build_time;
 mod cache;
 mod cli;
 mod config;
-mod default_shorthands;
 mod direnv;
 mod dirs;
 pub mod duration;
@@ -45,6 +44,7 @@ mod hash;
 mod hook_env;
 mod http;
 mod install_context;
+mod last_used;
 mod lock_file;
 mod logger;
 mod migrate;
@@ -53,6 +53,7 @@ mod plugins;
 mod rand;
 mod runtime_symlinks;
 mod shell;
+mod shim_cache;
 mod shims;
 mod shorthands;
 pub mod tera;
@@ -86,6 +87,7 @@ fn run(args: &Vec<String>) -> Result<()> {
     cli::version::print_version_if_requested(&env::ARGS, out);
     migrate::run();
 
+    shims::handle_shim_from_cache(args, out)?;
     let config = Config::load()?;
     let config = shims::handle_shim(config, args, out)?;
     if config.should_exit_early {
@@ -0,0 +1,48 @@
+# installed by rtx into site-packages and loaded by rtx_reshim.pth
+# runs `rtx reshim` after pip adds or removes executables so they get shims
+import atexit
+import os
+import subprocess
+import sys
+
+# replaced with the path to rtx when this file is installed
+RTX_EXE = "__RTX_EXE__"
+
+# <install>/lib/pythonX.Y/site-packages/_rtx_reshim.py -> <install>/bin
+BIN_DIR = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "..", "..", "bin")
+
+
+def _is_pip():
+    if not sys.argv or not sys.argv[0]:
+        return False
+    prog = sys.argv[0]
+    # `pip install` or `python -m pip install`
+    return os.path.basename(prog).startswith("pip") or os.sep + "pip" + os.sep in prog
+
+
+def _bins():
+    try:
+        return set(os.listdir(BIN_DIR))
+    except OSError:
+        return set()
+
+
+def _reshim(before):
+    if _bins() == before:
+        return
+    if os.environ.get("RTX_DEBUG"):
+        sys.stderr.write("[DEBUG] rtx reshim\n")
+    try:
+        rtx = RTX_EXE if os.path.exists(RTX_EXE) else "rtx"
+        subprocess.run([rtx, "reshim"], check=False)
+    except OSError:
+        pass
+
+
+# this runs on every interpreter start so anything but pip install/uninstall returns right away
+if (
+    not os.environ.get("RTX_SKIP_RESHIM")
+    and _is_pip()
+    and any(arg in ("install", "uninstall") for arg in sys.argv[1:])
+):
+    atexit.register(_reshim, _bins())
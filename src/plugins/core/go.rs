@@ -149,6 +149,18 @@ impl Plugin for GoPlugin {
         Ok(vec![".go-version".into()])
     }
 
+    fn manifest_filenames(&self) -> Vec<String> {
+        vec!["go.mod".into()]
+    }
+
+    fn parse_legacy_file(&self, path: &Path, _settings: &Settings) -> Result<String> {
+        let body = file::read_to_string(path)?;
+        if path.file_name().is_some_and(|f| f == "go.mod") {
+            return Ok(parse_go_mod(&body));
+        }
+        Ok(body.trim().to_string())
+    }
+
     fn install_version_impl(&self, ctx: &InstallContext) -> Result<()> {
         let tarball_path = self.download(&ctx.tv, &ctx.pr)?;
         self.install(&ctx.tv, &ctx.pr, &tarball_path)?;
@@ -198,6 +210,12 @@ impl Plugin for GoPlugin {
             (Some(true), _) | (None, None) => {
                 let gopath = self.gopath(tv).to_string_lossy().to_string();
                 map.insert("GOPATH".to_string(), gopath);
+                // a GOBIN set by the user sends `go install` bins outside of the watched bin paths
+                // but that's where they asked for them to go
+                if !env::PRISTINE_ENV.contains_key("GOBIN") && !go_env_sets_gobin() {
+                    let gobin = self.gopath(tv).join("bin").to_string_lossy().to_string();
+                    map.insert("GOBIN".to_string(), gobin);
+                }
             }
         };
         Ok(map)
@@ -226,3 +244,77 @@ fn arch() -> &'static str {
         &ARCH
     }
 }
+
+/// the "toolchain" directive if set, otherwise the minimum version from the "go" directive
+fn parse_go_mod(body: &str) -> String {
+    let directive = |name: &str| {
+        body.lines()
+            .filter_map(|l| l.split("//").next()?.trim().strip_prefix(name))
+            .find(|v| v.starts_with(char::is_whitespace))
+            .map(|v| v.trim().to_string())
+    };
+    directive("toolchain")
+        .filter(|v| v != "default")
+        .map(|v| v.trim_start_matches("go").to_string())
+        .or_else(|| directive("go"))
+        .unwrap_or_default()
+}
+
+/// true if GOBIN was set with `go env -w`, checked in its file since `go env` is too slow to run here
+fn go_env_sets_gobin() -> bool {
+    let path = match env::PRISTINE_ENV.get("GOENV") {
+        Some(goenv) if goenv == "off" => return false,
+        Some(goenv) => PathBuf::from(goenv),
+        // os.UserConfigDir()
+        None if cfg!(target_os = "macos") => env::HOME.join("Library/Application Support/go/env"),
+        None => env::XDG_CONFIG_HOME.join("go/env"),
+    };
+    file::read_to_string(path).is_ok_and(|body| env_file_sets_gobin(&body))
+}
+
+fn env_file_sets_gobin(body: &str) -> bool {
+    body.lines()
+        .filter_map(|l| l.split_once('='))
+        .any(|(k, v)| k.trim() == "GOBIN" && !v.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_file_sets_gobin() {
+        assert!(env_file_sets_gobin("GOPROXY=direct\nGOBIN=/home/me/bin\n"));
+        assert!(!env_file_sets_gobin("GOPROXY=direct\nGOBIN=\n"));
+        assert!(!env_file_sets_gobin(""));
+    }
+
+    #[test]
+    fn test_parse_go_mod() {
+        assert_eq!(
+            parse_go_mod(indoc! {r#"
+            module example.com/foo
+
+            go 1.21
+        "#}),
+            "1.21"
+        );
+        assert_eq!(
+            parse_go_mod(indoc! {r#"
+            module example.com/foo
+
+            go 1.21 // minimum
+            toolchain go1.21.5
+        "#}),
+            "1.21.5"
+        );
+        assert_eq!(
+            parse_go_mod(indoc! {r#"
+            module example.com/foo
+
+            require golang.org/x/mod v0.14.0
+        "#}),
+            ""
+        );
+    }
+}
@@ -34,6 +34,19 @@ mod ruby;
 
 pub type PluginMap = BTreeMap<PluginName, Arc<dyn Plugin>>;
 
+/// dirs core plugins keep in their cache dir for themselves rather than for a version
+const PLUGIN_CACHE_DIRS: [(&str, &str); 4] = [
+    ("node", "node-build"),
+    ("python", "pyenv"),
+    ("ruby", "ruby-build"),
+    ("ruby", "ruby-install"),
+];
+
+/// true if `dir` in the plugin's cache dir belongs to the plugin, e.g.: python's copy of pyenv
+pub fn is_plugin_cache_dir(plugin_name: &str, dir: &str) -> bool {
+    PLUGIN_CACHE_DIRS.contains(&(plugin_name, dir))
+}
+
 pub static CORE_PLUGINS: Lazy<PluginMap> = Lazy::new(|| {
     let plugins: Vec<Arc<dyn Plugin>> = vec![
         Arc::new(GoPlugin::new()),
@@ -1,7 +1,8 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
 use color_eyre::eyre::Result;
+use itertools::Itertools;
 use serde_derive::Deserialize;
 use tempfile::tempdir_in;
 use url::Url;
@@ -10,12 +11,13 @@ use crate::build_time::built_info;
 use crate::cmd::CmdLineRunner;
 use crate::config::{Config, Settings};
 use crate::env::{RTX_FETCH_REMOTE_VERSIONS_TIMEOUT, RTX_NODE_MIRROR_URL};
+use crate::file::display_path;
 use crate::install_context::InstallContext;
 use crate::plugins::core::CorePlugin;
 use crate::plugins::Plugin;
-use crate::toolset::ToolVersion;
+use crate::toolset::{ToolVersion, Toolset, VersionRange};
 use crate::ui::progress_report::ProgressReport;
-use crate::{env, file, hash, http};
+use crate::{dirs, env, file, hash, http};
 
 #[derive(Debug)]
 pub struct NodePlugin {
@@ -176,7 +178,7 @@ impl NodePlugin {
 
     fn install_npm_shim(&self, tv: &ToolVersion) -> Result<()> {
         file::remove_file(self.npm_path(tv)).ok();
-        file::write(self.npm_path(tv), include_str!("assets/node_npm_shim"))?;
+        file::write(self.npm_path(tv), npm_shim())?;
         file::make_executable(&self.npm_path(tv))?;
         Ok(())
     }
@@ -251,13 +253,21 @@ impl Plugin for NodePlugin {
         Ok(vec![".node-version".into(), ".nvmrc".into()])
     }
 
+    fn manifest_filenames(&self) -> Vec<String> {
+        vec!["package.json".into()]
+    }
+
     fn parse_legacy_file(&self, path: &Path, _settings: &Settings) -> Result<String> {
-        let body = file::read_to_string(path)?;
-        // trim "v" prefix
-        let body = body.trim().strip_prefix('v').unwrap_or(&body);
-        // replace lts/* with lts
-        let body = body.replace("lts/*", "lts");
-        Ok(body)
+        parse_legacy_file(path)
+    }
+
+    fn exec_env(
+        &self,
+        _config: &Config,
+        _ts: &Toolset,
+        tv: &ToolVersion,
+    ) -> Result<HashMap<String, String>> {
+        Ok(exec_env(tv))
     }
 
     fn install_version_impl(&self, ctx: &InstallContext) -> Result<()> {
@@ -380,3 +390,163 @@ fn arch() -> &'static str {
 struct NodeVersion {
     version: String,
 }
+
+/// the npm wrapper that reshims after global installs
+pub fn npm_shim() -> String {
+    include_str!("assets/node_npm_shim").replace(
+        "__RTX_EXE__",
+        &env::RTX_EXE.to_string_lossy().replace('\'', "'\\''"),
+    )
+}
+
+/// points npm's global prefix at the install so `npm i -g` bins land in a path rtx reshims,
+/// unless the user set a prefix in the environment or an .npmrc
+pub fn exec_env(tv: &ToolVersion) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    let user_prefix = env::PRISTINE_ENV
+        .keys()
+        .any(|k| k.eq_ignore_ascii_case("npm_config_prefix"))
+        || npmrc_files()
+            .into_iter()
+            .filter_map(|f| file::read_to_string(f).ok())
+            .any(|body| npmrc_sets_prefix(&body));
+    if !user_prefix {
+        let prefix = tv.install_path().to_string_lossy().to_string();
+        map.insert("npm_config_prefix".to_string(), prefix);
+    }
+    map
+}
+
+/// the user's .npmrc and the closest project .npmrc, reading these is cheaper than `npm config get`
+fn npmrc_files() -> Vec<PathBuf> {
+    let userconfig = env::PRISTINE_ENV
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("npm_config_userconfig"))
+        .map(|(_, v)| PathBuf::from(v))
+        .unwrap_or_else(|| env::HOME.join(".npmrc"));
+    let project = file::FindUp::new(&dirs::CURRENT, &[".npmrc".to_string()]).next();
+    [Some(userconfig), project].into_iter().flatten().collect()
+}
+
+fn npmrc_sets_prefix(body: &str) -> bool {
+    body.lines()
+        .filter_map(|l| l.split_once('='))
+        .any(|(k, _)| k.trim() == "prefix")
+}
+
+pub fn parse_legacy_file(path: &Path) -> Result<String> {
+    let body = file::read_to_string(path)?;
+    if path.file_name().is_some_and(|f| f == "package.json") {
+        return Ok(parse_package_json(&body).unwrap_or_else(|e| {
+            warn!("failed to parse {}: {e}", display_path(path));
+            String::new()
+        }));
+    }
+    // trim "v" prefix
+    let body = body.trim().strip_prefix('v').unwrap_or(&body);
+    // replace lts/* with lts
+    let body = body.replace("lts/*", "lts");
+    Ok(body)
+}
+
+/// reads the node version from volta.node, engines.node or a "node@" packageManager
+fn parse_package_json(body: &str) -> Result<String> {
+    let pkg: serde_json::Value = serde_json::from_str(body)?;
+    let version = pkg
+        .pointer("/volta/node")
+        .or_else(|| pkg.pointer("/engines/node"))
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string())
+        .or_else(|| {
+            pkg.get("packageManager")
+                .and_then(|v| v.as_str())
+                .and_then(|v| v.strip_prefix("node@"))
+                .map(|v| v.split('+').next().unwrap().to_string())
+        });
+    let version = match version {
+        Some(v) => v,
+        None => return Ok(String::new()),
+    };
+    let version = version.trim();
+    let version = if VersionRange::is_range(version) {
+        // legacy files are split on whitespace so join the comparators with commas instead
+        // and attach operators to their version, e.g.: "^18 || >= 20" -> "^18||>=20"
+        version
+            .split("||")
+            .map(|alt| {
+                let mut comparators: Vec<String> = vec![];
+                let mut pending_op = String::new();
+                for token in alt.split_whitespace() {
+                    if token
+                        .chars()
+                        .all(|c| matches!(c, '^' | '~' | '>' | '<' | '='))
+                    {
+                        pending_op.push_str(token);
+                    } else {
+                        comparators.push(format!("{pending_op}{token}"));
+                        pending_op.clear();
+                    }
+                }
+                comparators.join(",")
+            })
+            .join("||")
+    } else {
+        // "20.x" or "20" as a prefix
+        version
+            .trim_start_matches('v')
+            .trim_end_matches(['x', 'X', '*'])
+            .trim_end_matches('.')
+            .to_string()
+    };
+    Ok(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_npm_shim() {
+        let shim = npm_shim();
+        assert!(!shim.contains("__RTX_EXE__"));
+        assert!(shim.contains(&*env::RTX_EXE.to_string_lossy()));
+    }
+
+    #[test]
+    fn test_npmrc_sets_prefix() {
+        assert!(npmrc_sets_prefix(
+            "registry=https://example.com\nprefix = ~/.npm-global\n"
+        ));
+        assert!(!npmrc_sets_prefix(
+            "; prefix=~/.npm-global\nregistry=https://example.com\n"
+        ));
+    }
+
+    #[test]
+    fn test_parse_package_json() {
+        let parse = |s: &str| parse_package_json(s).unwrap();
+        assert_eq!(parse(r#"{"engines": {"node": ">=18 <21"}}"#), ">=18,<21");
+        assert_eq!(
+            parse(r#"{"engines": {"node": "^18 || >= 20"}}"#),
+            "^18||>=20"
+        );
+        assert_eq!(
+            parse(r#"{"engines": {"node": ">= 18 < 21 || 22"}}"#),
+            ">=18,<21||22"
+        );
+        let range =
+            VersionRange::parse(&parse(r#"{"engines": {"node": "^18 || >= 20"}}"#)).unwrap();
+        assert!(range.matches("18.1.0") && range.matches("21.0.0") && !range.matches("19.0.0"));
+        assert_eq!(parse(r#"{"engines": {"node": "20.x"}}"#), "20");
+        assert_eq!(
+            parse(r#"{"engines": {"node": ">=18"}, "volta": {"node": "20.9.0"}}"#),
+            "20.9.0"
+        );
+        assert_eq!(
+            parse(r#"{"packageManager": "node@20.1.0+sha256.abc"}"#),
+            "20.1.0"
+        );
+        assert_eq!(parse(r#"{"packageManager": "pnpm@8.6.0"}"#), "");
+        assert_eq!(parse(r#"{"name": "foo"}"#), "");
+    }
+}
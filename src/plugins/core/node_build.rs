@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use std::process::exit;
 
@@ -13,9 +13,9 @@ use crate::file::create_dir_all;
 use crate::git::Git;
 use crate::install_context::InstallContext;
 use crate::lock_file::LockFile;
-use crate::plugins::core::CorePlugin;
+use crate::plugins::core::{node, CorePlugin};
 use crate::plugins::Plugin;
-use crate::toolset::{ToolVersion, ToolVersionRequest};
+use crate::toolset::{ToolVersion, ToolVersionRequest, Toolset};
 use crate::ui::progress_report::ProgressReport;
 use crate::{cmd, env, file};
 
@@ -133,7 +133,7 @@ impl NodeBuildPlugin {
 
     fn install_npm_shim(&self, tv: &ToolVersion) -> Result<()> {
         file::remove_file(self.npm_path(tv)).ok();
-        file::write(self.npm_path(tv), include_str!("assets/node_npm_shim"))?;
+        file::write(self.npm_path(tv), node::npm_shim())?;
         file::make_executable(&self.npm_path(tv))?;
         Ok(())
     }
@@ -212,13 +212,21 @@ impl Plugin for NodeBuildPlugin {
         Ok(vec![".node-version".into(), ".nvmrc".into()])
     }
 
+    fn manifest_filenames(&self) -> Vec<String> {
+        vec!["package.json".into()]
+    }
+
     fn parse_legacy_file(&self, path: &Path, _settings: &Settings) -> Result<String> {
-        let body = file::read_to_string(path)?;
-        // trim "v" prefix
-        let body = body.trim().strip_prefix('v').unwrap_or(&body);
-        // replace lts/* with lts
-        let body = body.replace("lts/*", "lts");
-        Ok(body)
+        node::parse_legacy_file(path)
+    }
+
+    fn exec_env(
+        &self,
+        _config: &Config,
+        _ts: &Toolset,
+        tv: &ToolVersion,
+    ) -> Result<HashMap<String, String>> {
+        Ok(node::exec_env(tv))
     }
 
     fn external_commands(&self) -> Result<Vec<Command>> {
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
 
 use crate::cmd::CmdLineRunner;
 use crate::config::{Config, Settings};
@@ -10,7 +11,7 @@ use crate::git::Git;
 use crate::install_context::InstallContext;
 use crate::plugins::core::CorePlugin;
 use crate::plugins::Plugin;
-use crate::toolset::{ToolVersion, ToolVersionRequest, Toolset};
+use crate::toolset::{ToolVersion, ToolVersionRequest, Toolset, VersionRange};
 use crate::ui::progress_report::ProgressReport;
 use crate::{cmd, env, file, http};
 
@@ -144,6 +145,18 @@ impl PythonPlugin {
         Ok(())
     }
 
+    fn install_reshim_hook(&self, tv: &ToolVersion) -> Result<()> {
+        let lib = tv.install_path().join("lib");
+        for dir in file::dir_subdirs(&lib)? {
+            let site_packages = lib.join(&dir).join("site-packages");
+            if !dir.starts_with("python") || !site_packages.exists() {
+                continue;
+            }
+            write_reshim_hook(&site_packages, &env::RTX_EXE)?;
+        }
+        Ok(())
+    }
+
     fn test_python(&self, config: &Config, tv: &ToolVersion, pr: &ProgressReport) -> Result<()> {
         pr.set_message("python --version");
         CmdLineRunner::new(&config.settings, self.python_path(tv))
@@ -169,6 +182,21 @@ impl Plugin for PythonPlugin {
         Ok(vec![".python-version".to_string()])
     }
 
+    fn manifest_filenames(&self) -> Vec<String> {
+        vec!["pyproject.toml".to_string()]
+    }
+
+    fn parse_legacy_file(&self, path: &Path, _settings: &Settings) -> Result<String> {
+        let body = file::read_to_string(path)?;
+        if path.file_name().is_some_and(|f| f == "pyproject.toml") {
+            return Ok(parse_pyproject(&body).unwrap_or_else(|e| {
+                warn!("failed to parse {}: {e}", display_path(path));
+                String::new()
+            }));
+        }
+        Ok(body.trim().to_string())
+    }
+
     fn install_version_impl(&self, ctx: &InstallContext) -> Result<()> {
         self.install_python_build()?;
         if matches!(&ctx.tv.request, ToolVersionRequest::Ref(..)) {
@@ -208,6 +236,7 @@ impl Plugin for PythonPlugin {
             warn!("failed to get virtualenv: {e}");
         }
         self.install_default_packages(ctx.config, &ctx.tv, &ctx.pr)?;
+        self.install_reshim_hook(&ctx.tv)?;
         Ok(())
     }
 
@@ -237,3 +266,133 @@ impl Plugin for PythonPlugin {
         Ok(hm)
     }
 }
+
+/// reads project.requires-python (or poetry's python dependency) and converts it from
+/// PEP 440 specifiers into a range rtx understands
+fn parse_pyproject(body: &str) -> Result<String> {
+    let pyproject: toml::Value = toml::from_str(body)?;
+    let spec = ["project.requires-python", "tool.poetry.dependencies.python"]
+        .iter()
+        .find_map(|key| {
+            key.split('.')
+                .try_fold(&pyproject, |v, k| v.get(k))
+                .and_then(|v| v.as_str())
+        });
+    let spec = match spec {
+        Some(spec) => spec,
+        None => return Ok(String::new()),
+    };
+    let spec = spec
+        .split(',')
+        .map(|s| s.split_whitespace().join(""))
+        // rtx ranges have no way to exclude a version so "!=" is dropped
+        .filter(|s| !s.is_empty() && !s.starts_with("!="))
+        .map(|s| match s.strip_prefix("~=") {
+            // ~=3.9 is ">=3.9,<4" and ~=3.9.1 is ">=3.9.1,<3.10"
+            Some(v) if v.split('.').count() > 2 => format!("~{v}"),
+            Some(v) => format!("^{v}"),
+            None => s
+                .trim_start_matches("==")
+                .trim_start_matches('=')
+                .to_string(),
+        })
+        .collect_vec();
+    if spec.iter().all(|s| !VersionRange::is_range(s)) && spec.len() == 1 {
+        // a single exact or wildcard version like "==3.11.*"
+        return Ok(spec[0].trim_end_matches(".*").to_string());
+    }
+    Ok(spec
+        .into_iter()
+        .map(|s| match VersionRange::is_range(&s) {
+            true => s,
+            false => format!("={s}"),
+        })
+        .join(","))
+}
+
+/// makes pip run `rtx reshim` when it adds or removes bins, see assets/python_reshim.py
+fn write_reshim_hook(site_packages: &Path, rtx_exe: &Path) -> Result<()> {
+    let rtx_exe = serde_json::to_string(&rtx_exe.to_string_lossy())?;
+    let hook = include_str!("assets/python_reshim.py").replace("\"__RTX_EXE__\"", &rtx_exe);
+    file::write(site_packages.join("_rtx_reshim.py"), hook)?;
+    file::write(site_packages.join("rtx_reshim.pth"), "import _rtx_reshim\n")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reshim_hook() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+        let site_packages = root.join("lib/python3.12/site-packages");
+        let bin = root.join("bin");
+        let reshims = root.join("reshims");
+        file::create_dir_all(&site_packages).unwrap();
+        file::create_dir_all(&bin).unwrap();
+        file::create_dir_all(root.join("scripts")).unwrap();
+        let rtx = root.join("rtx");
+        file::write(
+            &rtx,
+            format!("#!/bin/sh\necho \"$@\" >> {}\n", reshims.display()),
+        )
+        .unwrap();
+        file::make_executable(&rtx).unwrap();
+        write_reshim_hook(&site_packages, &rtx).unwrap();
+
+        // stands in for pip, the hook is loaded like it would be from the install's site-packages
+        let pip = root.join("scripts/pip");
+        file::write(
+            &pip,
+            formatdoc! {r#"
+            import os, site, sys
+            site.addsitedir({site_packages})
+            if "--add-bin" in sys.argv:
+                open(os.path.join({bin}, "newbin"), "w").close()
+            "#,
+                site_packages = serde_json::to_string(&site_packages).unwrap(),
+                bin = serde_json::to_string(&bin).unwrap(),
+            },
+        )
+        .unwrap();
+        let python = |args: &[&str]| {
+            let mut cmd_args = vec![pip.to_string_lossy().to_string()];
+            cmd_args.extend(args.iter().map(|a| a.to_string()));
+            cmd::cmd("python3", cmd_args)
+                .env_remove("RTX_SKIP_RESHIM")
+                .run()
+                .unwrap();
+            file::read_to_string(&reshims).unwrap_or_default()
+        };
+        assert_eq!(python(&["install", "foo"]), "");
+        assert_eq!(python(&["list", "--add-bin"]), "");
+        file::remove_file(bin.join("newbin")).unwrap();
+        assert_eq!(python(&["install", "--add-bin"]), "reshim\n");
+    }
+
+    #[test]
+    fn test_parse_pyproject() {
+        let parse = |s: &str| parse_pyproject(s).unwrap();
+        assert_eq!(
+            parse("[project]\nrequires-python = \">=3.9, <3.12\""),
+            ">=3.9,<3.12"
+        );
+        assert_eq!(parse("[project]\nrequires-python = \"~=3.10\""), "^3.10");
+        assert_eq!(
+            parse("[project]\nrequires-python = \"~=3.10.2\""),
+            "~3.10.2"
+        );
+        assert_eq!(parse("[project]\nrequires-python = \"==3.11.*\""), "3.11");
+        assert_eq!(
+            parse("[project]\nrequires-python = \">=3.8,!=3.9.0\""),
+            ">=3.8"
+        );
+        assert_eq!(
+            parse("[tool.poetry.dependencies]\npython = \"^3.11\""),
+            "^3.11"
+        );
+        assert_eq!(parse("[project]\nname = \"foo\""), "");
+    }
+}
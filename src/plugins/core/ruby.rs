@@ -331,9 +331,16 @@ impl Plugin for RubyPlugin {
         Ok(vec![".ruby-version".into(), "Gemfile".into()])
     }
 
+    fn manifest_filenames(&self) -> Vec<String> {
+        vec!["Gemfile.lock".into()]
+    }
+
     fn parse_legacy_file(&self, path: &Path, _settings: &Settings) -> Result<String> {
         let v = match path.file_name() {
             Some(name) if name == "Gemfile" => parse_gemfile(&file::read_to_string(path)?),
+            Some(name) if name == "Gemfile.lock" => {
+                parse_gemfile_lock(&file::read_to_string(path)?)
+            }
             _ => {
                 // .ruby-version
                 let body = file::read_to_string(path)?;
@@ -412,6 +419,22 @@ fn parse_gemfile(body: &str) -> String {
     v
 }
 
+/// reads the "RUBY VERSION" section written by bundler, e.g.: "ruby 3.2.2p53"
+fn parse_gemfile_lock(body: &str) -> String {
+    let line = body
+        .lines()
+        .skip_while(|l| l.trim() != "RUBY VERSION")
+        .nth(1)
+        .unwrap_or_default();
+    match regex!(r"^\s*ruby ([0-9.]+)(p\d+)?(?: \((\w+) ([0-9.]+)\))?\s*$").captures(line) {
+        Some(caps) => match (caps.get(3), caps.get(4)) {
+            (Some(engine), Some(v)) => format!("{}-{}", engine.as_str(), v.as_str()),
+            _ => caps[1].to_string(),
+        },
+        None => "".to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -451,4 +474,35 @@ mod tests {
             ""
         );
     }
+
+    #[test]
+    fn test_parse_gemfile_lock() {
+        assert_eq!(
+            parse_gemfile_lock(indoc! {r#"
+            DEPENDENCIES
+              rails (~> 7.0)
+
+            RUBY VERSION
+               ruby 3.2.2p53
+
+            BUNDLED WITH
+               2.4.10
+        "#}),
+            "3.2.2"
+        );
+        assert_eq!(
+            parse_gemfile_lock(indoc! {r#"
+            RUBY VERSION
+               ruby 2.5.7p001 (jruby 9.2.19.0)
+        "#}),
+            "jruby-9.2.19.0"
+        );
+        assert_eq!(
+            parse_gemfile_lock(indoc! {r#"
+            BUNDLED WITH
+               2.4.10
+        "#}),
+            ""
+        );
+    }
 }
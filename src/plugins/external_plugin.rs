@@ -18,13 +18,13 @@ use crate::env::RTX_FETCH_REMOTE_VERSIONS_TIMEOUT;
 use crate::env_diff::{EnvDiff, EnvDiffOperation};
 use crate::errors::Error::PluginNotInstalled;
 use crate::file::{display_path, remove_all};
-use crate::git::Git;
+use crate::git::{Git, GitUpdate};
 use crate::hash::hash_to_str;
 use crate::install_context::InstallContext;
 use crate::plugins::external_plugin_cache::ExternalPluginCache;
 use crate::plugins::rtx_plugin_toml::RtxPluginToml;
 use crate::plugins::Script::{Download, ExecEnv, Install, ParseLegacyFile};
-use crate::plugins::{Plugin, PluginName, PluginType, Script, ScriptManager};
+use crate::plugins::{Plugin, PluginCommit, PluginName, PluginType, Script, ScriptManager};
 use crate::timeout::run_with_timeout;
 use crate::toolset::{ToolVersion, ToolVersionRequest, Toolset};
 use crate::ui::multi_progress_report::MultiProgressReport;
@@ -41,6 +41,8 @@ pub struct ExternalPlugin {
     cache_path: PathBuf,
     downloads_path: PathBuf,
     installs_path: PathBuf,
+    /// commit rtx last installed or updated the plugin to
+    commit: Arc<PluginCommit>,
     script_man: ScriptManager,
     cache: ExternalPluginCache,
     remote_version_cache: CacheManager<Vec<String>>,
@@ -55,8 +57,12 @@ impl ExternalPlugin {
         let cache_path = dirs::CACHE.join(&name);
         let toml_path = plugin_path.join("rtx.plugin.toml");
         let toml = RtxPluginToml::from_file(&toml_path).unwrap();
+        let commit = Arc::new(PluginCommit::new(
+            dirs::DATA.join("plugin-shas").join(&name),
+        ));
         Self {
-            script_man: build_script_man(&name, &plugin_path),
+            script_man: build_script_man(&name, &plugin_path).with_commit(commit.clone()),
+            commit,
             downloads_path: dirs::DOWNLOADS.join(&name),
             installs_path: dirs::INSTALLS.join(&name),
             cache: ExternalPluginCache::default(),
@@ -92,6 +98,22 @@ impl ExternalPlugin {
             .collect())
     }
 
+    /// records the current commit so scripts can tell if the plugin has been changed
+    /// outside of rtx, fails if the plugin was pinned to a commit and isn't on it
+    fn record_sha(&self, git: &Git, gitref: Option<&str>) -> Result<()> {
+        let sha = git.current_sha()?;
+        if let Some(gitref) = gitref.filter(|r| Git::is_full_sha(r)) {
+            if !sha.eq_ignore_ascii_case(gitref) {
+                return Err(eyre!(
+                    "plugin {} is pinned to {gitref} but {sha} was checked out",
+                    style(&self.name).cyan().for_stderr()
+                ));
+            }
+        }
+        file::create_dir_all(self.commit.sha_path.parent().unwrap())?;
+        file::write(&self.commit.sha_path, &sha)
+    }
+
     fn get_repo_url(&self, config: &Config) -> Result<String> {
         self.repo_url
             .clone()
@@ -115,6 +137,7 @@ impl ExternalPlugin {
             pr.set_message(format!("checking out {ref_}"));
             git.update(Some(ref_.to_string()))?;
         }
+        self.record_sha(&git, repo_ref.as_deref())?;
 
         pr.set_message("loading plugin remote versions");
         if self.has_list_all_script() {
@@ -138,7 +161,7 @@ impl ExternalPlugin {
     }
 
     fn fetch_remote_versions(&self, settings: &Settings) -> Result<Vec<String>> {
-        let cmd = self.script_man.cmd(settings, &Script::ListAll);
+        let cmd = self.script_man.cmd(settings, &Script::ListAll)?;
         let result = run_with_timeout(
             move || {
                 let result = cmd.stdout_capture().stderr_capture().unchecked().run()?;
@@ -269,7 +292,7 @@ impl ExternalPlugin {
             //         sm.prepend_path(p);
             //     }
             // }
-            let output = sm.cmd(&config.settings, &Script::ListBinPaths).read()?;
+            let output = sm.cmd(&config.settings, &Script::ListBinPaths)?.read()?;
             output.split_whitespace().map(|f| f.to_string()).collect()
         } else {
             vec!["bin".into()]
@@ -315,7 +338,9 @@ impl ExternalPlugin {
             sm = sm.with_env("RTX_PROJECT_ROOT", project_root);
         }
         let install_type = match &tv.request {
-            ToolVersionRequest::Version(_, _) | ToolVersionRequest::Prefix(_, _) => "version",
+            ToolVersionRequest::Version(_, _)
+            | ToolVersionRequest::Prefix(_, _)
+            | ToolVersionRequest::Range(_, _) => "version",
             ToolVersionRequest::Ref(_, _) => "ref",
             ToolVersionRequest::Path(_, _) => "path",
             ToolVersionRequest::Sub { .. } => "sub",
@@ -431,6 +456,10 @@ impl Plugin for ExternalPlugin {
         self.plugin_path.exists()
     }
 
+    fn set_pinned_ref(&self, gitref: Option<String>) {
+        self.commit.set_pinned_ref(gitref);
+    }
+
     fn ensure_installed(
         &self,
         config: &mut Config,
@@ -461,14 +490,14 @@ impl Plugin for ExternalPlugin {
         self.install(config, &pr)
     }
 
-    fn update(&self, gitref: Option<String>) -> Result<()> {
+    fn update(&self, gitref: Option<String>, dry_run: bool) -> Result<Option<GitUpdate>> {
         let plugin_path = self.plugin_path.to_path_buf();
         if plugin_path.is_symlink() {
             warn!(
                 "Plugin: {} is a symlink, not updating",
                 style(&self.name).cyan().for_stderr()
             );
-            return Ok(());
+            return Ok(None);
         }
         let git = Git::new(plugin_path);
         if !git.is_repo() {
@@ -476,12 +505,20 @@ impl Plugin for ExternalPlugin {
                 "Plugin {} is not a git repository, not updating",
                 style(&self.name).cyan().for_stderr()
             );
-            return Ok(());
+            return Ok(None);
+        }
+        if dry_run {
+            let from = git.current_sha()?;
+            let to = git.fetch(gitref)?;
+            let commits = git.log(&from, &to)?;
+            return Ok(Some(GitUpdate { from, to, commits }));
         }
         // TODO: asdf_run_hook "pre_plugin_update"
-        let (_pre, _post) = git.update(gitref)?;
+        let (from, to) = git.update(gitref.clone())?;
+        self.record_sha(&git, gitref.as_deref())?;
         // TODO: asdf_run_hook "post_plugin_update"
-        Ok(())
+        let commits = git.log(&from, &to)?;
+        Ok(Some(GitUpdate { from, to, commits }))
     }
 
     fn uninstall(&self, pr: &ProgressReport) -> Result<()> {
@@ -504,6 +541,9 @@ impl Plugin for ExternalPlugin {
         };
 
         rmdir(&self.plugin_path)?;
+        if self.commit.sha_path.exists() {
+            file::remove_file(&self.commit.sha_path)?;
+        }
 
         Ok(())
     }
@@ -622,7 +662,7 @@ impl Plugin for ExternalPlugin {
         );
         let result = self
             .script_man
-            .cmd(&config.settings, &script)
+            .cmd(&config.settings, &script)?
             .unchecked()
             .run()?;
         exit(result.status.code().unwrap_or(1));
@@ -13,23 +13,25 @@ use regex::Regex;
 use versions::Versioning;
 
 pub use external_plugin::ExternalPlugin;
-pub use script_manager::{Script, ScriptManager};
+pub use script_manager::{PluginCommit, Script, ScriptManager};
 
 use crate::config::{Config, Settings};
 use crate::file::{display_path, remove_all, remove_all_with_warning};
+use crate::git::GitUpdate;
 use crate::install_context::InstallContext;
 use crate::lock_file::LockFile;
 use crate::runtime_symlinks::is_runtime_symlink;
 use crate::toolset::{ToolVersion, ToolVersionRequest, Toolset};
 use crate::ui::multi_progress_report::MultiProgressReport;
 use crate::ui::progress_report::{ProgressReport, PROG_TEMPLATE};
-use crate::{dirs, file};
+use crate::{dirs, file, last_used};
 
 pub mod core;
 mod external_plugin;
 mod external_plugin_cache;
 mod rtx_plugin_toml;
 mod script_manager;
+mod script_sandbox;
 
 pub type PluginName = String;
 
@@ -146,6 +148,8 @@ pub trait Plugin: Debug + Send + Sync {
     fn is_installed(&self) -> bool {
         true
     }
+    /// the git ref the plugin is pinned to in `[plugins]`, scripts won't run from any other commit
+    fn set_pinned_ref(&self, _gitref: Option<String>) {}
     fn ensure_installed(
         &self,
         _config: &mut Config,
@@ -154,8 +158,10 @@ pub trait Plugin: Debug + Send + Sync {
     ) -> Result<()> {
         Ok(())
     }
-    fn update(&self, _git_ref: Option<String>) -> Result<()> {
-        Ok(())
+    /// updates the plugin to `git_ref`, or only fetches it with `dry_run`
+    /// returns None for plugins that can't be updated
+    fn update(&self, _git_ref: Option<String>, _dry_run: bool) -> Result<Option<GitUpdate>> {
+        Ok(None)
     }
     fn uninstall(&self, _pr: &ProgressReport) -> Result<()> {
         Ok(())
@@ -172,6 +178,10 @@ pub trait Plugin: Debug + Send + Sync {
     fn legacy_filenames(&self, _settings: &Settings) -> Result<Vec<String>> {
         Ok(vec![])
     }
+    /// project manifests like package.json, only read if `legacy_version_file_manifests` is set
+    fn manifest_filenames(&self) -> Vec<String> {
+        vec![]
+    }
     fn parse_legacy_file(&self, path: &Path, _settings: &Settings) -> Result<String> {
         let contents = file::read_to_string(path)?;
         Ok(contents.trim().to_string())
@@ -197,6 +207,11 @@ pub trait Plugin: Debug + Send + Sync {
         }
         self.decorate_progress_bar(&mut ctx.pr, Some(&ctx.tv));
         let _lock = self.get_lock(&ctx.tv.install_path(), ctx.force)?;
+        if !ctx.force && self.is_version_installed(&ctx.tv) {
+            // another process installed it while we were waiting for the lock
+            ctx.pr.finish();
+            return Ok(());
+        }
         self.create_install_dirs(&ctx.tv)?;
 
         if let Err(e) = self.install_version_impl(&ctx) {
@@ -247,6 +262,9 @@ pub trait Plugin: Debug + Send + Sync {
         rmdir(&tv.install_path())?;
         rmdir(&tv.download_path())?;
         rmdir(&tv.cache_path())?;
+        if !dryrun {
+            last_used::remove(tv)?;
+        }
         Ok(())
     }
     fn uninstall_version_impl(&self, _config: &Config, _tv: &ToolVersion) -> Result<()> {
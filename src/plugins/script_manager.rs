@@ -2,13 +2,15 @@ use std::collections::HashMap;
 use std::ffi::OsString;
 use std::fmt;
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Output;
+use std::sync::{Arc, Mutex};
 
-use color_eyre::eyre::{Context, Result};
+use color_eyre::eyre::{eyre, Context, Result};
+use console::style;
 use duct::Expression;
 use indexmap::indexmap;
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
 
 use crate::cmd::{cmd, CmdLineRunner};
 use crate::config::Settings;
@@ -16,14 +18,83 @@ use crate::errors::Error;
 use crate::errors::Error::ScriptFailed;
 use crate::fake_asdf::get_path_with_fake_asdf;
 use crate::file::{basename, display_path};
+use crate::git::Git;
+use crate::plugins::script_sandbox::ScriptSandbox;
 use crate::ui::progress_report::ProgressReport;
-use crate::{dirs, env};
+use crate::{dirs, env, file};
 
 #[derive(Debug, Clone)]
 pub struct ScriptManager {
     pub plugin_path: PathBuf,
     pub plugin_name: String,
     pub env: HashMap<OsString, OsString>,
+    /// if set, scripts only run if the plugin is still at the commit rtx checked out
+    pub commit: Option<Arc<PluginCommit>>,
+}
+
+/// the commit rtx installed a plugin at and the ref it's pinned to in `[plugins]`
+#[derive(Debug, Default)]
+pub struct PluginCommit {
+    /// written when rtx installs or updates the plugin
+    pub sha_path: PathBuf,
+    pub pinned_ref: Mutex<Option<String>>,
+    verified: OnceCell<()>,
+}
+
+impl PluginCommit {
+    pub fn new(sha_path: PathBuf) -> Self {
+        Self {
+            sha_path,
+            ..Default::default()
+        }
+    }
+
+    pub fn set_pinned_ref(&self, gitref: Option<String>) {
+        *self.pinned_ref.lock().unwrap() = gitref;
+    }
+
+    /// refuses to run plugin scripts from a commit rtx didn't install or one that doesn't match
+    /// the ref in `[plugins]`, only checked once per process
+    fn verify(&self, plugin_name: &str, plugin_path: &Path) -> Result<()> {
+        self.verified.get_or_try_init(|| {
+            if plugin_path.is_symlink() {
+                // linked plugins are expected to change
+                return Ok(());
+            }
+            let git = Git::new(plugin_path.to_path_buf());
+            if !git.is_repo() {
+                return Ok(());
+            }
+            let actual = git.current_sha()?;
+            // plugins installed by older versions of rtx have no sha but their pins are still checked
+            if self.sha_path.exists() {
+                let expected = file::read_to_string(&self.sha_path)?;
+                if expected.trim() != actual {
+                    return Err(eyre!(
+                        "plugin {} is at commit {actual} but rtx installed {}\n\
+                         if this is expected, reinstall it with `rtx plugins install --force {plugin_name}`",
+                        style(plugin_name).cyan().for_stderr(),
+                        expected.trim(),
+                    ));
+                }
+            }
+            if let Some(gitref) = self.pinned_ref.lock().unwrap().as_deref() {
+                let pinned = match Git::is_full_sha(gitref) {
+                    true => Some(gitref.to_lowercase()),
+                    false => git.resolve_ref(gitref),
+                };
+                if pinned.as_deref() != Some(actual.as_str()) {
+                    return Err(eyre!(
+                        "plugin {} is pinned to {gitref} in [plugins] but is at commit {actual}\n\
+                         check out the pinned ref with `rtx plugins update {plugin_name}`",
+                        style(plugin_name).cyan().for_stderr(),
+                    ));
+                }
+            }
+            Ok(())
+        })?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -102,6 +173,19 @@ impl ScriptManager {
             plugin_name: basename(&plugin_path).expect("invalid plugin path"),
             env,
             plugin_path,
+            commit: None,
+        }
+    }
+
+    pub fn with_commit(mut self, commit: Arc<PluginCommit>) -> Self {
+        self.commit = Some(commit);
+        self
+    }
+
+    fn verify_commit(&self) -> Result<()> {
+        match &self.commit {
+            Some(commit) => commit.verify(&self.plugin_name, &self.plugin_path),
+            None => Ok(()),
         }
     }
 
@@ -133,7 +217,8 @@ impl ScriptManager {
         self.get_script_path(script).is_file()
     }
 
-    pub fn cmd(&self, settings: &Settings, script: &Script) -> Expression {
+    pub fn cmd(&self, settings: &Settings, script: &Script) -> Result<Expression> {
+        self.verify_commit()?;
         let args = match script {
             Script::ParseLegacyFile(filename) => vec![filename.clone()],
             Script::RunExternalCommand(_, args) => args.clone(),
@@ -143,33 +228,64 @@ impl ScriptManager {
         // if !script_path.exists() {
         //     return Err(PluginNotInstalled(self.plugin_name.clone()).into());
         // }
-        let mut cmd = cmd(script_path, args).full_env(&self.env);
+        let mut cmd = match self.sandbox(settings, script) {
+            Some(sandbox) => {
+                let (program, args) = sandbox.wrap(script_path, args);
+                cmd(program, args).full_env(sandbox.env(&self.env))
+            }
+            None => cmd(script_path, args).full_env(&self.env),
+        };
         if !settings.raw {
             // ignore stdin, otherwise a prompt may show up where the user won't see it
             cmd = cmd.stdin_null();
         }
-        cmd
+        Ok(cmd)
+    }
+
+    /// `RTX_PLUGIN_SANDBOX` restrictions for `script` if they're enabled
+    fn sandbox(&self, settings: &Settings, script: &Script) -> Option<ScriptSandbox> {
+        settings
+            .plugin_sandbox
+            .then(|| ScriptSandbox::new(self, script))
+    }
+
+    /// adds an explanation of the sandbox to errors from sandboxed scripts
+    fn wrap_sandbox_err<T>(
+        &self,
+        settings: &Settings,
+        script: &Script,
+        res: Result<T>,
+    ) -> Result<T> {
+        match self.sandbox(settings, script) {
+            Some(sandbox) => {
+                res.wrap_err_with(|| sandbox.describe(&display_path(&self.get_script_path(script))))
+            }
+            None => res,
+        }
     }
 
     pub fn run(&self, settings: &Settings, script: &Script) -> Result<()> {
-        let cmd = self.cmd(settings, script);
+        let cmd = self.cmd(settings, script)?;
         let Output { status, .. } = cmd.unchecked().run()?;
 
-        match status.success() {
+        let res = match status.success() {
             true => Ok(()),
             false => {
                 Err(ScriptFailed(display_path(&self.get_script_path(script)), Some(status)).into())
             }
-        }
+        };
+        self.wrap_sandbox_err(settings, script, res)
     }
 
     pub fn read(&self, settings: &Settings, script: &Script) -> Result<String> {
-        let mut cmd = self.cmd(settings, script);
+        let mut cmd = self.cmd(settings, script)?;
         if !settings.verbose {
             cmd = cmd.stderr_null();
         }
-        cmd.read()
-            .wrap_err_with(|| ScriptFailed(display_path(&self.get_script_path(script)), None))
+        let res = cmd
+            .read()
+            .wrap_err_with(|| ScriptFailed(display_path(&self.get_script_path(script)), None));
+        self.wrap_sandbox_err(settings, script, res)
     }
 
     pub fn run_by_line(
@@ -178,17 +294,28 @@ impl ScriptManager {
         script: &Script,
         pr: &ProgressReport,
     ) -> Result<()> {
-        let cmd = CmdLineRunner::new(settings, self.get_script_path(script))
-            .with_pr(pr)
-            .env_clear()
-            .envs(&self.env);
+        self.verify_commit()?;
+        let script_path = self.get_script_path(script);
+        let cmd = match self.sandbox(settings, script) {
+            Some(sandbox) => {
+                let (program, args) = sandbox.wrap(script_path, vec![]);
+                CmdLineRunner::new(settings, program)
+                    .args(args)
+                    .env_clear()
+                    .envs(sandbox.env(&self.env))
+            }
+            None => CmdLineRunner::new(settings, script_path)
+                .env_clear()
+                .envs(&self.env),
+        }
+        .with_pr(pr);
         if let Err(e) = cmd.execute() {
             let status = match e.downcast_ref::<Error>() {
                 Some(ScriptFailed(_, status)) => *status,
                 _ => None,
             };
             let path = display_path(&self.get_script_path(script));
-            return Err(ScriptFailed(path, status).into());
+            return self.wrap_sandbox_err(settings, script, Err(ScriptFailed(path, status).into()));
         }
         Ok(())
     }
@@ -223,4 +350,54 @@ mod tests {
         let script = Script::RunExternalCommand(PathBuf::from("/bin/ls"), vec!["-l".to_string()]);
         test(&script, PathBuf::from("/bin/ls"));
     }
+
+    #[test]
+    fn test_plugin_commit() {
+        let dir = tempfile::tempdir().unwrap();
+        let plugin_path = dir.path().join("tiny");
+        file::create_dir_all(plugin_path.join("bin")).unwrap();
+        file::write(plugin_path.join("bin/list-all"), "#!/bin/sh\necho 1.0.0\n").unwrap();
+        file::make_executable(&plugin_path.join("bin/list-all")).unwrap();
+        let git = |args: &[&str]| {
+            let mut cmd = vec!["-C", plugin_path.to_str().unwrap()];
+            cmd.extend(["-c", "user.name=rtx", "-c", "user.email=rtx@example.com"]);
+            cmd.extend(args);
+            crate::cmd::cmd("git", cmd).read().unwrap()
+        };
+        git(&["init", "-q"]);
+        git(&["add", "."]);
+        git(&["commit", "-qm", "v1"]);
+        git(&["tag", "v1"]);
+        let v1 = git(&["rev-parse", "HEAD"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "v2"]);
+        let v2 = git(&["rev-parse", "HEAD"]);
+
+        let sm = |sha: &str, pinned_ref: Option<&str>| {
+            let commit = PluginCommit::new(dir.path().join("sha"));
+            file::write(&commit.sha_path, sha).unwrap();
+            commit.set_pinned_ref(pinned_ref.map(String::from));
+            ScriptManager::new(plugin_path.clone()).with_commit(Arc::new(commit))
+        };
+        let settings = Settings::default();
+        let list_all = |sm: ScriptManager| sm.read(&settings, &Script::ListAll);
+
+        assert_eq!(list_all(sm(&v2, None)).unwrap(), "1.0.0");
+        assert_eq!(list_all(sm(&v2, Some(&v2))).unwrap(), "1.0.0");
+        let err = list_all(sm(&v1, None)).unwrap_err();
+        assert!(err.to_string().contains("but rtx installed"));
+        let err = list_all(sm(&v2, Some("v1"))).unwrap_err();
+        assert!(err.to_string().contains("is pinned to v1 in [plugins]"));
+        let err = list_all(sm(&v2, Some(&v1))).unwrap_err();
+        assert!(err.to_string().contains("is pinned to"));
+
+        git(&["checkout", "-q", "v1"]);
+        assert_eq!(list_all(sm(&v1, Some("v1"))).unwrap(), "1.0.0");
+
+        // plugins installed without a sha file still have their pin checked
+        let commit = PluginCommit::new(dir.path().join("missing"));
+        commit.set_pinned_ref(Some(v2.clone()));
+        let sm = ScriptManager::new(plugin_path.clone()).with_commit(Arc::new(commit));
+        let err = list_all(sm).unwrap_err();
+        assert!(err.to_string().contains("is pinned to"));
+    }
 }
@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+
+use crate::file::display_path;
+use crate::plugins::{Script, ScriptManager};
+use crate::{dirs, file};
+
+/// bubblewrap is used for filesystem and network isolation when it's installed
+static BWRAP: Lazy<Option<PathBuf>> = Lazy::new(|| {
+    let bwrap = file::which("bwrap");
+    if bwrap.is_none() {
+        warn!(
+            "bwrap not found, plugin scripts can still write anywhere and access the network. \
+             Install bubblewrap to isolate them, only their environment is restricted"
+        );
+    }
+    bwrap
+});
+
+/// env vars passed to sandboxed scripts, along with LC_*, ASDF_*, and RTX_*
+const ENV_ALLOWLIST: [&str; 15] = [
+    "HOME",
+    "HTTPS_PROXY",
+    "HTTP_PROXY",
+    "LANG",
+    "LOGNAME",
+    "NO_PROXY",
+    "PATH",
+    "SHELL",
+    "TERM",
+    "TMPDIR",
+    "TZ",
+    "USER",
+    "http_proxy",
+    "https_proxy",
+    "no_proxy",
+];
+
+/// restricts what a plugin script can do, see `RTX_PLUGIN_SANDBOX`
+///
+/// scripts can only write to the install, download, plugin cache, and temp dirs
+/// and only scripts that fetch versions or downloads have network access
+#[derive(Debug)]
+pub struct ScriptSandbox {
+    writable: Vec<PathBuf>,
+    network: bool,
+}
+
+impl ScriptSandbox {
+    pub fn new(sm: &ScriptManager, script: &Script) -> Self {
+        let mut writable = vec![dirs::CACHE.join(&sm.plugin_name)];
+        for key in ["ASDF_INSTALL_PATH", "ASDF_DOWNLOAD_PATH"] {
+            if let Some(path) = sm.env.get(&OsString::from(key)) {
+                writable.push(PathBuf::from(path));
+            }
+        }
+        if let Some(tmpdir) = sm.env.get(&OsString::from("TMPDIR")).map(PathBuf::from) {
+            // /tmp is already a private tmpfs
+            if !tmpdir.starts_with("/tmp") {
+                writable.push(tmpdir);
+            }
+        }
+        let network = match script {
+            Script::Download
+            | Script::ListAll
+            | Script::LatestStable
+            | Script::ListAliases
+            | Script::ListLegacyFilenames => true,
+            // most plugins download in bin/install if they don't have bin/download
+            Script::Install => !sm.script_exists(&Script::Download),
+            _ => false,
+        };
+        Self { writable, network }
+    }
+
+    /// the program and args that run `program` inside the sandbox
+    pub fn wrap(&self, program: PathBuf, args: Vec<String>) -> (PathBuf, Vec<OsString>) {
+        match &*BWRAP {
+            Some(bwrap) => {
+                for dir in &self.writable {
+                    if let Err(err) = file::create_dir_all(dir) {
+                        warn!("failed to create {}: {err:#}", display_path(dir));
+                    }
+                }
+                (bwrap.clone(), self.bwrap_args(program, args))
+            }
+            None => (program, args.into_iter().map(OsString::from).collect()),
+        }
+    }
+
+    fn bwrap_args(&self, program: PathBuf, args: Vec<String>) -> Vec<OsString> {
+        let mut bwrap_args: Vec<OsString> = vec![
+            "--ro-bind".into(),
+            "/".into(),
+            "/".into(),
+            "--dev".into(),
+            "/dev".into(),
+            "--proc".into(),
+            "/proc".into(),
+            "--tmpfs".into(),
+            "/tmp".into(),
+        ];
+        for dir in &self.writable {
+            bwrap_args.extend(["--bind".into(), dir.into(), dir.into()]);
+        }
+        if !self.network {
+            bwrap_args.push("--unshare-net".into());
+        }
+        bwrap_args.extend(["--die-with-parent".into(), "--".into(), program.into()]);
+        bwrap_args.extend(args.into_iter().map(OsString::from));
+        bwrap_args
+    }
+
+    pub fn env(&self, env: &HashMap<OsString, OsString>) -> HashMap<OsString, OsString> {
+        env.iter()
+            .filter(|(k, _)| {
+                let k = k.to_string_lossy();
+                ENV_ALLOWLIST.contains(&k.as_ref())
+                    || ["LC_", "ASDF_", "RTX_", "__RTX_"]
+                        .iter()
+                        .any(|prefix| k.starts_with(prefix))
+            })
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    }
+
+    /// explains the restrictions a failed script was running under
+    pub fn describe(&self, script_path: &str) -> String {
+        let restrictions = match (&*BWRAP, self.network) {
+            (None, _) => "only had a restricted environment".to_string(),
+            (Some(_), network) => {
+                let writable = self.writable.iter().map(|p| display_path(p)).join(", ");
+                let network = if network {
+                    ""
+                } else {
+                    " and had no network access"
+                };
+                format!("could only write to {writable}{network}")
+            }
+        };
+        format!(
+            "{script_path} ran in the plugin sandbox and {restrictions}.\n\
+             If this plugin is trusted, disable the sandbox with RTX_PLUGIN_SANDBOX=0"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Settings;
+
+    use super::*;
+
+    #[test]
+    fn test_script_sandbox() {
+        let sm = ScriptManager::new(dirs::PLUGINS.join("tiny"))
+            .with_env("ASDF_INSTALL_PATH", "/tmp/install")
+            .with_env("TMPDIR", "/var/tmp/rtx")
+            .with_env("AWS_SECRET_ACCESS_KEY", "secret");
+        let sandbox = ScriptSandbox::new(&sm, &Script::ExecEnv);
+        assert!(!sandbox.network);
+        for script in [
+            Script::Download,
+            Script::ListAll,
+            Script::LatestStable,
+            Script::ListAliases,
+            Script::ListLegacyFilenames,
+            // tiny has no bin/download
+            Script::Install,
+        ] {
+            assert!(ScriptSandbox::new(&sm, &script).network);
+        }
+        for script in [Script::ListBinPaths, Script::Uninstall] {
+            assert!(!ScriptSandbox::new(&sm, &script).network);
+        }
+        assert!(sandbox.writable.contains(&PathBuf::from("/tmp/install")));
+        assert!(sandbox.writable.contains(&PathBuf::from("/var/tmp/rtx")));
+
+        let sm = sm.with_env("TMPDIR", "/tmp/rtx");
+        let sandbox = ScriptSandbox::new(&sm, &Script::ExecEnv);
+        assert!(!sandbox.writable.contains(&PathBuf::from("/tmp/rtx")));
+
+        let env = sandbox.env(&sm.env);
+        assert!(env.contains_key(&OsString::from("ASDF_INSTALL_PATH")));
+        assert!(env.contains_key(&OsString::from("PATH")));
+        assert!(!env.contains_key(&OsString::from("AWS_SECRET_ACCESS_KEY")));
+    }
+
+    #[test]
+    fn test_sandboxed_list_all() {
+        let sm = ScriptManager::new(dirs::PLUGINS.join("tiny"));
+        let settings = Settings {
+            plugin_sandbox: true,
+            ..Settings::default()
+        };
+        let versions = sm.read(&settings, &Script::ListAll).unwrap();
+        assert!(versions.lines().any(|v| v == "3.1.0"));
+    }
+
+    #[test]
+    fn test_bwrap_args() {
+        let sandbox = ScriptSandbox {
+            writable: vec![PathBuf::from("/rtx/installs/tiny/1.0.0")],
+            network: false,
+        };
+        let args = sandbox.bwrap_args("/rtx/plugins/tiny/bin/install".into(), vec!["a".into()]);
+        let args = args.iter().map(|a| a.to_string_lossy()).join(" ");
+        assert_eq!(
+            args,
+            "--ro-bind / / --dev /dev --proc /proc --tmpfs /tmp \
+             --bind /rtx/installs/tiny/1.0.0 /rtx/installs/tiny/1.0.0 \
+             --unshare-net --die-with-parent -- /rtx/plugins/tiny/bin/install a"
+        );
+    }
+}
@@ -1,18 +1,29 @@
 use std::path::Path;
 
-use crate::shell::{is_dir_in_path, is_dir_not_in_nix, Shell};
+use crate::shell::{is_dir_in_path, is_dir_not_in_nix, shims_dir_to_add, Shell};
 
 #[derive(Default)]
 pub struct Bash {}
 
 impl Shell for Bash {
-    fn activate(&self, exe: &Path, status: bool) -> String {
+    fn activate(&self, exe: &Path, status: bool, shims: bool) -> String {
         let dir = exe.parent().unwrap();
         let status = if status { " --status" } else { "" };
         let mut out = String::new();
         if is_dir_not_in_nix(dir) && !is_dir_in_path(dir) {
             out.push_str(&format!("export PATH=\"{}:$PATH\"\n", dir.display()));
         }
+        if let Some(shims_dir) = shims_dir_to_add(shims) {
+            out.push_str(&format!("export PATH=\"{}:$PATH\"\n", shims_dir.display()));
+        }
+        if shims {
+            out.push_str("export __RTX_SHIMS=1\n");
+        }
+        // bash has no chpwd hook so the prompt hook only runs when PWD has changed
+        let guard = match shims {
+            true => "\n  [[ \"${__RTX_LAST_PWD:-}\" == \"$PWD\" ]] && return $previous_exit_status;\n  __RTX_LAST_PWD=\"$PWD\";",
+            false => "",
+        };
         out.push_str(&formatdoc! {r#"
             export RTX_SHELL=bash
 
@@ -38,7 +49,7 @@ impl Shell for Bash {
             }}
 
             _rtx_hook() {{
-              local previous_exit_status=$?;
+              local previous_exit_status=$?;{guard}
               eval "$(rtx hook-env{status} -s bash)";
               return $previous_exit_status;
             }};
@@ -55,6 +66,7 @@ impl Shell for Bash {
             PROMPT_COMMAND="${{PROMPT_COMMAND//_rtx_hook;/}}"
             PROMPT_COMMAND="${{PROMPT_COMMAND//_rtx_hook/}}"
             unset _rtx_hook
+            unset __RTX_LAST_PWD
             unset rtx
             unset RTX_SHELL
         "#}
@@ -84,14 +96,21 @@ mod tests {
     fn test_hook_init() {
         let bash = Bash::default();
         let exe = Path::new("/some/dir/rtx");
-        assert_snapshot!(bash.activate(exe, true));
+        assert_snapshot!(bash.activate(exe, true, false));
     }
 
     #[test]
     fn test_hook_init_nix() {
         let bash = Bash::default();
         let exe = Path::new("/nix/store/rtx");
-        assert_snapshot!(bash.activate(exe, true));
+        assert_snapshot!(bash.activate(exe, true, false));
+    }
+
+    #[test]
+    fn test_hook_init_shims() {
+        let bash = Bash::default();
+        let exe = Path::new("/some/dir/rtx");
+        assert_snapshot!(replace_path(&bash.activate(exe, false, true)));
     }
 
     #[test]
@@ -0,0 +1,142 @@
+use std::borrow::Cow;
+use std::path::Path;
+
+use crate::shell::{is_dir_in_path, is_dir_not_in_nix, shims_dir_to_add, Shell};
+
+#[derive(Default)]
+pub struct Elvish {}
+
+/// quotes a string for elvish, inside single quotes only ' needs escaping (as '')
+fn elvish_quote(input: &str) -> Cow<'_, str> {
+    if !input.is_empty()
+        && input
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | ':'))
+    {
+        return Cow::Borrowed(input);
+    }
+    Cow::Owned(format!("'{}'", input.replace('\'', "''")))
+}
+
+impl Shell for Elvish {
+    fn activate(&self, exe: &Path, status: bool, shims: bool) -> String {
+        let dir = exe.parent().unwrap();
+        let exe = exe.to_string_lossy();
+        let exe = elvish_quote(&exe);
+        let status = if status { " --status" } else { "" };
+        let mut out = String::new();
+        if is_dir_not_in_nix(dir) && !is_dir_in_path(dir) {
+            let dir = dir.to_string_lossy();
+            out.push_str(&format!("set paths = [{} $@paths]\n", elvish_quote(&dir)));
+        }
+        if let Some(shims_dir) = shims_dir_to_add(shims) {
+            let shims_dir = shims_dir.to_string_lossy();
+            out.push_str(&format!(
+                "set paths = [{} $@paths]\n",
+                elvish_quote(&shims_dir)
+            ));
+        }
+        if shims {
+            out.push_str("set-env __RTX_SHIMS 1\n");
+        }
+        // with shims the hook only runs when changing directories and once on activation
+        let hook = match shims {
+            true => "$_rtx_hook",
+            false => "set edit:before-readline = [$@edit:before-readline $_rtx_hook]",
+        };
+        // everything is kept in closures since definitions in `eval` don't leak out of it,
+        // `rtx` is added to the REPL namespace explicitly
+        out.push_str(&formatdoc! {r#"
+            set-env RTX_SHELL elvish
+
+            var _rtx_exe = (external {exe})
+
+            edit:add-var rtx~ {{|@args|
+              if (and (> (count $args) 0) (has-value [deactivate s shell] $args[0]) (not (has-value $args --help)) (not (has-value $args -h))) {{
+                eval ($_rtx_exe $@args | slurp)
+              }} else {{
+                $_rtx_exe $@args
+              }}
+            }}
+
+            var _rtx_hook = {{
+              eval ($_rtx_exe hook-env{status} -s elvish | slurp)
+            }}
+            {hook}
+            set after-chdir = [$@after-chdir {{|_| $_rtx_hook }}]
+            "#});
+
+        out
+    }
+
+    fn deactivate(&self) -> String {
+        formatdoc! {r#"
+            use str
+            var keep = {{|f| try {{ not (str:contains $f[def] '_rtx_') }} catch {{ put $true }} }}
+            set edit:before-readline = [(each {{|f| if ($keep $f) {{ put $f }} }} $edit:before-readline)]
+            set after-chdir = [(each {{|f| if ($keep $f) {{ put $f }} }} $after-chdir)]
+            edit:del-var rtx~
+            unset-env RTX_SHELL
+        "#}
+    }
+
+    fn set_env(&self, k: &str, v: &str) -> String {
+        format!("set-env {} {}\n", elvish_quote(k), elvish_quote(v))
+    }
+
+    fn unset_env(&self, k: &str) -> String {
+        format!("unset-env {}\n", elvish_quote(k))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use crate::test::replace_path;
+
+    use super::*;
+
+    #[test]
+    fn test_hook_init() {
+        let elvish = Elvish::default();
+        let exe = Path::new("/some/dir/rtx");
+        assert_snapshot!(elvish.activate(exe, true, false));
+    }
+
+    #[test]
+    fn test_hook_init_nix() {
+        let elvish = Elvish::default();
+        let exe = Path::new("/nix/store/rtx");
+        assert_snapshot!(elvish.activate(exe, true, false));
+    }
+
+    #[test]
+    fn test_set_env() {
+        assert_snapshot!(Elvish::default().set_env("FOO", "1"));
+    }
+
+    #[test]
+    fn test_set_env_quoting() {
+        let elvish = Elvish::default();
+        assert_snapshot!([
+            elvish.set_env("FOO", "hello world"),
+            elvish.set_env("FOO", "it's \"quoted\""),
+            elvish.set_env("FOO", "line1\nline2"),
+            elvish.set_env("FOO", "$HOME `ls` \\"),
+            elvish.set_env("FOO", ""),
+        ]
+        .join(""));
+    }
+
+    #[test]
+    fn test_unset_env() {
+        assert_snapshot!(Elvish::default().unset_env("FOO"));
+    }
+
+    #[test]
+    fn test_deactivate() {
+        let deactivate = Elvish::default().deactivate();
+        assert_snapshot!(replace_path(&deactivate));
+    }
+}
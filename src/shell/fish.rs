@@ -1,12 +1,12 @@
 use std::path::Path;
 
-use crate::shell::{is_dir_in_path, is_dir_not_in_nix, Shell};
+use crate::shell::{is_dir_in_path, is_dir_not_in_nix, shims_dir_to_add, Shell};
 
 #[derive(Default)]
 pub struct Fish {}
 
 impl Shell for Fish {
-    fn activate(&self, exe: &Path, status: bool) -> String {
+    fn activate(&self, exe: &Path, status: bool, shims: bool) -> String {
         let dir = exe.parent().unwrap();
         let status = if status { " --status" } else { "" };
         let description = "'Update rtx environment when changing directories'";
@@ -15,6 +15,15 @@ impl Shell for Fish {
         if is_dir_not_in_nix(dir) && !is_dir_in_path(dir) {
             out.push_str(&format!("fish_add_path -g {dir}\n", dir = dir.display()));
         }
+        if let Some(shims_dir) = shims_dir_to_add(shims) {
+            out.push_str(&format!(
+                "fish_add_path -g {dir}\n",
+                dir = shims_dir.display()
+            ));
+        }
+        if shims {
+            out.push_str("set -gx __RTX_SHIMS 1\n");
+        }
 
         // much of this is from direnv
         // https://github.com/direnv/direnv/blob/cb5222442cb9804b1574954999f6073cc636eff0/internal/cmd/shell_fish.go#L14-L36
@@ -49,6 +58,20 @@ impl Shell for Fish {
                 command rtx "$command" $argv
               end
             end
+            "#});
+
+        if shims {
+            out.push_str(&formatdoc! {r#"
+
+                function __rtx_env_eval --on-variable PWD --description {description};
+                    rtx hook-env{status} -s fish | source;
+                end;
+                __rtx_env_eval
+            "#});
+            return out;
+        }
+
+        out.push_str(&formatdoc! {r#"
 
             function __rtx_env_eval --on-event fish_prompt --description {description};
                 rtx hook-env{status} -s fish | source;
@@ -112,14 +135,21 @@ mod tests {
     fn test_hook_init() {
         let fish = Fish::default();
         let exe = Path::new("/some/dir/rtx");
-        assert_snapshot!(fish.activate(exe, true));
+        assert_snapshot!(fish.activate(exe, true, false));
     }
 
     #[test]
     fn test_hook_init_nix() {
         let fish = Fish::default();
         let exe = Path::new("/nix/store/rtx");
-        assert_snapshot!(fish.activate(exe, true));
+        assert_snapshot!(fish.activate(exe, true, false));
+    }
+
+    #[test]
+    fn test_hook_init_shims() {
+        let fish = Fish::default();
+        let exe = Path::new("/some/dir/rtx");
+        assert_snapshot!(replace_path(&fish.activate(exe, false, true)));
     }
 
     #[test]
@@ -1,11 +1,14 @@
 use std::fmt::{Display, Formatter};
 use std::path::Path;
 
-use crate::env;
+use crate::{dirs, env};
 
 mod bash;
+mod elvish;
 mod fish;
 mod nushell;
+mod pwsh;
+mod tcsh;
 mod xonsh;
 mod zsh;
 
@@ -15,8 +18,11 @@ pub mod completions;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum ShellType {
     Bash,
+    Elvish,
     Fish,
     Nu,
+    Pwsh,
+    Tcsh,
     Xonsh,
     Zsh,
 }
@@ -26,10 +32,17 @@ impl ShellType {
         let shell = env::var("RTX_SHELL").or(env::var("SHELL")).ok()?;
         if shell.ends_with("bash") {
             Some(ShellType::Bash)
+        } else if shell.ends_with("elvish") {
+            Some(ShellType::Elvish)
         } else if shell.ends_with("fish") {
             Some(ShellType::Fish)
         } else if shell.ends_with("nu") {
             Some(ShellType::Nu)
+        } else if shell.ends_with("pwsh") {
+            Some(ShellType::Pwsh)
+        } else if shell.ends_with("csh") {
+            // csh is usually tcsh these days
+            Some(ShellType::Tcsh)
         } else if shell.ends_with("xonsh") {
             Some(ShellType::Xonsh)
         } else if shell.ends_with("zsh") {
@@ -44,8 +57,11 @@ impl Display for ShellType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Bash => write!(f, "bash"),
+            Self::Elvish => write!(f, "elvish"),
             Self::Fish => write!(f, "fish"),
             Self::Nu => write!(f, "nu"),
+            Self::Pwsh => write!(f, "pwsh"),
+            Self::Tcsh => write!(f, "tcsh"),
             Self::Xonsh => write!(f, "xonsh"),
             Self::Zsh => write!(f, "zsh"),
         }
@@ -53,7 +69,9 @@ impl Display for ShellType {
 }
 
 pub trait Shell {
-    fn activate(&self, exe: &Path, status: bool) -> String;
+    /// with `shims`, the environment is only updated when changing directories
+    /// and the shims dir is added to PATH to cover everything else
+    fn activate(&self, exe: &Path, status: bool, shims: bool) -> String;
     fn deactivate(&self) -> String;
     fn set_env(&self, k: &str, v: &str) -> String;
     fn unset_env(&self, k: &str) -> String;
@@ -62,8 +80,11 @@ pub trait Shell {
 pub fn get_shell(shell: Option<ShellType>) -> Option<Box<dyn Shell>> {
     match shell.or_else(ShellType::load) {
         Some(ShellType::Bash) => Some(Box::<bash::Bash>::default()),
+        Some(ShellType::Elvish) => Some(Box::<elvish::Elvish>::default()),
         Some(ShellType::Fish) => Some(Box::<fish::Fish>::default()),
         Some(ShellType::Nu) => Some(Box::<nushell::Nushell>::default()),
+        Some(ShellType::Pwsh) => Some(Box::<pwsh::Pwsh>::default()),
+        Some(ShellType::Tcsh) => Some(Box::<tcsh::Tcsh>::default()),
         Some(ShellType::Xonsh) => Some(Box::<xonsh::Xonsh>::default()),
         Some(ShellType::Zsh) => Some(Box::<zsh::Zsh>::default()),
         _ => None,
@@ -78,6 +99,16 @@ pub fn is_dir_in_path(dir: &Path) -> bool {
         .any(|p| p.canonicalize().unwrap_or(p) == dir)
 }
 
+/// the shims dir if `rtx activate --shims` needs to add it to PATH
+pub fn shims_dir_to_add(shims: bool) -> Option<&'static Path> {
+    Some(dirs::SHIMS.as_path()).filter(|dir| shims && !is_dir_in_path(dir))
+}
+
+/// set by `rtx activate --shims`
+pub fn is_shims_mode() -> bool {
+    env::var("__RTX_SHIMS").is_ok()
+}
+
 pub fn is_dir_not_in_nix(dir: &Path) -> bool {
     !dir.canonicalize()
         .unwrap_or(dir.to_path_buf())
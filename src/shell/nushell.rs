@@ -1,6 +1,6 @@
 use std::{fmt::Display, path::Path};
 
-use crate::shell::{is_dir_in_path, is_dir_not_in_nix, Shell};
+use crate::shell::{is_dir_in_path, is_dir_not_in_nix, shims_dir_to_add, Shell};
 
 #[derive(Default)]
 pub struct Nushell {}
@@ -21,7 +21,7 @@ impl<'a> Display for EnvOp<'a> {
 }
 
 impl Shell for Nushell {
-    fn activate(&self, exe: &Path, status: bool) -> String {
+    fn activate(&self, exe: &Path, status: bool, shims: bool) -> String {
         let dir = exe.parent().unwrap();
         let exe = exe.display();
         let status = if status { " --status" } else { "" };
@@ -33,17 +33,33 @@ impl Shell for Nushell {
                 dir.display()
             ));
         }
+        if let Some(shims_dir) = shims_dir_to_add(shims) {
+            out.push_str(&format!(
+                "$env.PATH = ($env.PATH | prepend '{}')\n",
+                shims_dir.display()
+            ));
+        }
+        // with shims the hook only runs when changing directories
+        let pre_prompt = match shims {
+            true => "$env.config.hooks.pre_prompt",
+            false => indoc! {r#"
+                ($env.config.hooks.pre_prompt ++
+                      [{
+                      condition: {|| "RTX_SHELL" in $env }
+                      code: {|| rtx_hook }
+                      }])"#},
+        };
+        let shims_env = match shims {
+            true => "\n    $env.__RTX_SHIMS = \"1\"",
+            false => "",
+        };
 
         out.push_str(&formatdoc! {r#"
           export-env {{
-            $env.RTX_SHELL = "nu"
+            $env.RTX_SHELL = "nu"{shims_env}
             
             $env.config = ($env.config | upsert hooks {{
-                pre_prompt: ($env.config.hooks.pre_prompt ++
-                [{{
-                condition: {{|| "RTX_SHELL" in $env }}
-                code: {{|| rtx_hook }}
-                }}])
+                pre_prompt: {pre_prompt}
                 env_change: {{
                     PWD: ($env.config.hooks.env_change.PWD ++
                     [{{
@@ -126,14 +142,14 @@ mod tests {
     fn test_hook_init() {
         let nushell = Nushell::default();
         let exe = Path::new("/some/dir/rtx");
-        assert_snapshot!(nushell.activate(exe, true));
+        assert_snapshot!(nushell.activate(exe, true, false));
     }
 
     #[test]
     fn test_hook_init_nix() {
         let nushell = Nushell::default();
         let exe = Path::new("/nix/store/rtx");
-        assert_snapshot!(nushell.activate(exe, true));
+        assert_snapshot!(nushell.activate(exe, true, false));
     }
 
     #[test]
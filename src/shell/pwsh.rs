@@ -0,0 +1,175 @@
+use std::borrow::Cow;
+use std::path::Path;
+
+use crate::shell::{is_dir_in_path, is_dir_not_in_nix, shims_dir_to_add, Shell};
+
+#[derive(Default)]
+pub struct Pwsh {}
+
+/// escapes a string to go inside single quotes
+/// powershell treats the unicode single quotes the same as ' so those need doubling too
+fn pwsh_escape_sq(input: &str) -> Cow<'_, str> {
+    if !input.contains(['\'', '‘', '’', '‚', '‛']) {
+        return Cow::Borrowed(input);
+    }
+    let mut escaped = String::with_capacity(input.len());
+    for ch in input.chars() {
+        if matches!(ch, '\'' | '‘' | '’' | '‚' | '‛') {
+            escaped.push(ch);
+        }
+        escaped.push(ch);
+    }
+    Cow::Owned(escaped)
+}
+
+impl Shell for Pwsh {
+    fn activate(&self, exe: &Path, status: bool, shims: bool) -> String {
+        let dir = exe.parent().unwrap();
+        let exe = exe.to_string_lossy();
+        let exe = pwsh_escape_sq(&exe);
+        let status = if status { " --status" } else { "" };
+        let mut out = String::new();
+        if is_dir_not_in_nix(dir) && !is_dir_in_path(dir) {
+            let dir = dir.to_string_lossy();
+            out.push_str(&format!(
+                "$env:PATH = '{}' + [IO.Path]::PathSeparator + $env:PATH\n",
+                pwsh_escape_sq(&dir)
+            ));
+        }
+        if let Some(shims_dir) = shims_dir_to_add(shims) {
+            let shims_dir = shims_dir.to_string_lossy();
+            out.push_str(&format!(
+                "$env:PATH = '{}' + [IO.Path]::PathSeparator + $env:PATH\n",
+                pwsh_escape_sq(&shims_dir)
+            ));
+        }
+        if shims {
+            out.push_str("$env:__RTX_SHIMS = '1'\n");
+        }
+        // powershell has no chpwd hook so the prompt hook only runs when the directory has changed
+        let guard = match shims {
+            true => "\n  if ($global:_rtx_last_pwd -eq $PWD.Path) { return }\n  $global:_rtx_last_pwd = $PWD.Path",
+            false => "",
+        };
+        out.push_str(&formatdoc! {r#"
+            $env:RTX_SHELL = 'pwsh'
+
+            function global:rtx {{
+              $command = if ($args.Count -gt 0) {{ $args[0] }} else {{ '' }}
+              if ($command -in @('deactivate', 's', 'shell') -and -not ($args -contains '--help' -or $args -contains '-h')) {{
+                & '{exe}' @args | Out-String | Invoke-Expression
+                return
+              }}
+              & '{exe}' @args
+            }}
+
+            function global:_rtx_hook {{{guard}
+              $previous_exit_code = $global:LASTEXITCODE
+              & '{exe}' hook-env{status} -s pwsh | Out-String | Invoke-Expression
+              $global:LASTEXITCODE = $previous_exit_code
+            }}
+
+            if (-not (Test-Path -Path Function:\_rtx_original_prompt)) {{
+              Set-Item -Path function:global:_rtx_original_prompt -Value $function:prompt
+              function global:prompt {{
+                _rtx_hook
+                & $function:_rtx_original_prompt
+              }}
+            }}
+            "#});
+
+        out
+    }
+
+    fn deactivate(&self) -> String {
+        formatdoc! {r#"
+            if (Test-Path -Path Function:\_rtx_original_prompt) {{
+              Set-Item -Path function:global:prompt -Value $function:_rtx_original_prompt
+              Remove-Item -Path Function:\_rtx_original_prompt
+            }}
+            Remove-Item -ErrorAction SilentlyContinue -Path Function:\_rtx_hook
+            Remove-Item -ErrorAction SilentlyContinue -Path Variable:\_rtx_last_pwd
+            Remove-Item -ErrorAction SilentlyContinue -Path Function:\rtx
+            Remove-Item -ErrorAction SilentlyContinue -Path Env:\RTX_SHELL
+        "#}
+    }
+
+    fn set_env(&self, k: &str, v: &str) -> String {
+        format!(
+            "${{env:{k}}} = '{v}'\n",
+            k = pwsh_escape_env_key(k),
+            v = pwsh_escape_sq(v)
+        )
+    }
+
+    fn unset_env(&self, k: &str) -> String {
+        format!(
+            "Remove-Item -ErrorAction SilentlyContinue -LiteralPath 'Env:\\{k}'\n",
+            k = pwsh_escape_sq(k)
+        )
+    }
+}
+
+/// escapes an env var name to go inside ${env:...}
+fn pwsh_escape_env_key(k: &str) -> Cow<'_, str> {
+    if !k.contains(['`', '{', '}']) {
+        return Cow::Borrowed(k);
+    }
+    let mut escaped = String::with_capacity(k.len());
+    for ch in k.chars() {
+        if matches!(ch, '`' | '{' | '}') {
+            escaped.push('`');
+        }
+        escaped.push(ch);
+    }
+    Cow::Owned(escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use crate::test::replace_path;
+
+    use super::*;
+
+    #[test]
+    fn test_hook_init() {
+        let pwsh = Pwsh::default();
+        let exe = Path::new("/some/dir/rtx");
+        assert_snapshot!(pwsh.activate(exe, true, false));
+    }
+
+    #[test]
+    fn test_hook_init_nix() {
+        let pwsh = Pwsh::default();
+        let exe = Path::new("/nix/store/rtx");
+        assert_snapshot!(pwsh.activate(exe, true, false));
+    }
+
+    #[test]
+    fn test_set_env() {
+        assert_snapshot!(Pwsh::default().set_env("FOO", "1"));
+    }
+
+    #[test]
+    fn test_unset_env() {
+        assert_snapshot!(Pwsh::default().unset_env("FOO"));
+    }
+
+    #[test]
+    fn test_pwsh_escape() {
+        assert_eq!(pwsh_escape_sq("foo"), "foo");
+        assert_eq!(pwsh_escape_sq("it's"), "it''s");
+        assert_eq!(pwsh_escape_sq("it’s"), "it’’s");
+        assert_eq!(pwsh_escape_sq("$HOME `n"), "$HOME `n");
+        assert_eq!(pwsh_escape_env_key("FOO_BAR"), "FOO_BAR");
+        assert_eq!(pwsh_escape_env_key("FOO{}"), "FOO`{`}");
+    }
+
+    #[test]
+    fn test_deactivate() {
+        let deactivate = Pwsh::default().deactivate();
+        assert_snapshot!(replace_path(&deactivate));
+    }
+}
@@ -0,0 +1,164 @@
+use std::borrow::Cow;
+use std::path::Path;
+
+use crate::shell::{is_dir_in_path, is_dir_not_in_nix, shims_dir_to_add, Shell};
+
+#[derive(Default)]
+pub struct Tcsh {}
+
+/// quotes a string for tcsh
+///
+/// single quotes can't be escaped inside single quotes so they are closed and reopened,
+/// `!` still triggers history substitution in single quotes and newlines must be escaped
+fn tcsh_quote(input: &str) -> Cow<'_, str> {
+    if !input.is_empty()
+        && input
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | ':'))
+    {
+        return Cow::Borrowed(input);
+    }
+    let mut quoted = String::with_capacity(input.len() + 2);
+    quoted.push('\'');
+    for ch in input.chars() {
+        match ch {
+            '\'' => quoted.push_str("'\\''"),
+            '!' => quoted.push_str("\\!"),
+            '\n' => quoted.push_str("\\\n"),
+            _ => quoted.push(ch),
+        }
+    }
+    quoted.push('\'');
+    Cow::Owned(quoted)
+}
+
+/// runs _rtx_hook after the user's existing `name` alias, which is saved to be restored on deactivate
+fn chain_alias(name: &str) -> String {
+    formatdoc! {r#"
+        if ( ! $?_rtx_{name} ) set _rtx_{name} = "`alias {name}`";
+        if ( "$_rtx_{name}" == "" ) alias {name} _rtx_hook;
+        if ( "$_rtx_{name}" != "" ) alias {name} "$_rtx_{name}:q; _rtx_hook";
+    "#}
+}
+
+fn restore_alias(name: &str) -> String {
+    formatdoc! {r#"
+        if ( $?_rtx_{name} ) then
+          unalias {name};
+          if ( "$_rtx_{name}" != "" ) alias {name} "$_rtx_{name}:q";
+          unset _rtx_{name};
+        endif
+    "#}
+}
+
+impl Shell for Tcsh {
+    // output is eval'd from backticks which joins it into one line, so every command ends with ;
+    // hook-env output is sourced from a temp file instead so multiline values survive
+    fn activate(&self, exe: &Path, status: bool, shims: bool) -> String {
+        let dir = exe.parent().unwrap();
+        let exe = exe.to_string_lossy();
+        let status = if status { " --status" } else { "" };
+        let mut out = String::new();
+        if is_dir_not_in_nix(dir) && !is_dir_in_path(dir) {
+            let dir = dir.to_string_lossy();
+            out.push_str(&format!("setenv PATH {}:\"$PATH\";\n", tcsh_quote(&dir)));
+        }
+        if let Some(shims_dir) = shims_dir_to_add(shims) {
+            let shims_dir = shims_dir.to_string_lossy();
+            out.push_str(&format!(
+                "setenv PATH {}:\"$PATH\";\n",
+                tcsh_quote(&shims_dir)
+            ));
+        }
+        if shims {
+            out.push_str("setenv __RTX_SHIMS 1;\n");
+        }
+        // with shims the hook only runs when changing directories and once on activation
+        let (precmd, init) = match shims {
+            true => (String::new(), "_rtx_hook;\n"),
+            false => (chain_alias("precmd"), ""),
+        };
+        let cwdcmd = chain_alias("cwdcmd");
+        out.push_str(&formatdoc! {r#"
+            setenv RTX_SHELL tcsh;
+            set _rtx_exe = {exe};
+            alias _rtx_source 'set _rtx_tmp = "`mktemp`"; $_rtx_exe:q \!* >! $_rtx_tmp:q; source $_rtx_tmp:q; rm -f $_rtx_tmp:q; unset _rtx_tmp';
+            alias _rtx_hook '_rtx_source hook-env{status} -s tcsh';
+            alias rtx 'set _rtx_args = (\!*); set _rtx_eval = 0; if ( " $_rtx_args " =~ " deactivate "* || " $_rtx_args " =~ " s "* || " $_rtx_args " =~ " shell "* ) set _rtx_eval = 1; if ( " $_rtx_args " =~ *" --help "* || " $_rtx_args " =~ *" -h "* ) set _rtx_eval = 0; if ( $_rtx_eval ) _rtx_source $_rtx_args:q; if ( ! $_rtx_eval ) $_rtx_exe:q $_rtx_args:q; unset _rtx_args _rtx_eval';
+            {precmd}{cwdcmd}{init}"#, exe = tcsh_quote(&exe)});
+
+        out
+    }
+
+    // deactivate output is sourced rather than eval'd so it can use multiline ifs
+    fn deactivate(&self) -> String {
+        let (precmd, cwdcmd) = (restore_alias("precmd"), restore_alias("cwdcmd"));
+        formatdoc! {r#"
+            {precmd}{cwdcmd}unalias rtx;
+            unalias _rtx_hook;
+            unalias _rtx_source;
+            unset _rtx_exe;
+            unsetenv RTX_SHELL;
+        "#}
+    }
+
+    fn set_env(&self, k: &str, v: &str) -> String {
+        format!("setenv {} {};\n", tcsh_quote(k), tcsh_quote(v))
+    }
+
+    fn unset_env(&self, k: &str) -> String {
+        format!("unsetenv {};\n", tcsh_quote(k))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use crate::test::replace_path;
+
+    use super::*;
+
+    #[test]
+    fn test_hook_init() {
+        let tcsh = Tcsh::default();
+        let exe = Path::new("/some/dir/rtx");
+        assert_snapshot!(tcsh.activate(exe, true, false));
+    }
+
+    #[test]
+    fn test_hook_init_nix() {
+        let tcsh = Tcsh::default();
+        let exe = Path::new("/nix/store/rtx");
+        assert_snapshot!(tcsh.activate(exe, true, false));
+    }
+
+    #[test]
+    fn test_set_env() {
+        assert_snapshot!(Tcsh::default().set_env("FOO", "1"));
+    }
+
+    #[test]
+    fn test_set_env_quoting() {
+        let tcsh = Tcsh::default();
+        assert_snapshot!([
+            tcsh.set_env("FOO", "hello world"),
+            tcsh.set_env("FOO", "it's \"quoted\""),
+            tcsh.set_env("FOO", "line1\nline2"),
+            tcsh.set_env("FOO", "$HOME `ls` \\ !!"),
+            tcsh.set_env("FOO", ""),
+        ]
+        .join(""));
+    }
+
+    #[test]
+    fn test_unset_env() {
+        assert_snapshot!(Tcsh::default().unset_env("FOO"));
+    }
+
+    #[test]
+    fn test_deactivate() {
+        let deactivate = Tcsh::default().deactivate();
+        assert_snapshot!(replace_path(&deactivate));
+    }
+}
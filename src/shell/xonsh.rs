@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 use std::path::Path;
 
-use crate::shell::{is_dir_in_path, is_dir_not_in_nix, Shell};
+use crate::shell::{is_dir_in_path, is_dir_not_in_nix, shims_dir_to_add, Shell};
 
 #[derive(Default)]
 pub struct Xonsh {}
@@ -35,7 +35,7 @@ fn xonsh_escape_char(ch: char) -> Option<&'static str> {
 }
 
 impl Shell for Xonsh {
-    fn activate(&self, exe: &Path, status: bool) -> String {
+    fn activate(&self, exe: &Path, status: bool, shims: bool) -> String {
         let dir = exe.parent().unwrap();
         let exe = exe.display();
         let status = if status { " --status" } else { "" };
@@ -60,6 +60,30 @@ impl Shell for Xonsh {
 
             "#});
         }
+        if let Some(shims_dir) = shims_dir_to_add(shims) {
+            let dir_str = shims_dir.to_string_lossy();
+            let dir_esc = xonsh_escape_sq(&dir_str);
+            out.push_str(&formatdoc! {r#"
+                envx = XSH.env
+                envx['PATH'].add('{dir_esc}', front=True)
+                environ['PATH'] = envx.get_detyped('PATH')
+
+            "#});
+        }
+        if shims {
+            // only update the environment when changing directories, shims handle the rest
+            out.push_str(&formatdoc! {r#"
+                XSH.env['__RTX_SHIMS'] = '1'
+                environ['__RTX_SHIMS'] = '1'
+
+                def listen_chdir(olddir, newdir, **_): # Hook Events
+                  execx($({exe} hook-env{status} -s xonsh))
+
+                XSH.builtins.events.on_chdir(listen_chdir) # Activate hook: after changing directories
+                execx($({exe} hook-env{status} -s xonsh))
+                "#});
+            return out;
+        }
         // todo: subprocess instead of $() is a bit faster, but lose auto-color detection (use $FORCE_COLOR)
         out.push_str(&formatdoc! {r#"
             def listen_prompt(): # Hook Events
@@ -77,6 +101,7 @@ impl Shell for Xonsh {
 
             hooks = {{
               'on_pre_prompt' : ['listen_prompt'],
+              'on_chdir'      : ['listen_chdir'],
             }}
             for   hook_type in hooks:
               hook_fns = hooks[hook_type]
@@ -132,14 +157,14 @@ mod tests {
     fn test_hook_init() {
         let xonsh = Xonsh::default();
         let exe = Path::new("/some/dir/rtx");
-        insta::assert_snapshot!(xonsh.activate(exe, true));
+        insta::assert_snapshot!(xonsh.activate(exe, true, false));
     }
 
     #[test]
     fn test_hook_init_nix() {
         let xonsh = Xonsh::default();
         let exe = Path::new("/nix/store/rtx");
-        insta::assert_snapshot!(xonsh.activate(exe, true));
+        insta::assert_snapshot!(xonsh.activate(exe, true, false));
     }
 
     #[test]
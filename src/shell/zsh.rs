@@ -1,13 +1,13 @@
 use std::path::Path;
 
 use crate::shell::bash::Bash;
-use crate::shell::{is_dir_in_path, is_dir_not_in_nix, Shell};
+use crate::shell::{is_dir_in_path, is_dir_not_in_nix, shims_dir_to_add, Shell};
 
 #[derive(Default)]
 pub struct Zsh {}
 
 impl Shell for Zsh {
-    fn activate(&self, exe: &Path, status: bool) -> String {
+    fn activate(&self, exe: &Path, status: bool, shims: bool) -> String {
         let dir = exe.parent().unwrap();
         let status = if status { " --status" } else { "" };
         let mut out = String::new();
@@ -17,6 +17,20 @@ impl Shell for Zsh {
         if is_dir_not_in_nix(dir) && !is_dir_in_path(dir) {
             out.push_str(&format!("export PATH=\"{}:$PATH\"\n", dir.display()));
         }
+        if let Some(shims_dir) = shims_dir_to_add(shims) {
+            out.push_str(&format!("export PATH=\"{}:$PATH\"\n", shims_dir.display()));
+        }
+        if shims {
+            out.push_str("export __RTX_SHIMS=1\n");
+        }
+        // with shims the hook only runs when changing directories and once on activation
+        let (precmd, init) = match shims {
+            true => ("", "_rtx_hook\n"),
+            false => (
+                "typeset -ag precmd_functions;\nif [[ -z \"${precmd_functions[(r)_rtx_hook]+1}\" ]]; then\n  precmd_functions=( _rtx_hook ${precmd_functions[@]} )\nfi\n",
+                "",
+            ),
+        };
         out.push_str(&formatdoc! {r#"
             export RTX_SHELL=zsh
 
@@ -44,15 +58,11 @@ impl Shell for Zsh {
             _rtx_hook() {{
               eval "$(rtx hook-env{status} -s zsh)";
             }}
-            typeset -ag precmd_functions;
-            if [[ -z "${{precmd_functions[(r)_rtx_hook]+1}}" ]]; then
-              precmd_functions=( _rtx_hook ${{precmd_functions[@]}} )
-            fi
-            typeset -ag chpwd_functions;
+            {precmd}typeset -ag chpwd_functions;
             if [[ -z "${{chpwd_functions[(r)_rtx_hook]+1}}" ]]; then
               chpwd_functions=( _rtx_hook ${{chpwd_functions[@]}} )
             fi
-            "#});
+            {init}"#});
 
         out
     }
@@ -88,14 +98,21 @@ mod tests {
     fn test_hook_init() {
         let zsh = Zsh::default();
         let exe = Path::new("/some/dir/rtx");
-        assert_snapshot!(zsh.activate(exe, true));
+        assert_snapshot!(zsh.activate(exe, true, false));
     }
 
     #[test]
     fn test_hook_init_nix() {
         let zsh = Zsh::default();
         let exe = Path::new("/nix/store/rtx");
-        assert_snapshot!(zsh.activate(exe, true));
+        assert_snapshot!(zsh.activate(exe, true, false));
+    }
+
+    #[test]
+    fn test_hook_init_shims() {
+        let zsh = Zsh::default();
+        let exe = Path::new("/some/dir/rtx");
+        assert_snapshot!(replace_path(&zsh.activate(exe, false, true)));
     }
 
     #[test]
@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+use std::fs::{self, Permissions};
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use color_eyre::eyre::Result;
+use itertools::Itertools;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::cache::CacheManager;
+use crate::config::Config;
+use crate::file;
+use crate::file::remove_all;
+use crate::hash::hash_to_str;
+use crate::path_env::PathEnv;
+use crate::toolset::Toolset;
+use crate::{dirs, env, hook_env};
+
+/// bins resolved by shims in a single directory
+///
+/// This lets shims skip loading config entirely on repeated invocations.
+/// It is invalidated when any of the watched paths change (including the
+/// paths a new config file could be created at and the tools' bin paths)
+/// and is wiped on reshim.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ShimCache {
+    /// mtimes of watched paths, None if the path did not exist
+    watches: BTreeMap<PathBuf, Option<SystemTime>>,
+    path_dirs: Vec<PathBuf>,
+    env: BTreeMap<String, String>,
+    bins: BTreeMap<String, PathBuf>,
+}
+
+impl ShimCache {
+    /// the cache for the current directory if it is still valid
+    pub fn load() -> Option<Self> {
+        let cache: Self = CacheManager::new(cache_path()).parse().ok()?;
+        if !cache.is_fresh() {
+            trace!("shim cache is stale");
+            return None;
+        }
+        Some(cache)
+    }
+
+    fn is_fresh(&self) -> bool {
+        self.watches.iter().all(|(p, mtime)| get_mtime(p) == *mtime)
+    }
+
+    pub fn which(&self, bin_name: &str) -> Option<&PathBuf> {
+        self.bins.get(bin_name).filter(|bin| bin.exists())
+    }
+
+    /// the same env `Toolset::env_with_path` would produce
+    pub fn env_with_path(&self) -> BTreeMap<String, String> {
+        let mut path_env = PathEnv::from_iter(env::PATH.clone());
+        for p in &self.path_dirs {
+            path_env.add(p.clone());
+        }
+        let mut env = self.env.clone();
+        env.insert("PATH".to_string(), path_env.to_string());
+        env
+    }
+
+    /// records `bin` as the resolution of `bin_name` in the current directory
+    pub fn add(config: &Config, ts: &Toolset, bin_name: &str, bin: PathBuf) -> Result<()> {
+        let mut cache = match Self::load() {
+            Some(cache) => cache,
+            None => Self::build(config, ts),
+        };
+        cache.bins.insert(bin_name.to_string(), bin);
+        cache.write()
+    }
+
+    fn write(&self) -> Result<()> {
+        // env may hold secrets from [env] so only the user can read the cache
+        let path = cache_path();
+        let dir = path.parent().unwrap();
+        file::create_dir_all(dir)?;
+        fs::set_permissions(dir, Permissions::from_mode(0o700))?;
+        CacheManager::new(path.clone()).write(self)?;
+        fs::set_permissions(&path, Permissions::from_mode(0o600))?;
+        Ok(())
+    }
+
+    /// removes the caches for all directories
+    pub fn clear() -> Result<()> {
+        let dir = dirs::CACHE.join("shims");
+        if dir.exists() {
+            debug!("clearing shim cache from {}", dir.display());
+            remove_all(dir)?;
+        }
+        Ok(())
+    }
+
+    fn build(config: &Config, ts: &Toolset) -> Self {
+        let mut watches = config
+            .config_files
+            .values()
+            .flat_map(|cf| cf.watch_files())
+            .collect_vec();
+        // new config files could show up at any of these, other files in these directories
+        // like editor swap files or build output don't matter
+        let filenames = config.config_filenames();
+        for dir in dirs::CURRENT.ancestors() {
+            watches.extend(filenames.iter().map(|f| dir.join(f)));
+        }
+        watches.push(dirs::CONFIG.clone());
+
+        let mut env = ts.env(config);
+        let mut path_dirs = config.path_dirs.clone();
+        let bin_paths = ts.list_paths(config);
+        // bins added by `go install` and the like need a reshim
+        watches.extend(bin_paths.iter().cloned());
+        path_dirs.extend(bin_paths);
+        if let Some(path) = env.remove("PATH") {
+            path_dirs.extend(std::env::split_paths(&path));
+        }
+        Self {
+            watches: watches
+                .into_iter()
+                .map(|p| (p.clone(), get_mtime(&p)))
+                .collect(),
+            path_dirs,
+            env,
+            bins: BTreeMap::new(),
+        }
+    }
+}
+
+fn cache_path() -> PathBuf {
+    let key = hash_to_str(&(&*dirs::CURRENT, hook_env::get_rtx_env_vars_hashed()));
+    dirs::CACHE.join("shims").join(format!("{key}.msgpack.z"))
+}
+
+fn get_mtime(path: &Path) -> Option<SystemTime> {
+    path.metadata().and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::config_file::rtx_toml::RtxToml;
+    use crate::config::Config;
+    use crate::toolset::ToolsetBuilder;
+
+    use super::*;
+
+    #[test]
+    fn test_shim_cache() {
+        let mut config = Config::load().unwrap();
+        let ts = ToolsetBuilder::new().build(&mut config).unwrap();
+        let mut cache = ShimCache::build(&config, &ts);
+        let bin = dirs::INSTALLS.join("tiny/3.1.0/bin/rtx-tiny");
+        cache.bins.insert("rtx-tiny".into(), bin.clone());
+        assert_eq!(cache.which("rtx-tiny"), Some(&bin));
+        assert_eq!(cache.which("rtx-missing"), None);
+        assert!(cache.env_with_path()["PATH"].contains("tiny"));
+
+        // a watched path with a different mtime makes the cache stale
+        cache
+            .watches
+            .insert(dirs::CURRENT.clone(), Some(SystemTime::UNIX_EPOCH));
+        assert!(!cache.is_fresh());
+    }
+
+    #[test]
+    fn test_shim_cache_hits() {
+        let mut config = Config::load().unwrap();
+        let ts = ToolsetBuilder::new().build(&mut config).unwrap();
+        let bin = dirs::INSTALLS.join("tiny/3.1.0/bin/rtx-tiny");
+        ShimCache::add(&config, &ts, "rtx-tiny", bin.clone()).unwrap();
+
+        // files that aren't config like editor swap files don't invalidate the cache
+        let mut hits = 0;
+        for i in 0..10 {
+            let other = dirs::CURRENT.join(format!(".swap-{i}.swp"));
+            file::write(&other, "").unwrap();
+            if ShimCache::load().and_then(|c| c.which("rtx-tiny").cloned()) == Some(bin.clone()) {
+                hits += 1;
+            }
+            file::remove_file(&other).unwrap();
+        }
+        assert_eq!(hits, 10);
+
+        // a new config file does
+        let cf = dirs::CURRENT.join(".test.rtx.toml");
+        file::write(&cf, "").unwrap();
+        assert!(ShimCache::load().is_none());
+        file::remove_file(&cf).unwrap();
+        ShimCache::clear().unwrap();
+    }
+
+    #[test]
+    fn test_shim_cache_skips_config() {
+        // a cache hit must not parse config, even if a watched config file is now invalid
+        let dir = tempfile::tempdir().unwrap();
+        let cf = dir.path().join(".rtx.toml");
+        file::write(&cf, "[tools\n").unwrap();
+        assert!(RtxToml::from_file(&cf, true).is_err());
+        let bin = dirs::INSTALLS.join("tiny/3.1.0/bin/rtx-tiny");
+        let cache = ShimCache {
+            watches: BTreeMap::from([(cf.clone(), get_mtime(&cf))]),
+            path_dirs: vec![],
+            env: BTreeMap::new(),
+            bins: BTreeMap::from([("rtx-tiny".to_string(), bin.clone())]),
+        };
+        cache.write().unwrap();
+        let mode = cache_path().metadata().unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        let cache = ShimCache::load().unwrap();
+        assert_eq!(cache.which("rtx-tiny"), Some(&bin));
+        ShimCache::clear().unwrap();
+    }
+}
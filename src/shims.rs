@@ -18,13 +18,14 @@ use crate::file::{create_dir_all, display_path, remove_all};
 use crate::lock_file::LockFile;
 use crate::output::Output;
 use crate::plugins::Plugin;
+use crate::shim_cache::ShimCache;
 use crate::toolset::{ToolVersion, Toolset, ToolsetBuilder};
 use crate::{dirs, file};
 
 // executes as if it was a shim if the command is not "rtx", e.g.: "node"
 #[allow(dead_code)]
 pub fn handle_shim(mut config: Config, args: &[String], out: &mut Output) -> Result<Config> {
-    let (_, bin_name) = args[0].rsplit_once('/').unwrap_or(("", &args[0]));
+    let bin_name = shim_name(args);
     if bin_name == "rtx" {
         return Ok(config);
     }
@@ -36,16 +37,56 @@ pub fn handle_shim(mut config: Config, args: &[String], out: &mut Output) -> Res
         command: Some(args),
         cd: None,
     };
-    exec.run(config, out)?;
+    // which_shim already installed what provides the bin so this skips Exec::run
+    // which would install every missing version in the toolset
+    let ts = ToolsetBuilder::new().build(&mut config)?;
+    exec.run_with_env(ts.env_with_path(&config), out)?;
+    exit(0);
+}
+
+// runs the shim with a previously resolved bin if the shim cache for this directory is fresh
+// this happens before config is loaded so it needs to stay cheap
+pub fn handle_shim_from_cache(args: &[String], out: &mut Output) -> Result<()> {
+    let bin_name = shim_name(args);
+    if bin_name == "rtx" || !dirs::SHIMS.join(bin_name).exists() {
+        return Ok(());
+    }
+    let Some(cache) = ShimCache::load() else {
+        return Ok(());
+    };
+    let Some(bin) = cache.which(bin_name) else {
+        return Ok(());
+    };
+    trace!("shim cache hit for {bin_name}: {}", display_path(bin));
+    let mut args: Vec<OsString> = args.iter().map(OsString::from).collect();
+    args[0] = bin.into();
+    let exec = Exec {
+        tool: vec![],
+        c: None,
+        command: Some(args),
+        cd: None,
+    };
+    exec.run_with_env(cache.env_with_path(), out)?;
     exit(0);
 }
 
+fn shim_name(args: &[String]) -> &str {
+    let (_, bin_name) = args[0].rsplit_once('/').unwrap_or(("", &args[0]));
+    bin_name
+}
+
 fn which_shim(config: &mut Config, bin_name: &str) -> Result<PathBuf> {
     let shim = dirs::SHIMS.join(bin_name);
     if shim.exists() {
-        let ts = ToolsetBuilder::new().build(config)?;
-        if let Some((p, tv)) = ts.which(config, bin_name) {
-            if let Some(bin) = p.which(config, &ts, &tv, bin_name)? {
+        let mut ts = ToolsetBuilder::new().build(config)?;
+        reshim_if_bin_paths_changed(config, &ts)?;
+        if let Some(bin) = which_in_toolset(config, &ts, bin_name)? {
+            cache_shim(config, &ts, bin_name, &bin);
+            return Ok(bin);
+        }
+        if install_missing_for_bin(config, &mut ts, bin_name)? {
+            if let Some(bin) = which_in_toolset(config, &ts, bin_name)? {
+                cache_shim(config, &ts, bin_name, &bin);
                 return Ok(bin);
             }
         }
@@ -67,6 +108,36 @@ fn which_shim(config: &mut Config, bin_name: &str) -> Result<PathBuf> {
     Err(eyre!("{} is not a valid shim", bin_name))
 }
 
+fn which_in_toolset(config: &Config, ts: &Toolset, bin_name: &str) -> Result<Option<PathBuf>> {
+    match ts.which(config, bin_name) {
+        Some((p, tv)) => p.which(config, ts, &tv, bin_name),
+        None => Ok(None),
+    }
+}
+
+fn cache_shim(config: &Config, ts: &Toolset, bin_name: &str, bin: &Path) {
+    if let Err(err) = ShimCache::add(config, ts, bin_name, bin.to_path_buf()) {
+        warn!("failed to write shim cache: {:#}", err);
+    }
+}
+
+/// installs the missing current versions of plugins that provide `bin_name`
+/// if missing_runtime_behavior allows it
+fn install_missing_for_bin(config: &mut Config, ts: &mut Toolset, bin_name: &str) -> Result<bool> {
+    let plugins = ts
+        .list_rtvs_with_bin(config, bin_name)?
+        .into_iter()
+        .map(|tv| tv.plugin_name)
+        .collect::<HashSet<_>>();
+    let missing = ts
+        .list_missing_versions(config)
+        .into_iter()
+        .filter(|tv| plugins.contains(&tv.plugin_name))
+        .cloned()
+        .collect_vec();
+    ts.install_missing_versions(config, missing)
+}
+
 pub fn reshim(config: &Config, ts: &Toolset) -> Result<()> {
     let _lock = LockFile::new(&dirs::SHIMS)
         .with_callback(|l| {
@@ -77,6 +148,8 @@ pub fn reshim(config: &Config, ts: &Toolset) -> Result<()> {
     let rtx_bin = file::which("rtx").unwrap_or(env::RTX_EXE.clone());
 
     create_dir_all(&*dirs::SHIMS)?;
+    // installed versions may have changed what shims resolve to
+    ShimCache::clear()?;
 
     let existing_shims = list_executables_in_dir(&dirs::SHIMS)?
         .into_par_iter()
@@ -132,10 +205,30 @@ pub fn reshim(config: &Config, ts: &Toolset) -> Result<()> {
             }
         }
     }
+    // mark when this reshim happened so bins added later can be detected
+    file::touch_dir(&dirs::SHIMS)?;
 
     Ok(())
 }
 
+/// reshims if any of the active tools' bin paths changed since the last reshim
+/// this picks up bins added outside of rtx, e.g.: by `go install` or `pip install`
+pub fn reshim_if_bin_paths_changed(config: &Config, ts: &Toolset) -> Result<()> {
+    let Ok(last_reshim) = dirs::SHIMS.metadata().and_then(|m| m.modified()) else {
+        return Ok(());
+    };
+    let changed = ts.list_paths(config).into_iter().find(|p| {
+        p.metadata()
+            .and_then(|m| m.modified())
+            .is_ok_and(|modified| modified > last_reshim)
+    });
+    if let Some(path) = changed {
+        debug!("{} changed since last reshim", display_path(&path));
+        reshim(config, ts)?;
+    }
+    Ok(())
+}
+
 // lists all the paths to bins in a tv that shims will be needed for
 fn list_tool_bins(
     config: &Config,
@@ -229,3 +322,32 @@ fn err_no_version_set(
         Err(eyre!(msg.trim().to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::config::config_file::tool_versions::ToolVersions;
+    use crate::config::MissingRuntimeBehavior;
+    use crate::{assert_cli, dirs};
+
+    use super::*;
+
+    #[test]
+    fn test_which_shim_autoinstall() {
+        assert_cli!("reshim");
+        let mut config = Config::load().unwrap();
+        let path = dirs::CURRENT.join(".autoinstall-tool-versions");
+        let cf = ToolVersions::parse_str("dummy 2.0.0", path.clone(), true).unwrap();
+        config.config_files.insert(path, Box::new(cf));
+        config
+            .config_files
+            .move_index(config.config_files.len() - 1, 0);
+
+        config.settings.missing_runtime_behavior = MissingRuntimeBehavior::Ignore;
+        assert!(which_shim(&mut config, "dummy").is_err());
+
+        config.settings.missing_runtime_behavior = MissingRuntimeBehavior::AutoInstall;
+        let bin = which_shim(&mut config, "dummy").unwrap();
+        assert_eq!(bin, dirs::INSTALLS.join("dummy/2.0.0/bin/dummy"));
+        assert_cli!("uninstall", "dummy@2.0.0");
+    }
+}
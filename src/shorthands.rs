@@ -1,51 +1,192 @@
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
 use color_eyre::eyre::Result;
-use toml::Table;
+use serde_derive::Deserialize;
 
+use crate::cache::CacheManager;
 use crate::config::Settings;
-use crate::default_shorthands::DEFAULT_SHORTHANDS;
-use crate::{dirs, file};
+use crate::hash::hash_to_str;
+use crate::{dirs, env, file, http};
 
 pub type Shorthands = HashMap<String, String>;
 
-pub fn get_shorthands(settings: &Settings) -> Shorthands {
-    let mut shorthands = HashMap::new();
+/// the plugins that can be installed by name out of the box, generated by scripts/update-shorthand-repo.sh
+const DEFAULT_REGISTRY: &str = include_str!("../registry.toml");
+
+/// plugins that can be installed by name, keyed by that name
+pub type Registry = BTreeMap<String, RegistryEntry>;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RegistryEntry {
+    pub repo: String,
+    pub description: Option<String>,
+    /// e.g.: ["asdf", "rtx"]
+    pub backends: Vec<String>,
+    /// commit or tag the plugin is pinned to
+    pub rev: Option<String>,
+}
+
+impl RegistryEntry {
+    /// the repo with the pinned rev if there is one, e.g.: https://github.com/rtx-plugins/rtx-tiny.git#v1.0.0
+    pub fn url(&self) -> String {
+        match &self.rev {
+            Some(rev) => format!("{}#{rev}", self.repo),
+            None => self.repo.clone(),
+        }
+    }
+
+    pub fn matches(&self, name: &str, query: &str) -> bool {
+        let query = query.to_lowercase();
+        name.to_lowercase().contains(&query)
+            || self.repo.to_lowercase().contains(&query)
+            || self
+                .description
+                .as_ref()
+                .is_some_and(|d| d.to_lowercase().contains(&query))
+    }
+}
+
+/// an entry in a registry file, either just the repo url or a table
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RegistryFileEntry {
+    Url(String),
+    Table {
+        repo: Option<String>,
+        description: Option<String>,
+        backends: Option<Vec<String>>,
+        rev: Option<String>,
+    },
+}
+
+type RegistryFile = BTreeMap<String, RegistryFileEntry>;
+
+/// builds the registry from the default registry, the remote registry, and the shorthands file
+/// with each layer overriding the previous one
+pub fn get_registry(settings: &Settings) -> Registry {
+    let mut registry = Registry::new();
     if !settings.disable_default_shorthands {
-        shorthands.extend(
-            DEFAULT_SHORTHANDS
-                .iter()
-                .map(|(k, v)| (k.to_string(), v.to_string())),
-        );
+        match parse_registry_file(DEFAULT_REGISTRY, Path::new("registry.toml")) {
+            Ok(default) => apply_registry_file(&mut registry, default),
+            Err(err) => warn!("Failed to parse default plugin registry: {:#}", err),
+        }
     };
+    if let Some(url) = &settings.plugin_registry_url {
+        match fetch_remote_registry(url) {
+            Ok(remote) => apply_registry_file(&mut registry, remote),
+            Err(err) => {
+                warn!("Failed to fetch plugin registry: {} {:#}", url, err);
+            }
+        }
+    }
     if let Some(f) = &settings.shorthands_file {
         match parse_shorthands_file(f.clone()) {
-            Ok(custom) => {
-                shorthands.extend(custom);
-            }
+            Ok(custom) => apply_registry_file(&mut registry, custom),
             Err(err) => {
                 warn!("Failed to read shorthands file: {} {:#}", &f.display(), err);
             }
         }
     }
-    shorthands
+    registry
+}
+
+/// the repo url of each plugin in the registry
+pub fn get_shorthands(registry: &Registry) -> Shorthands {
+    registry
+        .iter()
+        .map(|(name, entry)| (name.clone(), entry.url()))
+        .collect()
+}
+
+fn apply_registry_file(registry: &mut Registry, registry_file: RegistryFile) {
+    for (name, entry) in registry_file {
+        match entry {
+            RegistryFileEntry::Url(repo) => {
+                let entry = RegistryEntry {
+                    repo,
+                    ..Default::default()
+                };
+                registry.insert(name, entry);
+            }
+            RegistryFileEntry::Table {
+                repo,
+                description,
+                backends,
+                rev,
+            } => {
+                let entry = registry.entry(name.clone()).or_default();
+                if let Some(repo) = repo {
+                    if repo != entry.repo {
+                        // a pinned rev or description from another repo no longer applies
+                        *entry = RegistryEntry {
+                            repo,
+                            ..Default::default()
+                        };
+                    }
+                }
+                if description.is_some() {
+                    entry.description = description;
+                }
+                if let Some(backends) = backends {
+                    entry.backends = backends;
+                }
+                if rev.is_some() {
+                    entry.rev = rev;
+                }
+                if entry.repo.is_empty() {
+                    warn!("plugin registry entry {name} has no repo");
+                    registry.remove(&name);
+                }
+            }
+        }
+    }
+}
+
+fn fetch_remote_registry(url: &str) -> Result<RegistryFile> {
+    let cache_path = dirs::CACHE
+        .join("registry")
+        .join(format!("{}.msgpack.z", hash_to_str(&url)));
+    let raw = CacheManager::<String>::new(cache_path)
+        .with_fresh_duration(*env::RTX_FETCH_REMOTE_VERSIONS_CACHE)
+        .get_or_try_init(|| {
+            http::Client::new_with_timeout(*env::RTX_FETCH_REMOTE_VERSIONS_TIMEOUT)?.get_text(url)
+        })?
+        .clone();
+    parse_registry_file(&raw, Path::new(url))
 }
 
-fn parse_shorthands_file(mut f: PathBuf) -> Result<Shorthands> {
+fn parse_shorthands_file(mut f: PathBuf) -> Result<RegistryFile> {
     if f.starts_with("~") {
         f = dirs::HOME.join(f.strip_prefix("~")?);
     }
     let raw = file::read_to_string(&f)?;
-    let toml = raw.parse::<Table>()?;
+    parse_registry_file(&raw, &f)
+}
 
-    let mut shorthands = HashMap::new();
-    for (k, v) in toml {
-        if let Some(v) = v.as_str() {
-            shorthands.insert(k, v.to_string());
+/// registries are json if the path ends in .json, otherwise toml
+/// entries that can't be parsed are skipped with a warning so one bad entry doesn't drop the whole file
+fn parse_registry_file(raw: &str, path: &Path) -> Result<RegistryFile> {
+    let entries: BTreeMap<String, serde_json::Value> =
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => serde_json::from_str(raw)?,
+            _ => toml::from_str(raw)?,
+        };
+    let mut registry_file = RegistryFile::new();
+    for (name, value) in entries {
+        match serde_json::from_value(value) {
+            Ok(entry) => {
+                registry_file.insert(name, entry);
+            }
+            Err(err) => {
+                warn!(
+                    "invalid plugin registry entry {name} in {}: {err}",
+                    path.display()
+                );
+            }
         }
     }
-    Ok(shorthands)
+    Ok(registry_file)
 }
 
 #[cfg(test)]
@@ -60,7 +201,7 @@ mod tests {
             shorthands_file: Some("../fixtures/shorthands.toml".into()),
             ..Settings::default()
         };
-        let shorthands = get_shorthands(&settings);
+        let shorthands = get_shorthands(&get_registry(&settings));
         assert_str_eq!(
             shorthands["elixir"],
             "https://github.com/asdf-vm/asdf-elixir.git"
@@ -75,7 +216,68 @@ mod tests {
             shorthands_file: Some("test/fixtures/missing.toml".into()),
             ..Settings::default()
         };
-        let shorthands = get_shorthands(&settings);
+        let shorthands = get_shorthands(&get_registry(&settings));
         assert!(!shorthands.is_empty());
     }
+
+    #[test]
+    fn test_get_registry_overlay() {
+        let settings = Settings {
+            shorthands_file: Some("../fixtures/registry.toml".into()),
+            ..Settings::default()
+        };
+        let registry = get_registry(&settings);
+        let tiny = &registry["tiny"];
+        assert_str_eq!(tiny.repo, "https://github.com/rtx-plugins/rtx-tiny.git");
+        assert_str_eq!(
+            tiny.url(),
+            "https://github.com/rtx-plugins/rtx-tiny.git#v1.0.0"
+        );
+        assert_eq!(tiny.backends, vec!["asdf"]);
+        assert!(tiny.matches("tiny", "TESTING"));
+        assert_str_eq!(registry["node"].url(), "https://node");
+        assert_str_eq!(
+            registry["my-tool"].description.as_deref().unwrap(),
+            "an internal tool"
+        );
+    }
+
+    #[test]
+    fn test_parse_registry_json() {
+        let raw = r#"{
+            "node": "https://node",
+            "tiny": {"repo": "https://tiny", "rev": "abc123"},
+            "no-repo": {"description": "missing a repo"}
+        }"#;
+        let mut registry = Registry::new();
+        let registry_file = parse_registry_file(raw, Path::new("registry.json")).unwrap();
+        apply_registry_file(&mut registry, registry_file);
+        assert_str_eq!(registry["node"].url(), "https://node");
+        assert_str_eq!(registry["tiny"].url(), "https://tiny#abc123");
+        assert!(!registry.contains_key("no-repo"));
+    }
+
+    #[test]
+    fn test_parse_registry_invalid_entry() {
+        let raw = r#"
+            node = "https://node"
+            bad = 1
+            [tiny]
+            repo = "https://tiny"
+            backends = "asdf"
+        "#;
+        let registry_file = parse_registry_file(raw, Path::new("registry.toml")).unwrap();
+        assert_eq!(registry_file.keys().collect::<Vec<_>>(), vec!["node"]);
+    }
+
+    #[test]
+    fn test_default_registry() {
+        let registry = get_registry(&Settings::default());
+        let poetry = &registry["poetry"];
+        assert_str_eq!(poetry.repo, "https://github.com/rtx-plugins/rtx-poetry.git");
+        assert_eq!(poetry.backends, vec!["rtx"]);
+        assert!(poetry.matches("poetry", "packaging"));
+        assert_eq!(registry["shellcheck"].backends, vec!["asdf"]);
+        assert!(registry["shellcheck"].matches("shellcheck", "luizm/asdf"));
+    }
 }
@@ -1,12 +1,14 @@
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use once_cell::sync::Lazy;
 use tera::{Context, Tera, Value};
 
+use crate::cli::version::{ARCH, OS};
 use crate::cmd::cmd;
-use crate::env;
 use crate::hash::hash_to_str;
+use crate::toolset::VersionRange;
+use crate::{env, file};
 
 pub static BASE_CONTEXT: Lazy<Context> = Lazy::new(|| {
     let mut context = Context::new();
@@ -16,14 +18,14 @@ pub static BASE_CONTEXT: Lazy<Context> = Lazy::new(|| {
 
 pub fn get_tera(dir: &Path) -> Tera {
     let mut tera = Tera::default();
-    let dir = dir.to_path_buf();
+    let exec_dir = dir.to_path_buf();
     tera.register_function(
         "exec",
         move |args: &HashMap<String, Value>| -> tera::Result<Value> {
             match args.get("command") {
                 Some(Value::String(command)) => {
                     let result = cmd("bash", ["-c", command])
-                        .dir(&dir)
+                        .dir(&exec_dir)
                         .full_env(&*env::PRISTINE_ENV)
                         .read()?;
                     Ok(Value::String(result))
@@ -32,6 +34,86 @@ pub fn get_tera(dir: &Path) -> Tera {
             }
         },
     );
+    tera.register_function(
+        "os",
+        |_args: &HashMap<String, Value>| -> tera::Result<Value> {
+            Ok(Value::String(OS.to_string()))
+        },
+    );
+    tera.register_function(
+        "arch",
+        |_args: &HashMap<String, Value>| -> tera::Result<Value> {
+            Ok(Value::String(ARCH.to_string()))
+        },
+    );
+    tera.register_function(
+        "num_cpus",
+        |_args: &HashMap<String, Value>| -> tera::Result<Value> {
+            Ok(Value::Number(num_cpus::get().into()))
+        },
+    );
+    let read_dir = dir.to_path_buf();
+    tera.register_function(
+        "read_file",
+        move |args: &HashMap<String, Value>| -> tera::Result<Value> {
+            match args.get("path") {
+                // relative paths are relative to the config file
+                Some(Value::String(path)) => {
+                    let contents = file::read_to_string(read_dir.join(path))
+                        .map_err(|e| tera::Error::msg(format!("{e:#}")))?;
+                    Ok(Value::String(contents))
+                }
+                _ => Err("read_file path must be a string".into()),
+            }
+        },
+    );
+    tera.register_filter(
+        "from_json",
+        move |input: &Value, _args: &HashMap<String, Value>| match input {
+            Value::String(s) => {
+                serde_json::from_str(s).map_err(|e| tera::Error::msg(format!("invalid json: {e}")))
+            }
+            _ => Err("from_json input must be a string".into()),
+        },
+    );
+    tera.register_filter(
+        "from_toml",
+        move |input: &Value, _args: &HashMap<String, Value>| match input {
+            Value::String(s) => {
+                let toml: toml::Value = toml::from_str(s)
+                    .map_err(|e| tera::Error::msg(format!("invalid toml: {e}")))?;
+                Ok(serde_json::to_value(toml)?)
+            }
+            _ => Err("from_toml input must be a string".into()),
+        },
+    );
+    tera.register_filter(
+        "from_yaml",
+        move |input: &Value, _args: &HashMap<String, Value>| match input {
+            Value::String(s) => {
+                let yaml: Value = serde_yaml::from_str(s)
+                    .map_err(|e| tera::Error::msg(format!("invalid yaml: {e}")))?;
+                Ok(yaml)
+            }
+            _ => Err("from_yaml input must be a string".into()),
+        },
+    );
+    tera.register_filter(
+        "join_path",
+        move |input: &Value, _args: &HashMap<String, Value>| match input {
+            Value::Array(parts) => {
+                let mut path = PathBuf::new();
+                for part in parts {
+                    match part {
+                        Value::String(part) => path.push(part),
+                        _ => return Err("join_path input must be an array of strings".into()),
+                    }
+                }
+                Ok(Value::String(path.to_string_lossy().to_string()))
+            }
+            _ => Err("join_path input must be an array of strings".into()),
+        },
+    );
     tera.register_filter(
         "hash",
         move |input: &Value, _args: &HashMap<String, Value>| match input {
@@ -69,5 +151,79 @@ pub fn get_tera(dir: &Path) -> Tera {
             _ => Err("file_exists input must be a string".into()),
         },
     );
+    tera.register_tester(
+        "semver_matching",
+        move |input: Option<&Value>, args: &[Value]| match (input, args) {
+            (Some(Value::String(version)), [Value::String(range)]) => {
+                let range = VersionRange::parse(range).map_err(tera::Error::msg)?;
+                Ok(range.matches(version))
+            }
+            _ => Err("semver_matching input must be a string and its argument a range".into()),
+        },
+    );
     tera
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::dirs;
+
+    use super::*;
+
+    fn render(template: &str) -> String {
+        let mut tera = get_tera(&dirs::HOME);
+        tera.render_str(template, &BASE_CONTEXT).unwrap()
+    }
+
+    #[test]
+    fn test_read_file() {
+        assert_eq!(
+            render("{{ read_file(path='cwd/.test-tool-versions') | trim }}"),
+            "tiny 3"
+        );
+    }
+
+    #[test]
+    fn test_parse_filters() {
+        assert_eq!(
+            render(
+                r#"{{ '{"engines": {"node": ">=18"}}' | from_json | get(key='engines') | get(key='node') }}"#
+            ),
+            ">=18"
+        );
+        assert_eq!(
+            render("{{ 'go = \"1.21\"' | from_toml | get(key='go') }}"),
+            "1.21"
+        );
+        assert_eq!(
+            render("{{ 'python:\n  version: 3.11' | from_yaml | get(key='python') | get(key='version') }}"),
+            "3.11"
+        );
+        assert_eq!(
+            render("{{ '- 18\n- 20' | from_yaml | join(sep=',') }}"),
+            "18,20"
+        );
+    }
+
+    #[test]
+    fn test_functions() {
+        assert_eq!(
+            render("{{ os() }}-{{ arch() }}"),
+            format!("{}-{}", *OS, *ARCH)
+        );
+        assert_ne!(render("{{ num_cpus() }}"), "0");
+        assert_eq!(render("{{ ['a', 'b', 'c'] | join_path }}"), "a/b/c");
+    }
+
+    #[test]
+    fn test_semver_matching() {
+        assert_eq!(
+            render("{% set v = '20.1.0' %}{% if v is semver_matching('>=18 <21') %}yes{% endif %}"),
+            "yes"
+        );
+        assert_eq!(
+            render("{% set v = '21.0.0' %}{% if v is semver_matching('^20') %}yes{% else %}no{% endif %}"),
+            "no"
+        );
+    }
+}
@@ -14,21 +14,26 @@ pub use tool_source::ToolSource;
 pub use tool_version::ToolVersion;
 pub use tool_version_list::ToolVersionList;
 pub use tool_version_request::ToolVersionRequest;
+pub use version_range::VersionRange;
 
-use crate::config::Config;
+use crate::cli::version::{ARCH, OS};
+use crate::config::{Config, MissingRuntimeBehavior};
 use crate::env;
+use crate::hash::hash_to_str;
 use crate::install_context::InstallContext;
 use crate::path_env::PathEnv;
 use crate::plugins::{Plugin, PluginName};
 use crate::runtime_symlinks;
 use crate::shims;
 use crate::ui::multi_progress_report::MultiProgressReport;
+use crate::ui::prompt;
 
 mod builder;
 mod tool_source;
 mod tool_version;
 mod tool_version_list;
 mod tool_version_request;
+mod version_range;
 
 pub type ToolVersionOptions = BTreeMap<String, String>;
 
@@ -94,6 +99,35 @@ impl Toolset {
         self.install_versions(config, versions, &mpr, false)
     }
 
+    /// installs `versions` if `missing_runtime_behavior` allows it, returns true if anything was installed
+    pub fn install_missing_versions(
+        &mut self,
+        config: &mut Config,
+        versions: Vec<ToolVersion>,
+    ) -> Result<bool> {
+        if versions.is_empty() {
+            return Ok(false);
+        }
+        let list = versions.iter().join(" ");
+        match config.settings.missing_runtime_behavior {
+            MissingRuntimeBehavior::AutoInstall => {}
+            MissingRuntimeBehavior::Prompt => {
+                let msg = format!("rtx: install missing {list}?");
+                if !config.settings.yes && !prompt::confirm(&msg)? {
+                    return Ok(false);
+                }
+            }
+            MissingRuntimeBehavior::Warn => {
+                warn!("missing: {list}, install with `rtx install`");
+                return Ok(false);
+            }
+            MissingRuntimeBehavior::Ignore => return Ok(false),
+        }
+        let mpr = MultiProgressReport::new(config.show_progress_bars());
+        self.install_versions(config, versions, &mpr, false)?;
+        Ok(true)
+    }
+
     pub fn list_missing_plugins(&self, config: &mut Config) -> Vec<PluginName> {
         self.versions
             .keys()
@@ -266,6 +300,17 @@ impl Toolset {
             })
             .collect()
     }
+    /// a short hash of the resolved tool versions and the current platform
+    /// suitable for use as a CI cache key
+    pub fn hash(&self, config: &Config) -> String {
+        let versions = self
+            .list_current_versions(config)
+            .into_iter()
+            .map(|(p, tv)| (p.name().to_string(), tv.version))
+            .sorted()
+            .collect_vec();
+        hash_to_str(&(versions, &*OS, &*ARCH))
+    }
     pub fn env_with_path(&self, config: &Config) -> BTreeMap<String, String> {
         let mut path_env = PathEnv::from_iter(env::PATH.clone());
         for p in config.path_dirs.clone() {
@@ -360,3 +405,33 @@ impl Display for Toolset {
         write!(f, "{}", plugins.join(", "))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::tool::ToolArg;
+
+    use super::*;
+
+    #[test]
+    fn test_install_missing_versions_not_allowed() {
+        let mut config = Config::load().unwrap();
+        let mut ts = ToolsetBuilder::new()
+            .with_args(&[ToolArg::parse("tiny@1.1.0")])
+            .build(&mut config)
+            .unwrap();
+        let missing = ts
+            .list_missing_versions(&config)
+            .into_iter()
+            .cloned()
+            .collect_vec();
+        assert_eq!(missing.len(), 1);
+        for behavior in [MissingRuntimeBehavior::Warn, MissingRuntimeBehavior::Ignore] {
+            config.settings.missing_runtime_behavior = behavior;
+            let installed = ts
+                .install_missing_versions(&mut config, missing.clone())
+                .unwrap();
+            assert!(!installed);
+        }
+        assert_eq!(ts.list_missing_versions(&config).len(), 1);
+    }
+}
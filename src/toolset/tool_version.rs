@@ -8,9 +8,10 @@ use versions::{Chunk, Version};
 
 use crate::config::Config;
 use crate::dirs;
+use crate::errors::Error::VersionNotFound;
 use crate::hash::hash_to_str;
 use crate::plugins::{Plugin, PluginName};
-use crate::toolset::{ToolVersionOptions, ToolVersionRequest};
+use crate::toolset::{ToolVersionOptions, ToolVersionRequest, VersionRange};
 
 /// represents a single version of a tool for a particular plugin
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -54,6 +55,9 @@ impl ToolVersion {
             ToolVersionRequest::Prefix(_, prefix) => {
                 Self::resolve_prefix(config, tool, request, &prefix, opts)?
             }
+            ToolVersionRequest::Range(_, range) => {
+                Self::resolve_range(config, tool, request, latest_versions, &range, opts)?
+            }
             ToolVersionRequest::Sub {
                 sub, orig_version, ..
             } => Self::resolve_sub(
@@ -110,6 +114,7 @@ impl ToolVersion {
         match &self.request {
             ToolVersionRequest::Version(_, _) => self.version.to_string(),
             ToolVersionRequest::Prefix(_, _) => self.version.to_string(),
+            ToolVersionRequest::Range(_, _) => self.version.to_string(),
             ToolVersionRequest::Sub { .. } => self.version.to_string(),
             ToolVersionRequest::Ref(_, r) => format!("ref-{}", r),
             ToolVersionRequest::Path(_, p) => format!("path-{}", hash_to_str(p)),
@@ -146,6 +151,9 @@ impl ToolVersion {
                 let sub = part.split_once('-').unwrap().1;
                 return Self::resolve_sub(config, tool, request, latest_versions, sub, v, opts);
             }
+            _ if VersionRange::is_range(&v) => {
+                return Self::resolve_range(config, tool, request, latest_versions, &v, opts);
+            }
             _ => (),
         }
 
@@ -220,6 +228,29 @@ impl ToolVersion {
         Ok(Self::new(tool, request, opts, v.to_string()))
     }
 
+    /// resolve a range like `>=18 <21` or `^1.21` to the highest matching version
+    /// installed versions are preferred unless `latest_versions` is set
+    fn resolve_range(
+        config: &Config,
+        tool: Arc<dyn Plugin>,
+        request: ToolVersionRequest,
+        latest_versions: bool,
+        range: &str,
+        opts: ToolVersionOptions,
+    ) -> Result<Self> {
+        let range = VersionRange::parse(range)?;
+        if !latest_versions {
+            if let Some(v) = range.max_satisfying(&tool.list_installed_versions()?) {
+                return Ok(Self::new(tool, request, opts, v.clone()));
+            }
+        }
+        let remote_versions = tool.list_remote_versions(&config.settings)?;
+        match range.max_satisfying(&remote_versions) {
+            Some(v) => Ok(Self::new(tool, request, opts, v.clone())),
+            None => Err(VersionNotFound(tool.name().into(), range.to_string()))?,
+        }
+    }
+
     fn resolve_ref(tool: Arc<dyn Plugin>, r: String, opts: ToolVersionOptions) -> Self {
         let request = ToolVersionRequest::Ref(tool.name().into(), r);
         let version = request.version();
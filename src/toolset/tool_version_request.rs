@@ -6,12 +6,14 @@ use color_eyre::eyre::Result;
 
 use crate::config::Config;
 use crate::plugins::Plugin;
+use crate::toolset::version_range::VersionRange;
 use crate::toolset::{ToolVersion, ToolVersionOptions};
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum ToolVersionRequest {
     Version(String, String),
     Prefix(String, String),
+    Range(String, String),
     Ref(String, String),
     Path(String, PathBuf),
     Sub {
@@ -40,6 +42,8 @@ impl ToolVersionRequest {
             None => {
                 if s == "system" {
                     Self::System(plugin_name)
+                } else if VersionRange::is_range(&s) {
+                    Self::Range(plugin_name, s)
                 } else {
                     Self::Version(plugin_name, s.to_string())
                 }
@@ -52,6 +56,7 @@ impl ToolVersionRequest {
         match self {
             Self::Version(p, _) => p,
             Self::Prefix(p, _) => p,
+            Self::Range(p, _) => p,
             Self::Ref(p, _) => p,
             Self::Path(p, _) => p,
             Self::Sub { plugin_name, .. } => plugin_name,
@@ -63,6 +68,7 @@ impl ToolVersionRequest {
         match self {
             Self::Version(_, v) => v.clone(),
             Self::Prefix(_, p) => format!("prefix:{p}"),
+            Self::Range(_, r) => r.clone(),
             Self::Ref(_, r) => format!("ref:{r}"),
             Self::Path(_, p) => format!("path:{}", p.display()),
             Self::Sub {
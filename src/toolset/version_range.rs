@@ -0,0 +1,284 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+use color_eyre::eyre::{eyre, Result};
+
+/// a semver-style constraint such as `>=18 <21`, `~3.11` or `^1.21`
+///
+/// space or comma separated comparators must all match, `||` separates alternatives.
+/// Partial versions are allowed, `=3.11` (or just `3.11`) matches any 3.11.x.
+/// Prerelease versions (anything with non-numeric parts like `3.12.0rc1`) only
+/// match if the range itself mentions a prerelease.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionRange {
+    raw: String,
+    alternatives: Vec<Vec<Comparator>>,
+    allow_prerelease: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Comparator {
+    op: Op,
+    version: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl VersionRange {
+    /// true if `s` looks like a range rather than a version or prefix
+    pub fn is_range(s: &str) -> bool {
+        s.starts_with(['^', '~', '>', '<', '=']) || s.contains("||")
+    }
+
+    pub fn parse(s: &str) -> Result<Self> {
+        let err = || eyre!("invalid version range: {s}");
+        let mut alternatives = vec![];
+        for alt in s.split("||") {
+            let mut comparators = vec![];
+            let mut pending_op = String::new();
+            for token in alt.split([' ', ',']).filter(|t| !t.is_empty()) {
+                let token = format!("{pending_op}{token}");
+                let split = token
+                    .find(|c: char| !matches!(c, '^' | '~' | '>' | '<' | '='))
+                    .unwrap_or(token.len());
+                let (op, version) = token.split_at(split);
+                if version.is_empty() {
+                    // e.g.: ">= 18"
+                    pending_op = op.to_string();
+                    continue;
+                }
+                pending_op.clear();
+                comparators.extend(parse_comparator(op, version).ok_or_else(err)?);
+            }
+            if !pending_op.is_empty() {
+                return Err(err());
+            }
+            alternatives.push(comparators);
+        }
+        Ok(Self {
+            raw: s.to_string(),
+            alternatives,
+            allow_prerelease: s.split("||").any(|alt| {
+                alt.split([' ', ','])
+                    .any(|t| parse_version(t.trim_start_matches(['^', '~', '>', '<', '='])).1)
+            }),
+        })
+    }
+
+    pub fn matches(&self, version: &str) -> bool {
+        let (v, prerelease) = parse_version(version);
+        if v.is_empty() || (prerelease && !self.allow_prerelease) {
+            return false;
+        }
+        self.alternatives
+            .iter()
+            .any(|cmps| cmps.iter().all(|c| c.matches(&v)))
+    }
+
+    /// the highest version in `versions` matching the range
+    pub fn max_satisfying<'a>(&self, versions: &'a [String]) -> Option<&'a String> {
+        versions
+            .iter()
+            .filter(|v| self.matches(v))
+            .max_by(|a, b| compare(&parse_version(a).0, &parse_version(b).0))
+    }
+}
+
+impl Display for VersionRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl Comparator {
+    fn new(op: Op, version: Vec<u64>) -> Self {
+        Self { op, version }
+    }
+
+    fn matches(&self, v: &[u64]) -> bool {
+        let ord = compare(v, &self.version);
+        match self.op {
+            Op::Gt => ord == Ordering::Greater,
+            Op::Ge => ord != Ordering::Less,
+            Op::Lt => ord == Ordering::Less,
+            Op::Le => ord != Ordering::Greater,
+        }
+    }
+}
+
+fn parse_comparator(op: &str, version: &str) -> Option<Vec<Comparator>> {
+    let parts = version
+        .trim_start_matches('v')
+        .split('.')
+        .take_while(|p| !matches!(*p, "x" | "X" | "*"))
+        .map(|p| {
+            let digits = p.split(|c: char| !c.is_ascii_digit()).next()?;
+            digits.parse::<u64>().ok()
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let bump = |i: usize| {
+        let mut v = parts[..=i].to_vec();
+        v[i] += 1;
+        v
+    };
+    let cmps = match op {
+        _ if parts.is_empty() => match op {
+            "" | "=" | "^" | "~" => vec![],
+            _ => return None,
+        },
+        ">" => vec![Comparator::new(Op::Gt, parts)],
+        ">=" => vec![Comparator::new(Op::Ge, parts)],
+        "<" => vec![Comparator::new(Op::Lt, parts)],
+        "<=" => vec![Comparator::new(Op::Le, parts)],
+        "" | "=" => vec![
+            Comparator::new(Op::Lt, bump(parts.len() - 1)),
+            Comparator::new(Op::Ge, parts),
+        ],
+        "~" => vec![
+            Comparator::new(Op::Lt, bump(parts.len().min(2) - 1)),
+            Comparator::new(Op::Ge, parts),
+        ],
+        "^" => {
+            // bump the first non-zero component
+            let i = parts
+                .iter()
+                .position(|p| *p != 0)
+                .unwrap_or(parts.len() - 1);
+            vec![
+                Comparator::new(Op::Lt, bump(i)),
+                Comparator::new(Op::Ge, parts),
+            ]
+        }
+        _ => return None,
+    };
+    Some(cmps)
+}
+
+/// numeric components of a version and whether it is a prerelease
+fn parse_version(v: &str) -> (Vec<u64>, bool) {
+    let mut prerelease = false;
+    let mut parts = vec![];
+    for p in v.trim_start_matches('v').split('.') {
+        let digits = p.split(|c: char| !c.is_ascii_digit()).next().unwrap();
+        match digits.parse::<u64>() {
+            Ok(n) => parts.push(n),
+            Err(_) => {
+                prerelease = true;
+                break;
+            }
+        }
+        if digits.len() != p.len() {
+            prerelease = true;
+            break;
+        }
+    }
+    (parts, prerelease)
+}
+
+fn compare(a: &[u64], b: &[u64]) -> Ordering {
+    for i in 0..a.len().max(b.len()) {
+        let ord = a.get(i).unwrap_or(&0).cmp(b.get(i).unwrap_or(&0));
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    Ordering::Equal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(range: &str, version: &str) -> bool {
+        VersionRange::parse(range).unwrap().matches(version)
+    }
+
+    #[test]
+    fn test_is_range() {
+        assert!(VersionRange::is_range(">=18 <21"));
+        assert!(VersionRange::is_range("^1.21"));
+        assert!(VersionRange::is_range("~3.11"));
+        assert!(VersionRange::is_range("18 || 20"));
+        assert!(!VersionRange::is_range("18.0.0"));
+        assert!(!VersionRange::is_range("latest"));
+    }
+
+    #[test]
+    fn test_comparators() {
+        assert!(matches(">=18 <21", "18.0.0"));
+        assert!(matches(">=18 <21", "20.9.1"));
+        assert!(!matches(">=18 <21", "21.0.0"));
+        assert!(!matches(">=18 <21", "17.9.9"));
+        assert!(matches(">= 18, < 21", "19.1.0"));
+        assert!(matches(">18", "18.0.1"));
+        assert!(!matches(">18", "18.0.0"));
+        assert!(matches("<=3.11", "3.11.0"));
+        assert!(!matches("<=3.11", "3.11.1"));
+    }
+
+    #[test]
+    fn test_tilde() {
+        assert!(matches("~3.11", "3.11.0"));
+        assert!(matches("~3.11", "3.11.7"));
+        assert!(!matches("~3.11", "3.12.0"));
+        assert!(matches("~3.11.2", "3.11.5"));
+        assert!(!matches("~3.11.2", "3.11.1"));
+        assert!(matches("~3", "3.99.0"));
+        assert!(!matches("~3", "4.0.0"));
+    }
+
+    #[test]
+    fn test_caret() {
+        assert!(matches("^1.21", "1.21.0"));
+        assert!(matches("^1.21", "1.22.3"));
+        assert!(!matches("^1.21", "2.0.0"));
+        assert!(!matches("^1.21", "1.20.9"));
+        assert!(matches("^0.2.3", "0.2.9"));
+        assert!(!matches("^0.2.3", "0.3.0"));
+        assert!(matches("^0.0.3", "0.0.3"));
+        assert!(!matches("^0.0.3", "0.0.4"));
+    }
+
+    #[test]
+    fn test_partial_and_wildcards() {
+        assert!(matches("=3.11", "3.11.4"));
+        assert!(!matches("=3.11", "3.1.0"));
+        assert!(matches("=3.x", "3.9.0"));
+        assert!(matches("18 || >=20", "18.2.0"));
+        assert!(matches("18 || >=20", "21.0.0"));
+        assert!(!matches("18 || >=20", "19.0.0"));
+    }
+
+    #[test]
+    fn test_prerelease() {
+        assert!(!matches(">=3.11", "3.12.0rc1"));
+        assert!(!matches("^20", "20.0.0-beta"));
+        assert!(matches(">=3.12.0rc1", "3.12.0rc2"));
+        assert!(!matches(">=3", "latest"));
+    }
+
+    #[test]
+    fn test_max_satisfying() {
+        let versions = ["3.10.0", "3.11.5", "3.11.0", "3.12.0", "3.12.1rc1"]
+            .map(String::from)
+            .to_vec();
+        let range = VersionRange::parse("~3.11").unwrap();
+        assert_eq!(range.max_satisfying(&versions).unwrap(), "3.11.5");
+        let range = VersionRange::parse(">=3").unwrap();
+        assert_eq!(range.max_satisfying(&versions).unwrap(), "3.12.0");
+        let range = VersionRange::parse(">=4").unwrap();
+        assert!(range.max_satisfying(&versions).is_none());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(VersionRange::parse(">=").is_err());
+        assert!(VersionRange::parse(">=abc").is_err());
+    }
+}